@@ -1783,6 +1783,11 @@ impl shared::extensions::commands::CliCommand<PterodactylArgs> for PterodactylCo
                     return Ok(1);
                 }
 
+                if let Err(err) = database.reconcile_counters().await {
+                    tracing::error!("failed to reconcile counters: {:?}", err);
+                    return Ok(1);
+                }
+
                 tracing::info!(
                     "finished processing import, took {:.2} seconds",
                     start_time.elapsed().as_secs_f32()
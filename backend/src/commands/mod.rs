@@ -4,6 +4,7 @@ mod diagnostics;
 mod extensions;
 mod import;
 mod nest;
+mod reconcile_counters;
 mod service_install;
 mod user;
 mod version;
@@ -24,6 +25,11 @@ pub fn commands(cli: CliCommandGroupBuilder) -> CliCommandGroupBuilder {
         "Gets Diagnostic Data for the Panel.",
         diagnostics::DiagnosticsCommand,
     )
+    .add_command(
+        "reconcile-counters",
+        "Recomputes the denormalized server and backup counters.",
+        reconcile_counters::ReconcileCountersCommand,
+    )
     .add_group("user", "Manage users within the Panel.", user::commands)
     .add_group("nest", "Manage nests within the Panel.", nest::commands)
     .add_group(
@@ -156,9 +156,7 @@ impl shared::extensions::commands::CliCommand<MassImportArgs> for MassImportComm
                                 },
                             };
 
-                            nest_egg
-                                .import_update(&state.database, exported_egg)
-                                .await?;
+                            nest_egg.import_update(&state, exported_egg).await?;
 
                             eprintln!(
                                 "updated nest egg {} successfully",
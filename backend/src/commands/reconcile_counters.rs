@@ -0,0 +1,26 @@
+use clap::Args;
+
+#[derive(Args)]
+pub struct ReconcileCountersArgs;
+
+pub struct ReconcileCountersCommand;
+
+impl shared::extensions::commands::CliCommand<ReconcileCountersArgs> for ReconcileCountersCommand {
+    fn get_command(&self, command: clap::Command) -> clap::Command {
+        command
+    }
+
+    fn get_executor(self) -> Box<shared::extensions::commands::ExecutorFunc> {
+        Box::new(|env, _arg_matches| {
+            Box::pin(async move {
+                let state = shared::AppState::new_cli(env).await?;
+
+                state.database.reconcile_counters().await?;
+
+                eprintln!("counters have been reconciled");
+
+                Ok(0)
+            })
+        })
+    }
+}
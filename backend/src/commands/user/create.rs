@@ -144,6 +144,7 @@ impl shared::extensions::commands::CliCommand<CreateArgs> for CreateCommand {
                     password: Some(password),
                     admin,
                     language: settings.app.language.clone(),
+                    backup_storage_limit: None,
                 };
                 drop(settings);
                 let user = shared::models::user::User::create(&state, options).await?;
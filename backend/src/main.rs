@@ -327,6 +327,7 @@ async fn main() {
     );
     let storage = Arc::new(shared::storage::Storage::new(settings.clone()));
     let captcha = Arc::new(shared::captcha::Captcha::new(settings.clone()));
+    let features = Arc::new(shared::features::Features::new(settings.clone()));
     let mail = Arc::new(shared::mail::Mail::new(settings.clone()));
 
     let state = Arc::new(shared::AppState {
@@ -352,6 +353,7 @@ async fn main() {
         ntp,
         storage,
         captcha,
+        features,
         mail,
         database: database.clone(),
         cache: cache.clone(),
@@ -525,6 +527,39 @@ async fn main() {
             Ok(())
         })
         .await;
+    background_task_builder
+        .add_task("delete_expired_admin_approvals", async |state| {
+            let deleted_approvals =
+                shared::models::admin_approval::AdminApproval::delete_expired(&state.database)
+                    .await?;
+            if deleted_approvals > 0 {
+                tracing::info!("deleted {} expired admin approvals", deleted_approvals);
+            }
+
+            tokio::time::sleep(std::time::Duration::from_mins(30)).await;
+
+            Ok(())
+        })
+        .await;
+    background_task_builder
+        .add_task("evaluate_server_resource_alerts", async |state| {
+            shared::models::server_resource_alert::ServerResourceAlert::evaluate_all(&state)
+                .await?;
+
+            tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+
+            Ok(())
+        })
+        .await;
+    background_task_builder
+        .add_task("check_node_health", async |state| {
+            shared::models::node::Node::check_health_all(&state).await?;
+
+            tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+
+            Ok(())
+        })
+        .await;
     background_task_builder
         .add_task("delete_unconfigured_security_keys", async |state| {
             let deleted_security_keys =
@@ -550,12 +585,14 @@ async fn main() {
             let admin_retention_days = settings.activity.admin_log_retention_days;
             let user_retention_days = settings.activity.user_log_retention_days;
             let server_retention_days = settings.activity.server_log_retention_days;
+            let batch_size = settings.activity.log_prune_batch_size as i64;
             drop(settings);
 
             let deleted_admin_activity =
                 shared::models::admin_activity::AdminActivity::delete_older_than(
                     &state.database,
                     chrono::Utc::now() - chrono::Duration::days(admin_retention_days as i64),
+                    batch_size,
                 )
                 .await?;
             if deleted_admin_activity > 0 {
@@ -566,6 +603,7 @@ async fn main() {
                 shared::models::user_activity::UserActivity::delete_older_than(
                     &state.database,
                     chrono::Utc::now() - chrono::Duration::days(user_retention_days as i64),
+                    batch_size,
                 )
                 .await?;
             if deleted_user_activity > 0 {
@@ -576,6 +614,7 @@ async fn main() {
                 shared::models::server_activity::ServerActivity::delete_older_than(
                     &state.database,
                     chrono::Utc::now() - chrono::Duration::days(server_retention_days as i64),
+                    batch_size,
                 )
                 .await?;
             if deleted_server_activity > 0 {
@@ -591,6 +630,42 @@ async fn main() {
         })
         .await;
 
+    if state.env.app_primary {
+        match shared::models::job::Job::fail_interrupted(&state.database).await {
+            Ok(0) => {}
+            Ok(interrupted_jobs) => {
+                tracing::warn!("marked {} interrupted jobs as failed", interrupted_jobs)
+            }
+            Err(err) => tracing::error!("failed to mark interrupted jobs as failed: {:?}", err),
+        }
+    }
+    background_task_builder
+        .add_task("process_jobs", async |state| {
+            let started_jobs = shared::models::job::Job::process_pending(&state).await?;
+            if started_jobs == 0 {
+                tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+            }
+
+            Ok(())
+        })
+        .await;
+    background_task_builder
+        .add_task("delete_old_jobs", async |state| {
+            let deleted_jobs = shared::models::job::Job::delete_finished_older_than(
+                &state.database,
+                chrono::Utc::now() - chrono::Duration::days(7),
+            )
+            .await?;
+            if deleted_jobs > 0 {
+                tracing::info!("deleted {} old jobs", deleted_jobs);
+            }
+
+            tokio::time::sleep(std::time::Duration::from_hours(1)).await;
+
+            Ok(())
+        })
+        .await;
+
     background_tasks
         .merge_builder(background_task_builder)
         .await;
@@ -613,6 +688,23 @@ async fn main() {
             "/avatars/{user}/{file}",
             get(
                 |state: GetState, Path::<(uuid::Uuid, String)>((user, file))| async move {
+                    if file == "identicon.svg" {
+                        if state.settings.app().await?.avatar_fallback
+                            != shared::settings::app::AvatarFallback::Identicon
+                        {
+                            return ApiResponse::error("file not found")
+                                .with_status(StatusCode::NOT_FOUND)
+                                .ok();
+                        }
+
+                        return ApiResponse::new(Body::from(shared::storage::render_identicon(
+                            user,
+                        )))
+                        .with_header("Content-Type", "image/svg+xml")
+                        .with_header("Cache-Control", "public, max-age=86400")
+                        .ok();
+                    }
+
                     if file.len() != 13 || file.contains("..") || !file.ends_with(".webp") {
                         return ApiResponse::error("file not found")
                             .with_status(StatusCode::NOT_FOUND)
@@ -0,0 +1,83 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod post {
+    use axum::{extract::Path, http::StatusCode};
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            ByUuid,
+            admin_activity::GetAdminActivityLogger,
+            admin_approval::AdminApproval,
+            user::{GetPermissionManager, GetUser},
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        approval: shared::models::admin_approval::AdminApiAdminApproval,
+    }
+
+    #[utoipa::path(post, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = NOT_FOUND, body = ApiError),
+        (status = CONFLICT, body = ApiError),
+    ), params(
+        (
+            "approval" = uuid::Uuid,
+            description = "The approval request ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        user: GetUser,
+        permissions: GetPermissionManager,
+        activity_logger: GetAdminActivityLogger,
+        Path(approval): Path<uuid::Uuid>,
+    ) -> ApiResponseResult {
+        let mut approval = match AdminApproval::by_uuid_optional(&state.database, approval).await? {
+            Some(approval) => approval,
+            None => {
+                return ApiResponse::error("approval request not found")
+                    .with_status(StatusCode::NOT_FOUND)
+                    .ok();
+            }
+        };
+
+        let Some(action) = approval.action() else {
+            return ApiResponse::error("approval request has an unknown action")
+                .with_status(StatusCode::CONFLICT)
+                .ok();
+        };
+
+        permissions.has_admin_permission(action.admin_permission())?;
+
+        approval.approve(&state.database, user.uuid).await?;
+
+        activity_logger
+            .log(
+                "approval:approve",
+                serde_json::json!({
+                    "uuid": approval.uuid,
+                    "action": approval.action,
+                    "target_uuid": approval.target_uuid,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            approval: approval.into_admin_api_object(),
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(post::route))
+        .with_state(state.clone())
+}
@@ -0,0 +1,78 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod approve;
+
+mod delete {
+    use axum::{extract::Path, http::StatusCode};
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            ByUuid, DeletableModel,
+            admin_activity::GetAdminActivityLogger,
+            admin_approval::AdminApproval,
+            user::{GetPermissionManager, GetUser},
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {}
+
+    #[utoipa::path(delete, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "approval" = uuid::Uuid,
+            description = "The approval request ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        user: GetUser,
+        permissions: GetPermissionManager,
+        activity_logger: GetAdminActivityLogger,
+        Path(approval): Path<uuid::Uuid>,
+    ) -> ApiResponseResult {
+        let approval = match AdminApproval::by_uuid_optional(&state.database, approval).await? {
+            Some(approval) => approval,
+            None => {
+                return ApiResponse::error("approval request not found")
+                    .with_status(StatusCode::NOT_FOUND)
+                    .ok();
+            }
+        };
+
+        if approval.requester.uuid != user.uuid
+            && let Some(action) = approval.action()
+        {
+            permissions.has_admin_permission(action.admin_permission())?;
+        }
+
+        approval.delete(&state, ()).await?;
+
+        activity_logger
+            .log(
+                "approval:delete",
+                serde_json::json!({
+                    "uuid": approval.uuid,
+                    "action": approval.action,
+                    "target_uuid": approval.target_uuid,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {}).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(delete::route))
+        .nest("/approve", approve::router(state))
+        .with_state(state.clone())
+}
@@ -0,0 +1,46 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod _approval_;
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        GetState,
+        models::{admin_approval::AdminApproval, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        approvals: Vec<shared::models::admin_approval::AdminApiAdminApproval>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ))]
+    pub async fn route(state: GetState, permissions: GetPermissionManager) -> ApiResponseResult {
+        let approvals = AdminApproval::all_pending(&state.database)
+            .await?
+            .into_iter()
+            .filter(|approval| {
+                approval.action().is_some_and(|action| {
+                    permissions
+                        .has_admin_permission(action.admin_permission())
+                        .is_ok()
+                })
+            })
+            .map(|approval| approval.into_admin_api_object())
+            .collect();
+
+        ApiResponse::new_serialized(Response { approvals }).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .nest("/{approval}", _approval_::router(state))
+        .with_state(state.clone())
+}
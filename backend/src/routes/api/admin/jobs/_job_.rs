@@ -0,0 +1,63 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use axum::{extract::Path, http::StatusCode};
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{ByUuid, job::Job, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        job: shared::models::job::AdminApiJob,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "job" = uuid::Uuid,
+            description = "The job ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        Path(job): Path<uuid::Uuid>,
+    ) -> ApiResponseResult {
+        let job = match Job::by_uuid_optional(&state.database, job).await? {
+            Some(job) => job,
+            None => {
+                return ApiResponse::error("job not found")
+                    .with_status(StatusCode::NOT_FOUND)
+                    .ok();
+            }
+        };
+
+        match job.admin_permission() {
+            Some(permission) => permissions.has_admin_permission(permission)?,
+            None => {
+                return ApiResponse::error("you do not have permission to view this job")
+                    .with_status(StatusCode::FORBIDDEN)
+                    .ok();
+            }
+        }
+
+        ApiResponse::new_serialized(Response {
+            job: job.into_admin_api_object(&state.database).await?,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
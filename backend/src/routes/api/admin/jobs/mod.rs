@@ -0,0 +1,10 @@
+use super::State;
+use utoipa_axum::router::OpenApiRouter;
+
+mod _job_;
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .nest("/{job}", _job_::router(state))
+        .with_state(state.clone())
+}
@@ -0,0 +1,10 @@
+use super::State;
+use utoipa_axum::router::OpenApiRouter;
+
+mod orphans;
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .nest("/orphans", orphans::router(state))
+        .with_state(state.clone())
+}
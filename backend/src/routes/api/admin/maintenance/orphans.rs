@@ -0,0 +1,176 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        GetState,
+        models::user::GetPermissionManager,
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseUnusedAllocations {
+        node_uuid: uuid::Uuid,
+        node_name: compact_str::CompactString,
+        count: i64,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseUnusedMount {
+        uuid: uuid::Uuid,
+        name: compact_str::CompactString,
+    }
+
+    #[derive(ToSchema, Serialize, Clone, Copy)]
+    #[serde(rename_all = "snake_case")]
+    enum DanglingReferenceKind {
+        /// The primary allocation of the server belongs to another server.
+        PrimaryAllocationNotOwned,
+        /// The allocation is on a different node than the server.
+        AllocationOnOtherNode,
+        /// The mount is no longer attached to the server's node or egg.
+        MountNotAttached,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseDanglingReference {
+        kind: DanglingReferenceKind,
+        server_uuid: uuid::Uuid,
+        reference_uuid: uuid::Uuid,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        #[schema(inline)]
+        unused_allocations: Vec<ResponseUnusedAllocations>,
+        #[schema(inline)]
+        unused_mounts: Vec<ResponseUnusedMount>,
+        #[schema(inline)]
+        dangling_references: Vec<ResponseDanglingReference>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ))]
+    pub async fn route(state: GetState, permissions: GetPermissionManager) -> ApiResponseResult {
+        permissions.has_admin_permission("stats.read")?;
+
+        let unused_allocations: Vec<(uuid::Uuid, String, i64)> = sqlx::query_as(
+            r#"
+            SELECT nodes.uuid, nodes.name, COUNT(node_allocations.uuid)
+            FROM node_allocations
+            JOIN nodes ON nodes.uuid = node_allocations.node_uuid
+            LEFT JOIN server_allocations ON server_allocations.allocation_uuid = node_allocations.uuid
+            WHERE server_allocations.uuid IS NULL
+            GROUP BY nodes.uuid, nodes.name
+            ORDER BY nodes.name
+            "#,
+        )
+        .fetch_all(state.database.read())
+        .await?;
+
+        let unused_mounts: Vec<(uuid::Uuid, String)> = sqlx::query_as(
+            r#"
+            SELECT mounts.uuid, mounts.name
+            FROM mounts
+            LEFT JOIN node_mounts ON node_mounts.mount_uuid = mounts.uuid
+            LEFT JOIN nest_egg_mounts ON nest_egg_mounts.mount_uuid = mounts.uuid
+            LEFT JOIN server_mounts ON server_mounts.mount_uuid = mounts.uuid
+            WHERE node_mounts.mount_uuid IS NULL
+                AND nest_egg_mounts.mount_uuid IS NULL
+                AND server_mounts.mount_uuid IS NULL
+            ORDER BY mounts.name
+            "#,
+        )
+        .fetch_all(state.database.read())
+        .await?;
+
+        let primary_allocations: Vec<(uuid::Uuid, uuid::Uuid)> = sqlx::query_as(
+            r#"
+            SELECT servers.uuid, server_allocations.uuid
+            FROM servers
+            JOIN server_allocations ON server_allocations.uuid = servers.allocation_uuid
+            WHERE server_allocations.server_uuid != servers.uuid
+            "#,
+        )
+        .fetch_all(state.database.read())
+        .await?;
+
+        let node_allocations: Vec<(uuid::Uuid, uuid::Uuid)> = sqlx::query_as(
+            r#"
+            SELECT servers.uuid, server_allocations.uuid
+            FROM server_allocations
+            JOIN servers ON servers.uuid = server_allocations.server_uuid
+            JOIN node_allocations ON node_allocations.uuid = server_allocations.allocation_uuid
+            WHERE node_allocations.node_uuid != servers.node_uuid
+                AND node_allocations.node_uuid IS DISTINCT FROM servers.destination_node_uuid
+            "#,
+        )
+        .fetch_all(state.database.read())
+        .await?;
+
+        let server_mounts: Vec<(uuid::Uuid, uuid::Uuid)> = sqlx::query_as(
+            r#"
+            SELECT servers.uuid, server_mounts.mount_uuid
+            FROM server_mounts
+            JOIN servers ON servers.uuid = server_mounts.server_uuid
+            LEFT JOIN node_mounts ON node_mounts.mount_uuid = server_mounts.mount_uuid
+                AND node_mounts.node_uuid = servers.node_uuid
+            LEFT JOIN nest_egg_mounts ON nest_egg_mounts.mount_uuid = server_mounts.mount_uuid
+                AND nest_egg_mounts.egg_uuid = servers.egg_uuid
+            WHERE node_mounts.mount_uuid IS NULL AND nest_egg_mounts.mount_uuid IS NULL
+            "#,
+        )
+        .fetch_all(state.database.read())
+        .await?;
+
+        let mut dangling_references = Vec::new();
+        for (kind, references) in [
+            (
+                DanglingReferenceKind::PrimaryAllocationNotOwned,
+                primary_allocations,
+            ),
+            (
+                DanglingReferenceKind::AllocationOnOtherNode,
+                node_allocations,
+            ),
+            (DanglingReferenceKind::MountNotAttached, server_mounts),
+        ] {
+            for (server_uuid, reference_uuid) in references {
+                dangling_references.push(ResponseDanglingReference {
+                    kind,
+                    server_uuid,
+                    reference_uuid,
+                });
+            }
+        }
+
+        ApiResponse::new_serialized(Response {
+            unused_allocations: unused_allocations
+                .into_iter()
+                .map(|(node_uuid, node_name, count)| ResponseUnusedAllocations {
+                    node_uuid,
+                    node_name: node_name.into(),
+                    count,
+                })
+                .collect(),
+            unused_mounts: unused_mounts
+                .into_iter()
+                .map(|(uuid, name)| ResponseUnusedMount {
+                    uuid,
+                    name: name.into(),
+                })
+                .collect(),
+            dangling_references,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
@@ -17,12 +17,15 @@ use std::sync::Arc;
 use utoipa_axum::router::OpenApiRouter;
 
 mod activity;
+mod approvals;
 mod assets;
 mod backup_configurations;
 mod database_hosts;
 mod egg_repositories;
 mod extensions;
+mod jobs;
 mod locations;
+mod maintenance;
 mod mounts;
 mod nests;
 mod nodes;
@@ -32,6 +35,7 @@ mod servers;
 mod settings;
 mod stats;
 mod system;
+mod telemetry;
 mod users;
 
 pub async fn auth(
@@ -92,6 +96,10 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .nest("/users", users::router(state))
         .nest("/roles", roles::router(state))
         .nest("/extensions", extensions::router(state))
+        .nest("/jobs", jobs::router(state))
+        .nest("/approvals", approvals::router(state))
+        .nest("/maintenance", maintenance::router(state))
+        .nest("/telemetry", telemetry::router(state))
         .nest("/activity", activity::router(state))
         .route_layer(axum::middleware::from_fn_with_state(state.clone(), auth))
         .route_layer(axum::middleware::from_fn_with_state(
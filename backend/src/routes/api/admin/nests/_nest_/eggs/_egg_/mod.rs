@@ -16,6 +16,7 @@ use utoipa_axum::{router::OpenApiRouter, routes};
 mod export;
 mod mounts;
 mod r#move;
+mod resource_alerts;
 mod servers;
 mod update;
 mod variables;
@@ -269,6 +270,7 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .nest("/move", r#move::router(state))
         .nest("/mounts", mounts::router(state))
         .nest("/export", export::router(state))
+        .nest("/resource-alerts", resource_alerts::router(state))
         .route_layer(axum::middleware::from_fn_with_state(state.clone(), auth))
         .with_state(state.clone())
 }
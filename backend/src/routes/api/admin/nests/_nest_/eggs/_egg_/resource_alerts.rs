@@ -0,0 +1,220 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use crate::routes::api::admin::nests::_nest_::eggs::_egg_::GetNestEgg;
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{nest_egg_resource_alert::NestEggResourceAlert, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        resource_alert:
+            Option<shared::models::nest_egg_resource_alert::AdminApiNestEggResourceAlert>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+    ), params(
+        (
+            "nest" = uuid::Uuid,
+            description = "The nest ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "egg" = uuid::Uuid,
+            description = "The egg ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        egg: GetNestEgg,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("eggs.read")?;
+
+        let resource_alert = NestEggResourceAlert::by_egg_uuid(&state.database, egg.uuid).await?;
+
+        ApiResponse::new_serialized(Response {
+            resource_alert: resource_alert.map(|alert| alert.into_admin_api_object()),
+        })
+        .ok()
+    }
+}
+
+mod put {
+    use crate::routes::api::admin::nests::_nest_::{GetNest, eggs::_egg_::GetNestEgg};
+    use axum::http::StatusCode;
+    use garde::Validate;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger, nest_egg_resource_alert::NestEggResourceAlert,
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct Payload {
+        /// Skipped for servers of the egg without a cpu limit.
+        #[garde(range(min = 1, max = 100))]
+        #[schema(minimum = 1, maximum = 100)]
+        cpu_threshold: Option<i16>,
+        #[garde(range(min = 1, max = 100))]
+        #[schema(minimum = 1, maximum = 100)]
+        memory_threshold: Option<i16>,
+        #[garde(range(min = 30, max = 86400))]
+        #[schema(minimum = 30, maximum = 86400)]
+        window_seconds: i32,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        resource_alert: shared::models::nest_egg_resource_alert::AdminApiNestEggResourceAlert,
+    }
+
+    #[utoipa::path(put, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+        (status = UNAUTHORIZED, body = ApiError),
+    ), params(
+        (
+            "nest" = uuid::Uuid,
+            description = "The nest ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "egg" = uuid::Uuid,
+            description = "The egg ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        nest: GetNest,
+        egg: GetNestEgg,
+        activity_logger: GetAdminActivityLogger,
+        shared::Payload(data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&data) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        permissions.has_admin_permission("eggs.update")?;
+
+        if data.cpu_threshold.is_none() && data.memory_threshold.is_none() {
+            return ApiResponse::error("at least one threshold must be set")
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        let resource_alert = NestEggResourceAlert::upsert(
+            &state.database,
+            egg.uuid,
+            data.cpu_threshold,
+            data.memory_threshold,
+            data.window_seconds,
+        )
+        .await?;
+
+        activity_logger
+            .log(
+                "nest:egg.resource-alerts.update",
+                serde_json::json!({
+                    "uuid": egg.uuid,
+                    "nest_uuid": nest.uuid,
+                    "cpu_threshold": resource_alert.cpu_threshold,
+                    "memory_threshold": resource_alert.memory_threshold,
+                    "window_seconds": resource_alert.window_seconds,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            resource_alert: resource_alert.into_admin_api_object(),
+        })
+        .ok()
+    }
+}
+
+mod delete {
+    use crate::routes::api::admin::nests::_nest_::{GetNest, eggs::_egg_::GetNestEgg};
+    use axum::http::StatusCode;
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger, nest_egg_resource_alert::NestEggResourceAlert,
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {}
+
+    #[utoipa::path(delete, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "nest" = uuid::Uuid,
+            description = "The nest ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "egg" = uuid::Uuid,
+            description = "The egg ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        nest: GetNest,
+        egg: GetNestEgg,
+        activity_logger: GetAdminActivityLogger,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("eggs.update")?;
+
+        if !NestEggResourceAlert::delete_by_egg_uuid(&state.database, egg.uuid).await? {
+            return ApiResponse::error("resource alert not found")
+                .with_status(StatusCode::NOT_FOUND)
+                .ok();
+        }
+
+        activity_logger
+            .log(
+                "nest:egg.resource-alerts.delete",
+                serde_json::json!({
+                    "uuid": egg.uuid,
+                    "nest_uuid": nest.uuid,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {}).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .routes(routes!(put::route))
+        .routes(routes!(delete::route))
+        .with_state(state.clone())
+}
@@ -1,6 +1,70 @@
 use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
+mod get {
+    use crate::routes::api::admin::nests::_nest_::eggs::_egg_::GetNestEgg;
+    use axum::http::StatusCode;
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{nest_egg::NestEggUpdateDiff, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        up_to_date: bool,
+        #[schema(inline)]
+        diff: NestEggUpdateDiff,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = NOT_FOUND, body = ApiError),
+        (status = EXPECTATION_FAILED, body = ApiError),
+    ), params(
+        (
+            "nest" = uuid::Uuid,
+            description = "The nest ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "egg" = uuid::Uuid,
+            description = "The egg ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        egg: GetNestEgg,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("eggs.read")?;
+
+        let egg_repository_egg = match &egg.egg_repository_egg {
+            Some(egg_repository_egg) => egg_repository_egg.fetch(&state.database).await?,
+            None => {
+                return ApiResponse::error(
+                    "unable to preview update of egg thats not linked to a repository egg",
+                )
+                .with_status(StatusCode::EXPECTATION_FAILED)
+                .ok();
+            }
+        };
+
+        let diff = egg
+            .diff_update(&state.database, &egg_repository_egg.exported_egg)
+            .await?;
+
+        ApiResponse::new_serialized(Response {
+            up_to_date: diff.is_empty(),
+            diff,
+        })
+        .ok()
+    }
+}
+
 mod post {
     use crate::routes::api::admin::nests::_nest_::{GetNest, eggs::_egg_::GetNestEgg};
     use axum::http::StatusCode;
@@ -50,7 +114,7 @@ mod post {
         };
 
         match egg
-            .import_update(&state.database, egg_repository_egg.exported_egg)
+            .import_update(&state, egg_repository_egg.exported_egg)
             .await
         {
             Ok(_) => {}
@@ -97,6 +161,7 @@ mod post {
 
 pub fn router(state: &State) -> OpenApiRouter<State> {
     OpenApiRouter::new()
+        .routes(routes!(get::route))
         .routes(routes!(post::route))
         .with_state(state.clone())
 }
@@ -168,6 +168,7 @@ mod patch {
                     "user_viewable": egg_variable.user_viewable,
                     "user_editable": egg_variable.user_editable,
                     "secret": egg_variable.secret,
+                    "resolve_references": egg_variable.resolve_references,
                     "rules": egg_variable.rules,
                 }),
             )
@@ -0,0 +1,151 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod post {
+    use crate::routes::api::admin::nests::_nest_::{GetNest, eggs::_egg_::GetNestEgg};
+    use axum::http::StatusCode;
+    use garde::Validate;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger,
+            nest_egg_variable::{CreateNestEggVariableOptions, NestEggVariable},
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Deserialize)]
+    pub struct PayloadVariable {
+        name: compact_str::CompactString,
+        description: Option<compact_str::CompactString>,
+        order: i16,
+
+        env_variable: compact_str::CompactString,
+        default_value: Option<String>,
+
+        user_viewable: bool,
+        user_editable: bool,
+        secret: bool,
+        #[serde(default)]
+        resolve_references: bool,
+        rules: Vec<compact_str::CompactString>,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct Payload {
+        #[garde(length(min = 1, max = 100))]
+        #[schema(inline, min_items = 1, max_items = 100)]
+        variables: Vec<PayloadVariable>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseResult {
+        variable: Option<shared::models::nest_egg_variable::AdminApiNestEggVariable>,
+        error: Option<compact_str::CompactString>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        #[schema(inline)]
+        results: Vec<ResponseResult>,
+    }
+
+    #[utoipa::path(post, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+    ), params(
+        (
+            "nest" = uuid::Uuid,
+            description = "The nest ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "egg" = uuid::Uuid,
+            description = "The egg ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        nest: GetNest,
+        egg: GetNestEgg,
+        activity_logger: GetAdminActivityLogger,
+        shared::Payload(data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&data) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        permissions.has_admin_permission("eggs.update")?;
+
+        let results = NestEggVariable::create_bulk(
+            &state,
+            data.variables
+                .into_iter()
+                .map(|variable| CreateNestEggVariableOptions {
+                    egg_uuid: egg.uuid,
+                    name: variable.name,
+                    description: variable.description,
+                    order: variable.order,
+                    env_variable: variable.env_variable,
+                    default_value: variable.default_value,
+                    user_viewable: variable.user_viewable,
+                    user_editable: variable.user_editable,
+                    secret: variable.secret,
+                    resolve_references: variable.resolve_references,
+                    rules: variable.rules,
+                })
+                .collect(),
+        )
+        .await?;
+
+        let created: Vec<_> = results
+            .iter()
+            .filter_map(|result| result.as_ref().ok())
+            .map(|variable| variable.env_variable.clone())
+            .collect();
+
+        if !created.is_empty() {
+            activity_logger
+                .log(
+                    "nest:egg.variable.create-bulk",
+                    serde_json::json!({
+                        "nest_uuid": nest.uuid,
+                        "egg_uuid": egg.uuid,
+
+                        "env_variables": created,
+                    }),
+                )
+                .await;
+        }
+
+        ApiResponse::new_serialized(Response {
+            results: results
+                .into_iter()
+                .map(|result| match result {
+                    Ok(variable) => ResponseResult {
+                        variable: Some(variable.into_admin_api_object()),
+                        error: None,
+                    },
+                    Err(error) => ResponseResult {
+                        variable: None,
+                        error: Some(error),
+                    },
+                })
+                .collect(),
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(post::route))
+        .with_state(state.clone())
+}
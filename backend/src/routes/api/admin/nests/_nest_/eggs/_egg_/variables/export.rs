@@ -0,0 +1,60 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use crate::routes::api::admin::nests::_nest_::eggs::_egg_::GetNestEgg;
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            nest_egg_variable::{ExportedNestEggVariable, NestEggVariable},
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        variables: Vec<ExportedNestEggVariable>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "nest" = uuid::Uuid,
+            description = "The nest ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "egg" = uuid::Uuid,
+            description = "The egg ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        egg: GetNestEgg,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("eggs.read")?;
+
+        let variables = NestEggVariable::all_by_egg_uuid(&state.database, egg.uuid).await?;
+
+        ApiResponse::new_serialized(Response {
+            variables: variables
+                .into_iter()
+                .map(|variable| variable.into_exported())
+                .collect(),
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
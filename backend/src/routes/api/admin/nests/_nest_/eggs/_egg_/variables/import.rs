@@ -0,0 +1,100 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod post {
+    use crate::routes::api::admin::nests::_nest_::{GetNest, eggs::_egg_::GetNestEgg};
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger,
+            nest_egg_variable::{ExportedNestEggVariable, NestEggVariable},
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Deserialize, Serialize, Clone, Copy, Default)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ImportMode {
+        #[default]
+        Merge,
+        Replace,
+    }
+
+    #[derive(ToSchema, Deserialize)]
+    pub struct Payload {
+        #[serde(default)]
+        mode: ImportMode,
+        variables: Vec<ExportedNestEggVariable>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        variables: Vec<shared::models::nest_egg_variable::AdminApiNestEggVariable>,
+    }
+
+    #[utoipa::path(post, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "nest" = uuid::Uuid,
+            description = "The nest ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "egg" = uuid::Uuid,
+            description = "The egg ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        nest: GetNest,
+        egg: GetNestEgg,
+        activity_logger: GetAdminActivityLogger,
+        shared::Payload(data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("eggs.update")?;
+
+        let imported = data.variables.len();
+        let variables = NestEggVariable::import_all(
+            &state,
+            egg.uuid,
+            data.variables,
+            matches!(data.mode, ImportMode::Replace),
+        )
+        .await?;
+
+        activity_logger
+            .log(
+                "nest:egg.variable.import",
+                serde_json::json!({
+                    "nest_uuid": nest.uuid,
+                    "egg_uuid": egg.uuid,
+
+                    "mode": data.mode,
+                    "imported": imported,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            variables: variables
+                .into_iter()
+                .map(|variable| variable.into_admin_api_object())
+                .collect(),
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(post::route))
+        .with_state(state.clone())
+}
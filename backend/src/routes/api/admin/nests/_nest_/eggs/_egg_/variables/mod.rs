@@ -2,6 +2,9 @@ use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod _variable_;
+mod bulk;
+mod export;
+mod import;
 mod order;
 
 mod get {
@@ -93,6 +96,9 @@ mod post {
         user_editable: bool,
         #[garde(skip)]
         secret: bool,
+        #[garde(skip)]
+        #[serde(default)]
+        resolve_references: bool,
         #[garde(custom(rule_validator::validate_rules))]
         rules: Vec<compact_str::CompactString>,
     }
@@ -140,6 +146,7 @@ mod post {
                 user_viewable: data.user_viewable,
                 user_editable: data.user_editable,
                 secret: data.secret,
+                resolve_references: data.resolve_references,
                 rules: data.rules,
             },
         )
@@ -172,6 +179,7 @@ mod post {
                     "user_viewable": egg_variable.user_viewable,
                     "user_editable": egg_variable.user_editable,
                     "secret": egg_variable.secret,
+                    "resolve_references": egg_variable.resolve_references,
                     "rules": egg_variable.rules,
                 }),
             )
@@ -189,6 +197,9 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .routes(routes!(get::route))
         .routes(routes!(post::route))
         .nest("/{variable}", _variable_::router(state))
+        .nest("/bulk", bulk::router(state))
         .nest("/order", order::router(state))
+        .nest("/export", export::router(state))
+        .nest("/import", import::router(state))
         .with_state(state.clone())
 }
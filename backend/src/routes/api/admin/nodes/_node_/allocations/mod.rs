@@ -160,7 +160,10 @@ mod post {
         #[garde(skip)]
         #[schema(value_type = String)]
         ip: std::net::IpAddr,
-        #[garde(length(chars, min = 1, max = 255))]
+        #[garde(
+            length(chars, min = 1, max = 255),
+            inner(custom(shared::utils::validate_hostname))
+        )]
         #[schema(min_length = 1, max_length = 255)]
         ip_alias: Option<String>,
         #[garde(skip)]
@@ -197,6 +200,15 @@ mod post {
 
         permissions.has_admin_permission("nodes.allocations")?;
 
+        if let Some(ip_alias) = &data.ip_alias
+            && state.settings.server().await?.resolve_allocation_aliases
+            && !NodeAllocation::ip_alias_resolves(ip_alias).await
+        {
+            return ApiResponse::error(format!("allocation alias {ip_alias} does not resolve"))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
         let allocation_ip = data.ip.into();
         let mut futures = Vec::new();
         futures.reserve_exact(data.ports.len());
@@ -242,7 +254,8 @@ mod patch {
     use shared::{
         ApiError, GetState,
         models::{
-            admin_activity::GetAdminActivityLogger, node::GetNode, user::GetPermissionManager,
+            admin_activity::GetAdminActivityLogger, node::GetNode, node_allocation::NodeAllocation,
+            user::GetPermissionManager,
         },
         response::{ApiResponse, ApiResponseResult},
     };
@@ -256,7 +269,10 @@ mod patch {
         #[garde(skip)]
         #[schema(value_type = String)]
         ip: std::net::IpAddr,
-        #[garde(length(chars, min = 1, max = 255))]
+        #[garde(
+            length(chars, min = 1, max = 255),
+            inner(inner(custom(shared::utils::validate_hostname)))
+        )]
         #[schema(min_length = 1, max_length = 255)]
         #[serde(default, with = "::serde_with::rust::double_option")]
         ip_alias: Option<Option<String>>,
@@ -292,6 +308,15 @@ mod patch {
 
         permissions.has_admin_permission("nodes.allocations")?;
 
+        if let Some(Some(ip_alias)) = &data.ip_alias
+            && state.settings.server().await?.resolve_allocation_aliases
+            && !NodeAllocation::ip_alias_resolves(ip_alias).await
+        {
+            return ApiResponse::error(format!("allocation alias {ip_alias} does not resolve"))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
         let allocation_ip: sqlx::types::ipnetwork::IpNetwork = data.ip.into();
         let updated = if let Some(ip_alias) = &data.ip_alias {
             sqlx::query!(
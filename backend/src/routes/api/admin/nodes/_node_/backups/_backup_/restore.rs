@@ -20,6 +20,8 @@ mod post {
         server_uuid: uuid::Uuid,
 
         truncate_directory: bool,
+        #[serde(default)]
+        force: bool,
     }
 
     #[derive(ToSchema, Serialize)]
@@ -30,6 +32,7 @@ mod post {
         (status = UNAUTHORIZED, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
         (status = BAD_REQUEST, body = ApiError),
+        (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
         (
             "node" = uuid::Uuid,
@@ -68,6 +71,12 @@ mod post {
                 }
             };
 
+        if !data.force {
+            backup
+                .ensure_fits_disk_limit(&state.database, &server, data.truncate_directory)
+                .await?;
+        }
+
         let mut transaction = state.database.write().begin().await?;
 
         let rows_affected = sqlx::query!(
@@ -113,6 +122,7 @@ mod post {
 
                     "name": backup_name,
                     "truncate_directory": data.truncate_directory,
+                    "force": data.force,
                 }),
             )
             .await;
@@ -0,0 +1,96 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        GetState,
+        models::{node::GetNode, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseResource {
+        total: i64,
+        allocated: i64,
+        used: Option<i64>,
+        /// Not allocated to servers with a limit, servers without a limit may use it as well.
+        free: i64,
+        /// Servers without a limit, they are not part of `allocated`.
+        unlimited_servers: i64,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        servers: i64,
+        #[schema(inline)]
+        memory: ResponseResource,
+        #[schema(inline)]
+        disk: ResponseResource,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ), params(
+        (
+            "node" = uuid::Uuid,
+            description = "The node ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        node: GetNode,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("nodes.read")?;
+
+        let allocated = node.fetch_allocated_resources(&state.database).await?;
+
+        let used = match node.fetch_server_resources(&state.database).await {
+            Ok(resources) => {
+                let (memory_bytes, disk_bytes) = resources
+                    .values()
+                    .fold((0u64, 0u64), |(memory, disk), usage| {
+                        (memory + usage.memory_bytes, disk + usage.disk_bytes)
+                    });
+
+                Some((
+                    (memory_bytes / 1024 / 1024) as i64,
+                    (disk_bytes / 1024 / 1024) as i64,
+                ))
+            }
+            Err(err) => {
+                tracing::warn!(node = %node.uuid, "failed to fetch server resources for capacity: {:?}", err);
+
+                None
+            }
+        };
+
+        ApiResponse::new_serialized(Response {
+            servers: allocated.servers,
+            memory: ResponseResource {
+                total: node.memory,
+                allocated: allocated.memory,
+                used: used.map(|(memory, _)| memory),
+                free: node.memory - allocated.memory,
+                unlimited_servers: allocated.unlimited_memory_servers,
+            },
+            disk: ResponseResource {
+                total: node.disk,
+                allocated: allocated.disk,
+                used: used.map(|(_, disk)| disk),
+                free: node.disk - allocated.disk,
+                unlimited_servers: allocated.unlimited_disk_servers,
+            },
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
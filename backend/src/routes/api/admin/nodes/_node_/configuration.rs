@@ -0,0 +1,97 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use base64::Engine;
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger, node::GetNode, user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        /// The wings configuration, JSON is valid YAML so this can be written to `config.yml` as is.
+        configuration: serde_json::Value,
+        /// The configuration for `wings configure --join-data`.
+        join_data: String,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "node" = uuid::Uuid,
+            description = "The node ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        node: GetNode,
+        activity_logger: GetAdminActivityLogger,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("nodes.configuration")?;
+
+        let remote = state.settings.app().await?.url.clone();
+        let token = state.database.decrypt(node.token.clone()).await?;
+
+        // keep the upload limit the node currently runs with, falling back to the wings default
+        let upload_limit = match node.fetch_configuration(&state.database).await {
+            Ok(configuration) => configuration.api.upload_limit,
+            Err(err) => {
+                tracing::debug!(node = %node.uuid, "failed to fetch node configuration: {:?}", err);
+
+                10240
+            }
+        };
+
+        let configuration = serde_json::json!({
+            "uuid": node.uuid,
+            "token_id": node.token_id,
+            "token": token,
+            "api": {
+                "port": node.url.port().unwrap_or(8080),
+                "disable_openapi_docs": true,
+                "upload_limit": upload_limit,
+            },
+            "system": {
+                "sftp": {
+                    "bind_port": node.sftp_port,
+                },
+            },
+            "allowed_mounts": [],
+            "remote": remote.trim_end_matches('/'),
+        });
+
+        let join_data = base64::engine::general_purpose::STANDARD
+            .encode(serde_json::to_string(&configuration)?);
+
+        activity_logger
+            .log(
+                "node:configuration",
+                serde_json::json!({
+                    "node_uuid": node.uuid,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            configuration,
+            join_data,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
@@ -14,10 +14,13 @@ use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod allocations;
 mod backups;
+mod capacity;
+mod configuration;
 mod mounts;
 mod reset_token;
 mod servers;
 mod system;
+mod tags;
 
 pub async fn auth(
     state: GetState,
@@ -100,18 +103,29 @@ mod delete {
     use shared::{
         ApiError, GetState,
         models::{
-            DeletableModel, admin_activity::GetAdminActivityLogger, node::GetNode, server::Server,
-            user::GetPermissionManager,
+            DeletableModel,
+            admin_activity::GetAdminActivityLogger,
+            admin_approval::{AdminApproval, AdminApprovalCheck},
+            node::GetNode,
+            server::Server,
+            user::{GetPermissionManager, GetUser},
         },
         response::{ApiResponse, ApiResponseResult},
+        settings::approvals::ApprovalAction,
     };
     use utoipa::ToSchema;
 
     #[derive(ToSchema, Serialize)]
     struct Response {}
 
+    #[derive(ToSchema, Serialize)]
+    struct ResponseApprovalRequired {
+        approval: shared::models::admin_approval::AdminApiAdminApproval,
+    }
+
     #[utoipa::path(delete, path = "/", responses(
         (status = OK, body = inline(Response)),
+        (status = ACCEPTED, description = "The deletion has to be approved by another admin first", body = inline(ResponseApprovalRequired)),
         (status = NOT_FOUND, body = ApiError),
         (status = BAD_REQUEST, body = ApiError),
     ), params(
@@ -123,6 +137,7 @@ mod delete {
     ))]
     pub async fn route(
         state: GetState,
+        user: GetUser,
         permissions: GetPermissionManager,
         node: GetNode,
         activity_logger: GetAdminActivityLogger,
@@ -135,7 +150,27 @@ mod delete {
                 .ok();
         }
 
+        let approval = match AdminApproval::check(
+            &state,
+            ApprovalAction::NodeDelete,
+            node.uuid,
+            serde_json::json!({}),
+            user.uuid,
+        )
+        .await?
+        {
+            AdminApprovalCheck::Pending(approval) => {
+                return ApiResponse::new_serialized(ResponseApprovalRequired {
+                    approval: approval.into_admin_api_object(),
+                })
+                .with_status(StatusCode::ACCEPTED)
+                .ok();
+            }
+            approval => approval,
+        };
+
         node.delete(&state, ()).await?;
+        approval.consume(&state.database).await?;
 
         activity_logger
             .log(
@@ -211,6 +246,7 @@ mod patch {
                     "description": node.description,
                     "deployment_enabled": node.deployment_enabled,
                     "maintenance_enabled": node.maintenance_enabled,
+                    "deployment_weight": node.deployment_weight,
                     "public_url": node.public_url,
                     "url": node.url,
                     "sftp_host": node.sftp_host,
@@ -231,11 +267,14 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .routes(routes!(delete::route))
         .routes(routes!(patch::route))
         .nest("/reset-token", reset_token::router(state))
+        .nest("/configuration", configuration::router(state))
         .nest("/allocations", allocations::router(state))
         .nest("/system", system::router(state))
+        .nest("/capacity", capacity::router(state))
         .nest("/servers", servers::router(state))
         .nest("/mounts", mounts::router(state))
         .nest("/backups", backups::router(state))
+        .nest("/tags", tags::router(state))
         .route_layer(axum::middleware::from_fn_with_state(state.clone(), auth))
         .with_state(state.clone())
 }
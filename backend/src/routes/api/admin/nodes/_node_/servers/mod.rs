@@ -3,6 +3,7 @@ use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod power;
 mod resources;
+mod sync;
 mod transfer;
 mod transfers;
 
@@ -90,6 +91,7 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .nest("/power", power::router(state))
         .nest("/transfer", transfer::router(state))
         .nest("/resources", resources::router(state))
+        .nest("/sync", sync::router(state))
         .nest("/transfers", transfers::router(state))
         .routes(routes!(get::route))
         .with_state(state.clone())
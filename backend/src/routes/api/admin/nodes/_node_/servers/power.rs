@@ -6,7 +6,8 @@ mod post {
     use shared::{
         ApiError, GetState,
         models::{
-            admin_activity::GetAdminActivityLogger, node::GetNode, user::GetPermissionManager,
+            admin_activity::GetAdminActivityLogger, node::GetNode, server::Server,
+            user::GetPermissionManager,
         },
         response::{ApiResponse, ApiResponseResult},
     };
@@ -53,6 +54,16 @@ mod post {
             })
             .await?;
 
+        if data.servers.is_empty() {
+            for server in Server::all_by_node_uuid(&state.database, node.uuid).await? {
+                Server::invalidate_power_state(&state.database, server.uuid).await;
+            }
+        } else {
+            for server_uuid in data.servers.iter().copied() {
+                Server::invalidate_power_state(&state.database, server_uuid).await;
+            }
+        }
+
         activity_logger
             .log(
                 "node:servers.power",
@@ -0,0 +1,131 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod post {
+    use futures_util::StreamExt;
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger, node::GetNode, server::Server,
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct ServerSyncResult {
+        uuid: uuid::Uuid,
+        name: compact_str::CompactString,
+        success: bool,
+        error: Option<String>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        synced: usize,
+        failed: usize,
+        #[schema(inline)]
+        servers: Vec<ServerSyncResult>,
+    }
+
+    #[utoipa::path(post, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "node" = uuid::Uuid,
+            description = "The node ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        node: GetNode,
+        activity_logger: GetAdminActivityLogger,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("nodes.update")?;
+
+        let servers = Server::all_by_node_uuid(&state.database, node.uuid).await?;
+
+        let sync_server = async |server: Server| {
+            // a queued sync for this server would only repeat the work done here
+            state
+                .database
+                .cancel_batch_action("sync_server", server.uuid)
+                .await;
+
+            let uuid = server.uuid;
+            let name = server.name.clone();
+
+            match server.sync(&state.database).await {
+                Ok(()) => ServerSyncResult {
+                    uuid,
+                    name,
+                    success: true,
+                    error: None,
+                },
+                Err(err) => {
+                    tracing::warn!(server = %uuid, "failed to sync server: {:?}", err);
+
+                    ServerSyncResult {
+                        uuid,
+                        name,
+                        success: false,
+                        error: Some(err.to_string()),
+                    }
+                }
+            }
+        };
+
+        let mut futures = Vec::new();
+
+        for server in servers {
+            futures.push(sync_server(server));
+        }
+
+        let mut results_stream = futures_util::stream::iter(futures).buffer_unordered(5);
+
+        let mut results = Vec::new();
+        while let Some(result) = results_stream.next().await {
+            results.push(result);
+        }
+
+        let synced = results.iter().filter(|result| result.success).count();
+        let failed = results.len() - synced;
+
+        tracing::info!(
+            node = %node.uuid,
+            "synced servers on node: {} succeeded, {} failed",
+            synced,
+            failed
+        );
+
+        activity_logger
+            .log(
+                "node:servers.sync",
+                serde_json::json!({
+                    "node_uuid": node.uuid,
+                    "synced": synced,
+                    "failed": failed,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            synced,
+            failed,
+            servers: results,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(post::route))
+        .with_state(state.clone())
+}
@@ -92,7 +92,7 @@ mod post {
 
             let (needs_primary, required_allocation_count) = if data.allocation_uuids_random {
                 let count =
-                    ServerAllocation::count_by_server_uuid(&state.database, server.uuid).await;
+                    ServerAllocation::count_by_server_uuid(&state.database, server.uuid).await?;
                 if data.allocation_uuid_random {
                     (true, count)
                 } else {
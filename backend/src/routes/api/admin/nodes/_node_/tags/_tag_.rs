@@ -0,0 +1,75 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod delete {
+    use axum::{extract::Path, http::StatusCode};
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            DeletableModel, admin_activity::GetAdminActivityLogger, node::GetNode,
+            node_tag::NodeTag, user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {}
+
+    #[utoipa::path(delete, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "node" = uuid::Uuid,
+            description = "The node ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "tag" = String,
+            description = "The tag name",
+            example = "premium",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        node: GetNode,
+        activity_logger: GetAdminActivityLogger,
+        Path((_node, tag)): Path<(uuid::Uuid, compact_str::CompactString)>,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("nodes.update")?;
+
+        let tag = NodeTag::normalize_name(&tag);
+
+        let node_tag = match NodeTag::by_node_uuid_name(&state.database, node.uuid, &tag).await? {
+            Some(tag) => tag,
+            None => {
+                return ApiResponse::error("tag not found")
+                    .with_status(StatusCode::NOT_FOUND)
+                    .ok();
+            }
+        };
+
+        node_tag.delete(&state, ()).await?;
+
+        activity_logger
+            .log(
+                "node:tag.delete",
+                serde_json::json!({
+                    "node_uuid": node.uuid,
+                    "name": node_tag.name,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {}).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(delete::route))
+        .with_state(state.clone())
+}
@@ -0,0 +1,129 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod _tag_;
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        GetState,
+        models::{node::GetNode, node_tag::NodeTag, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        tags: Vec<shared::models::node_tag::AdminApiNodeTag>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ), params(
+        (
+            "node" = uuid::Uuid,
+            description = "The node ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        node: GetNode,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("nodes.read")?;
+
+        let tags = NodeTag::all_by_node_uuid(&state.database, node.uuid).await?;
+
+        ApiResponse::new_serialized(Response {
+            tags: tags
+                .into_iter()
+                .map(|tag| tag.into_admin_api_object())
+                .collect(),
+        })
+        .ok()
+    }
+}
+
+mod post {
+    use axum::http::StatusCode;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{
+            CreatableModel, admin_activity::GetAdminActivityLogger, node::GetNode,
+            node_tag::NodeTag, user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Deserialize)]
+    pub struct Payload {
+        #[schema(min_length = 1, max_length = 31)]
+        name: compact_str::CompactString,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        tag: shared::models::node_tag::AdminApiNodeTag,
+    }
+
+    #[utoipa::path(post, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+        (status = CONFLICT, body = ApiError),
+    ), params(
+        (
+            "node" = uuid::Uuid,
+            description = "The node ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        node: GetNode,
+        activity_logger: GetAdminActivityLogger,
+        shared::Payload(data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("nodes.update")?;
+
+        let options = shared::models::node_tag::CreateNodeTagOptions {
+            node_uuid: node.uuid,
+            name: NodeTag::normalize_name(&data.name),
+        };
+        let tag = match NodeTag::create(&state, options).await {
+            Ok(tag) => tag,
+            Err(err) if err.is_unique_violation() => {
+                return ApiResponse::error("tag already exists")
+                    .with_status(StatusCode::CONFLICT)
+                    .ok();
+            }
+            Err(err) => return ApiResponse::from(err).ok(),
+        };
+
+        activity_logger
+            .log(
+                "node:tag.create",
+                serde_json::json!({
+                    "node_uuid": node.uuid,
+                    "name": tag.name,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            tag: tag.into_admin_api_object(),
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .routes(routes!(post::route))
+        .nest("/{tag}", _tag_::router(state))
+        .with_state(state.clone())
+}
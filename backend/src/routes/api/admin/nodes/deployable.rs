@@ -0,0 +1,102 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use axum::http::StatusCode;
+    use axum_extra::extract::Query;
+    use garde::Validate;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{node::Node, node_tag::NodeTag, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct Params {
+        #[garde(range(min = 0))]
+        #[serde(default)]
+        memory: i64,
+        #[garde(range(min = 0))]
+        #[serde(default)]
+        disk: i64,
+
+        #[garde(skip)]
+        #[serde(default)]
+        location_uuids: Vec<uuid::Uuid>,
+        #[garde(length(max = 16))]
+        #[serde(default)]
+        tags: Vec<compact_str::CompactString>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        nodes: Vec<shared::models::node::AdminApiNode>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+    ), params(
+        (
+            "memory" = i64, Query,
+            description = "The amount of memory (MiB) the server will require",
+            example = "1024",
+        ),
+        (
+            "disk" = i64, Query,
+            description = "The amount of disk (MiB) the server will require",
+            example = "10240",
+        ),
+        (
+            "location_uuids" = Vec<uuid::Uuid>, Query,
+            description = "The locations to restrict deployment to",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "tags" = Vec<String>, Query,
+            description = "The tags a node must have to be considered",
+            example = "premium",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        Query(mut params): Query<Params>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&params) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        params.tags = NodeTag::normalize_names(&params.tags);
+
+        permissions.has_admin_permission("nodes.read")?;
+
+        let nodes = Node::all_deployable(
+            &state.database,
+            &params.location_uuids,
+            &params.tags,
+            params.memory,
+            params.disk,
+        )
+        .await?;
+
+        let mut api_nodes = Vec::new();
+        api_nodes.reserve_exact(nodes.len());
+
+        for node in nodes {
+            api_nodes.push(node.into_admin_api_object(&state.database).await?);
+        }
+
+        ApiResponse::new_serialized(Response { nodes: api_nodes }).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
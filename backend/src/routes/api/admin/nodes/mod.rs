@@ -2,6 +2,8 @@ use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod _node_;
+mod deployable;
+mod reset_tokens;
 
 mod get {
     use axum::{extract::Query, http::StatusCode};
@@ -122,6 +124,7 @@ mod post {
                     "description": node.description,
                     "deployment_enabled": node.deployment_enabled,
                     "maintenance_enabled": node.maintenance_enabled,
+                    "deployment_weight": node.deployment_weight,
                     "public_url": node.public_url,
                     "url": node.url,
                     "sftp_host": node.sftp_host,
@@ -143,6 +146,8 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
     OpenApiRouter::new()
         .routes(routes!(get::route))
         .routes(routes!(post::route))
+        .nest("/deployable", deployable::router(state))
+        .nest("/reset-tokens", reset_tokens::router(state))
         .nest("/{node}", _node_::router(state))
         .with_state(state.clone())
 }
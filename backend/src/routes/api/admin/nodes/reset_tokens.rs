@@ -0,0 +1,125 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod post {
+    use axum::http::StatusCode;
+    use garde::Validate;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{
+            ByUuid, admin_activity::GetAdminActivityLogger, node::Node, user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct Payload {
+        #[garde(length(min = 1, max = 100))]
+        #[schema(min_items = 1, max_items = 100)]
+        node_uuids: Vec<uuid::Uuid>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseReset {
+        node_uuid: uuid::Uuid,
+        token_id: String,
+        token: String,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseFailed {
+        node_uuid: uuid::Uuid,
+        error: String,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        #[schema(inline)]
+        reset: Vec<ResponseReset>,
+        #[schema(inline)]
+        failed: Vec<ResponseFailed>,
+    }
+
+    #[utoipa::path(post, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        activity_logger: GetAdminActivityLogger,
+        shared::Payload(mut data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&data) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        permissions.has_admin_permission("nodes.reset-token")?;
+
+        data.node_uuids.sort_unstable();
+        data.node_uuids.dedup();
+
+        let mut reset = Vec::new();
+        let mut failed = Vec::new();
+
+        for node_uuid in data.node_uuids {
+            let node = match Node::by_uuid_optional(&state.database, node_uuid).await {
+                Ok(Some(node)) => node,
+                Ok(None) => {
+                    failed.push(ResponseFailed {
+                        node_uuid,
+                        error: "node not found".into(),
+                    });
+                    continue;
+                }
+                Err(err) => {
+                    tracing::error!(node = %node_uuid, "failed to load node for token reset: {:?}", err);
+
+                    failed.push(ResponseFailed {
+                        node_uuid,
+                        error: "failed to load node".into(),
+                    });
+                    continue;
+                }
+            };
+
+            match node.reset_token(&state).await {
+                Ok((token_id, token)) => reset.push(ResponseReset {
+                    node_uuid,
+                    token_id,
+                    token,
+                }),
+                Err(err) => {
+                    tracing::error!(node = %node_uuid, "failed to reset node token: {:?}", err);
+
+                    failed.push(ResponseFailed {
+                        node_uuid,
+                        error: "failed to reset token".into(),
+                    });
+                }
+            }
+        }
+
+        activity_logger
+            .log(
+                "node:reset-tokens",
+                serde_json::json!({
+                    "node_uuids": reset.iter().map(|r| r.node_uuid).collect::<Vec<_>>(),
+                    "failed_node_uuids": failed.iter().map(|f| f.node_uuid).collect::<Vec<_>>(),
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response { reset, failed }).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(post::route))
+        .with_state(state.clone())
+}
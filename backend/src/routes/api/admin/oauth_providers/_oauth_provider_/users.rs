@@ -42,7 +42,7 @@ mod get {
         ),
         (
             "search" = Option<String>, Query,
-            description = "Search term for items",
+            description = "Search term for the link identifier, username or email",
         ),
     ))]
     pub async fn route(
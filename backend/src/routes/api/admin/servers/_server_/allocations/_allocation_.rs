@@ -21,6 +21,7 @@ mod delete {
         (status = OK, body = inline(Response)),
         (status = UNAUTHORIZED, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
+        (status = BAD_REQUEST, body = ApiError),
     ), params(
         (
             "server" = uuid::Uuid,
@@ -54,6 +55,13 @@ mod delete {
                 }
             };
 
+        let allocations =
+            ServerAllocation::count_by_server_uuid(&state.database, server.uuid).await?;
+        server
+            .egg
+            .config_allocations
+            .ensure_minimum((allocations as usize).saturating_sub(1))?;
+
         allocation.delete(&state, ()).await?;
 
         activity_logger
@@ -152,9 +152,11 @@ mod post {
         )
         .await
         {
-            Ok(allocation_uuid) => ServerAllocation::by_uuid(&state.database, allocation_uuid)
-                .await?
-                .ok_or_else(|| anyhow::anyhow!("allocation not found after creation"))?,
+            Ok(allocation_uuid) => {
+                ServerAllocation::by_uuid(&state.database.primary(), allocation_uuid)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("allocation not found after creation"))?
+            }
             Err(err) => return ApiResponse::from(err).ok(),
         };
 
@@ -85,6 +85,16 @@ mod post {
 
         transaction.commit().await?;
 
+        if let Some(destination_node) = &server.destination_node {
+            shared::models::job::Job::finish_server_transfer(
+                &state.database,
+                server.uuid,
+                destination_node.uuid,
+                false,
+            )
+            .await?;
+        }
+
         activity_logger
             .log(
                 "server:clear-state",
@@ -0,0 +1,98 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{server::GetServer, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseMismatch {
+        env_variable: compact_str::CompactString,
+        /// The value the panel would send, `null` if wings has a variable the panel does not know about.
+        panel: Option<serde_json::Value>,
+        /// The value wings currently has, `null` if the variable is missing on wings.
+        wings: Option<serde_json::Value>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        in_sync: bool,
+        #[schema(inline)]
+        mismatches: Vec<ResponseMismatch>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("servers.read")?;
+
+        let node = server.node.fetch_cached(&state.database).await?;
+        let (remote_server, wings_server) = tokio::try_join!(
+            server.0.clone().into_remote_api_object(&state.database),
+            async {
+                Ok::<_, anyhow::Error>(
+                    node.api_client(&state.database)
+                        .await?
+                        .get_servers_server(server.uuid)
+                        .await?,
+                )
+            },
+        )?;
+
+        let panel_environment = remote_server.environment();
+        let wings_environment = &wings_server.configuration.environment;
+
+        let mut mismatches = Vec::new();
+
+        for (env_variable, panel_value) in panel_environment {
+            let wings_value = wings_environment.get(env_variable);
+
+            if wings_value != Some(panel_value) {
+                mismatches.push(ResponseMismatch {
+                    env_variable: env_variable.clone(),
+                    panel: Some(panel_value.clone()),
+                    wings: wings_value.cloned(),
+                });
+            }
+        }
+
+        for (env_variable, wings_value) in wings_environment {
+            if !panel_environment.contains_key(env_variable) {
+                mismatches.push(ResponseMismatch {
+                    env_variable: env_variable.clone(),
+                    panel: None,
+                    wings: Some(wings_value.clone()),
+                });
+            }
+        }
+
+        ApiResponse::new_serialized(Response {
+            in_sync: mismatches.is_empty(),
+            mismatches,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
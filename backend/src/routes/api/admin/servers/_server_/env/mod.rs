@@ -0,0 +1,10 @@
+use super::State;
+use utoipa_axum::router::OpenApiRouter;
+
+mod diff;
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .nest("/diff", diff::router(state))
+        .with_state(state.clone())
+}
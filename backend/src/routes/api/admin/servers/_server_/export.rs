@@ -0,0 +1,53 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use shared::{
+        ApiError, GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger,
+            server::{ExportedServer, GetServer},
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = ExportedServer),
+        (status = UNAUTHORIZED, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+        activity_logger: GetAdminActivityLogger,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("servers.read")?;
+
+        let server_uuid = server.uuid;
+        let exported = server.0.into_exported(&state.database).await?;
+
+        activity_logger
+            .log(
+                "server:export",
+                serde_json::json!({
+                    "uuid": server_uuid,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(exported).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
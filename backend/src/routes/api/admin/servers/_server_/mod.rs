@@ -14,8 +14,12 @@ use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod allocations;
 mod clear_state;
+mod env;
+mod export;
 mod logs;
 mod mounts;
+mod reinstall_history;
+mod resource_alerts;
 mod transfer;
 mod variables;
 
@@ -93,10 +97,15 @@ mod delete {
     use shared::{
         ApiError, GetState,
         models::{
-            DeletableModel, admin_activity::GetAdminActivityLogger, server::GetServer,
-            server_backup::ServerBackup, user::GetPermissionManager,
+            DeletableModel,
+            admin_activity::GetAdminActivityLogger,
+            admin_approval::{AdminApproval, AdminApprovalCheck},
+            server::GetServer,
+            server_backup::ServerBackup,
+            user::{GetPermissionManager, GetUser},
         },
         response::{ApiResponse, ApiResponseResult},
+        settings::approvals::ApprovalAction,
     };
     use utoipa::ToSchema;
 
@@ -109,8 +118,14 @@ mod delete {
     #[derive(ToSchema, Serialize)]
     struct Response {}
 
+    #[derive(ToSchema, Serialize)]
+    struct ResponseApprovalRequired {
+        approval: shared::models::admin_approval::AdminApiAdminApproval,
+    }
+
     #[utoipa::path(delete, path = "/", responses(
         (status = OK, body = inline(Response)),
+        (status = ACCEPTED, description = "The deletion has to be approved by another admin first", body = inline(ResponseApprovalRequired)),
         (status = NOT_FOUND, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
@@ -122,6 +137,7 @@ mod delete {
     ), request_body = inline(Payload))]
     pub async fn route(
         state: GetState,
+        user: GetUser,
         permissions: GetPermissionManager,
         server: GetServer,
         activity_logger: GetAdminActivityLogger,
@@ -129,6 +145,28 @@ mod delete {
     ) -> ApiResponseResult {
         permissions.has_admin_permission("servers.delete")?;
 
+        let approval = match AdminApproval::check(
+            &state,
+            ApprovalAction::ServerDelete,
+            server.uuid,
+            serde_json::json!({
+                "force": data.force,
+                "delete_backups": data.delete_backups,
+            }),
+            user.uuid,
+        )
+        .await?
+        {
+            AdminApprovalCheck::Pending(approval) => {
+                return ApiResponse::new_serialized(ResponseApprovalRequired {
+                    approval: approval.into_admin_api_object(),
+                })
+                .with_status(StatusCode::ACCEPTED)
+                .ok();
+            }
+            approval => approval,
+        };
+
         let backups = if data.delete_backups {
             ServerBackup::all_by_server_uuid(&state.database, server.uuid).await?
         } else {
@@ -149,6 +187,8 @@ mod delete {
                 .ok();
         }
 
+        approval.consume(&state.database).await?;
+
         if data.delete_backups {
             for backup in backups {
                 let backup_uuid = backup.uuid;
@@ -275,7 +315,11 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .nest("/transfer", transfer::router(state))
         .nest("/allocations", allocations::router(state))
         .nest("/clear-state", clear_state::router(state))
+        .nest("/env", env::router(state))
+        .nest("/export", export::router(state))
         .nest("/logs", logs::router(state))
+        .nest("/reinstall-history", reinstall_history::router(state))
+        .nest("/resource-alerts", resource_alerts::router(state))
         .route_layer(axum::middleware::from_fn_with_state(state.clone(), auth))
         .with_state(state.clone())
 }
@@ -0,0 +1,207 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            server::GetServer, server_resource_alert::ServerResourceAlert,
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        resource_alert: Option<shared::models::server_resource_alert::AdminApiServerResourceAlert>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("servers.read")?;
+
+        let resource_alert =
+            ServerResourceAlert::by_server_uuid(&state.database, server.uuid).await?;
+
+        ApiResponse::new_serialized(Response {
+            resource_alert: resource_alert.map(|alert| alert.into_admin_api_object()),
+        })
+        .ok()
+    }
+}
+
+mod put {
+    use axum::http::StatusCode;
+    use garde::Validate;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger, server::GetServer,
+            server_resource_alert::ServerResourceAlert, user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct Payload {
+        /// Relative to the cpu limit of the server, requires the server to have one.
+        #[garde(range(min = 1, max = 100))]
+        #[schema(minimum = 1, maximum = 100)]
+        cpu_threshold: Option<i16>,
+        #[garde(range(min = 1, max = 100))]
+        #[schema(minimum = 1, maximum = 100)]
+        memory_threshold: Option<i16>,
+        #[garde(range(min = 30, max = 86400))]
+        #[schema(minimum = 30, maximum = 86400)]
+        window_seconds: i32,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        resource_alert: shared::models::server_resource_alert::AdminApiServerResourceAlert,
+    }
+
+    #[utoipa::path(put, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+        (status = UNAUTHORIZED, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+        activity_logger: GetAdminActivityLogger,
+        shared::Payload(data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&data) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        permissions.has_admin_permission("servers.update")?;
+
+        if data.cpu_threshold.is_none() && data.memory_threshold.is_none() {
+            return ApiResponse::error("at least one threshold must be set")
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        if data.cpu_threshold.is_some() && server.cpu == 0 {
+            return ApiResponse::error("cpu alerts require the server to have a cpu limit")
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        let resource_alert = ServerResourceAlert::upsert(
+            &state.database,
+            server.uuid,
+            data.cpu_threshold,
+            data.memory_threshold,
+            data.window_seconds,
+        )
+        .await?;
+
+        activity_logger
+            .log(
+                "server:resource-alerts.update",
+                serde_json::json!({
+                    "uuid": server.uuid,
+                    "cpu_threshold": resource_alert.cpu_threshold,
+                    "memory_threshold": resource_alert.memory_threshold,
+                    "window_seconds": resource_alert.window_seconds,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            resource_alert: resource_alert.into_admin_api_object(),
+        })
+        .ok()
+    }
+}
+
+mod delete {
+    use axum::http::StatusCode;
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger, server::GetServer,
+            server_resource_alert::ServerResourceAlert, user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {}
+
+    #[utoipa::path(delete, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+        activity_logger: GetAdminActivityLogger,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("servers.update")?;
+
+        if !ServerResourceAlert::delete_by_server_uuid(&state.database, server.uuid).await? {
+            return ApiResponse::error("resource alert not found")
+                .with_status(StatusCode::NOT_FOUND)
+                .ok();
+        }
+
+        activity_logger
+            .log(
+                "server:resource-alerts.delete",
+                serde_json::json!({
+                    "uuid": server.uuid,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {}).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .routes(routes!(put::route))
+        .routes(routes!(delete::route))
+        .with_state(state.clone())
+}
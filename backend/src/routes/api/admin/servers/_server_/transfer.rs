@@ -7,8 +7,12 @@ mod post {
     use shared::{
         ApiError, GetState,
         models::{
-            ByUuid, admin_activity::GetAdminActivityLogger, node::Node, server::GetServer,
-            user::GetPermissionManager,
+            ByUuid,
+            admin_activity::GetAdminActivityLogger,
+            job::{Job, JobPayload, ServerTransferJob},
+            node::Node,
+            server::GetServer,
+            user::{GetPermissionManager, GetUser},
         },
         response::{ApiResponse, ApiResponseResult},
     };
@@ -29,7 +33,9 @@ mod post {
     }
 
     #[derive(ToSchema, Serialize)]
-    struct Response {}
+    struct Response {
+        job: shared::models::job::AdminApiJob,
+    }
 
     #[utoipa::path(post, path = "/", responses(
         (status = ACCEPTED, body = inline(Response)),
@@ -45,6 +51,7 @@ mod post {
     pub async fn route(
         state: GetState,
         permissions: GetPermissionManager,
+        user: GetUser,
         server: GetServer,
         activity_logger: GetAdminActivityLogger,
         shared::Payload(data): shared::Payload<Payload>,
@@ -73,38 +80,39 @@ mod post {
             }
         };
 
-        let server_uuid = server.uuid;
-        let destination_node_uuid = destination_node.uuid;
-        server
-            .0
-            .transfer(
-                &state,
-                shared::models::server::ServerTransferOptions {
-                    destination_node,
-                    allocation_uuid: data.allocation_uuid,
-                    allocation_uuids: data.allocation_uuids,
-                    backups: data.backups,
-                    delete_source_backups: data.delete_source_backups,
-                    archive_format: data.archive_format,
-                    compression_level: data.compression_level,
-                    multiplex_channels: data.multiplex_channels,
-                },
-            )
-            .await?;
+        let job = Job::create(
+            &state.database,
+            Some(user.uuid),
+            &JobPayload::ServerTransfer(ServerTransferJob {
+                server_uuid: server.uuid,
+                destination_node_uuid: destination_node.uuid,
+                allocation_uuid: data.allocation_uuid,
+                allocation_uuids: data.allocation_uuids,
+                backups: data.backups,
+                delete_source_backups: data.delete_source_backups,
+                archive_format: data.archive_format,
+                compression_level: data.compression_level,
+                multiplex_channels: data.multiplex_channels,
+            }),
+        )
+        .await?;
 
         activity_logger
             .log(
                 "server:transfer",
                 serde_json::json!({
-                    "uuid": server_uuid,
-                    "destination_node_uuid": destination_node_uuid,
+                    "uuid": server.uuid,
+                    "destination_node_uuid": destination_node.uuid,
+                    "job_uuid": job.uuid,
                 }),
             )
             .await;
 
-        ApiResponse::new_serialized(Response {})
-            .with_status(StatusCode::ACCEPTED)
-            .ok()
+        ApiResponse::new_serialized(Response {
+            job: job.into_admin_api_object(&state.database).await?,
+        })
+        .with_status(StatusCode::ACCEPTED)
+        .ok()
     }
 }
 
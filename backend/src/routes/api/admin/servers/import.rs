@@ -0,0 +1,275 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod post {
+    use axum::http::StatusCode;
+    use garde::Validate;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{
+            CreatableModel, admin_activity::GetAdminActivityLogger, mount::Mount, nest::Nest,
+            nest_egg::NestEgg, nest_egg_variable::NestEggVariable, node_allocation::NodeAllocation,
+            server::Server, server_mount::ServerMount, user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use std::collections::HashSet;
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct Payload {
+        #[garde(skip)]
+        node_uuid: uuid::Uuid,
+        #[garde(skip)]
+        owner_uuid: uuid::Uuid,
+        #[garde(skip)]
+        backup_configuration_uuid: Option<uuid::Uuid>,
+
+        /// When no allocations are given, the exported allocations are assigned if they are available on the node.
+        #[garde(skip)]
+        allocation_uuid: Option<uuid::Uuid>,
+        #[garde(skip)]
+        allocation_uuids: Vec<uuid::Uuid>,
+
+        #[garde(skip)]
+        start_on_completion: bool,
+        #[garde(skip)]
+        skip_installer: bool,
+
+        #[garde(length(max = 255))]
+        #[schema(max_length = 255)]
+        external_id: Option<compact_str::CompactString>,
+
+        #[garde(dive)]
+        server: shared::models::server::ExportedServer,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        server: shared::models::server::AdminApiServer,
+        skipped_mounts: Vec<compact_str::CompactString>,
+        skipped_allocations: Vec<compact_str::CompactString>,
+    }
+
+    #[utoipa::path(post, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+        (status = NOT_FOUND, body = ApiError),
+        (status = CONFLICT, body = ApiError),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        activity_logger: GetAdminActivityLogger,
+        shared::Payload(data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&data) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        permissions.has_admin_permission("servers.create")?;
+
+        let nest = match Nest::by_name(&state.database, &data.server.egg.nest).await? {
+            Some(nest) => nest,
+            None => {
+                return ApiResponse::error("nest not found")
+                    .with_status(StatusCode::NOT_FOUND)
+                    .ok();
+            }
+        };
+
+        let egg =
+            match NestEgg::by_nest_uuid_name(&state.database, nest.uuid, &data.server.egg.name)
+                .await?
+            {
+                Some(egg) => egg,
+                None => {
+                    return ApiResponse::error("egg not found")
+                        .with_status(StatusCode::NOT_FOUND)
+                        .ok();
+                }
+            };
+
+        let mut allocation_uuid = data.allocation_uuid;
+        let mut allocation_uuids = data.allocation_uuids;
+        let mut allocation_notes = Vec::new();
+        let mut skipped_allocations = Vec::new();
+
+        if allocation_uuid.is_none() && allocation_uuids.is_empty() {
+            for exported_allocation in &data.server.allocations {
+                let allocation = match NodeAllocation::available_by_node_uuid_ip_port(
+                    &state.database,
+                    data.node_uuid,
+                    &exported_allocation.ip,
+                    exported_allocation.port,
+                )
+                .await?
+                {
+                    Some(allocation) => allocation,
+                    None => {
+                        skipped_allocations.push(compact_str::format_compact!(
+                            "{}:{}",
+                            exported_allocation.ip,
+                            exported_allocation.port
+                        ));
+                        continue;
+                    }
+                };
+
+                if exported_allocation.primary && allocation_uuid.is_none() {
+                    allocation_uuid = Some(allocation.uuid);
+                } else {
+                    allocation_uuids.push(allocation.uuid);
+                }
+
+                if let Some(notes) = &exported_allocation.notes {
+                    allocation_notes.push((allocation.uuid, notes));
+                }
+            }
+        }
+
+        let allocations = allocation_uuid
+            .iter()
+            .chain(allocation_uuids.iter())
+            .collect::<HashSet<_>>();
+        egg.config_allocations.ensure_minimum(allocations.len())?;
+
+        let variables = NestEggVariable::all_by_egg_uuid(&state.database, egg.uuid).await?;
+        let server_variables = match NestEggVariable::validate_server_values(
+            &variables,
+            &data
+                .server
+                .variables
+                .iter()
+                .map(|v| (v.env_variable.as_str(), v.value.as_str()))
+                .collect::<Vec<_>>(),
+        ) {
+            Ok(server_variables) => server_variables,
+            Err(error) => {
+                return ApiResponse::error(&error)
+                    .with_status(StatusCode::BAD_REQUEST)
+                    .ok();
+            }
+        };
+
+        let options = shared::models::server::CreateServerOptions {
+            node_uuid: data.node_uuid,
+            owner_uuid: data.owner_uuid,
+            egg_uuid: egg.uuid,
+            backup_configuration_uuid: data.backup_configuration_uuid,
+            allocation_uuid,
+            allocation_uuids: allocation_uuids.clone(),
+            start_on_completion: data.start_on_completion,
+            skip_installer: data.skip_installer,
+            external_id: data.external_id,
+            name: data.server.name,
+            description: data.server.description,
+            limits: data.server.limits,
+            pinned_cpus: data.server.pinned_cpus,
+            startup: data.server.startup,
+            image: data.server.image,
+            timezone: data.server.timezone,
+            hugepages_passthrough_enabled: data.server.hugepages_passthrough_enabled,
+            kvm_passthrough_enabled: data.server.kvm_passthrough_enabled,
+            feature_limits: data.server.feature_limits,
+            variables: server_variables,
+        };
+        let server = match Server::create(&state, options).await {
+            Ok(server) => server,
+            Err(err) if err.is_unique_violation() => {
+                return ApiResponse::error(
+                    "server with allocation(s) or external id already exists",
+                )
+                .with_status(StatusCode::CONFLICT)
+                .ok();
+            }
+            Err(err) => return ApiResponse::from(err).ok(),
+        };
+
+        for (allocation_uuid, notes) in allocation_notes {
+            sqlx::query(
+                r#"
+                UPDATE server_allocations
+                SET notes = $1
+                WHERE server_allocations.server_uuid = $2 AND server_allocations.allocation_uuid = $3
+                "#,
+            )
+            .bind(notes.as_str())
+            .bind(server.uuid)
+            .bind(allocation_uuid)
+            .execute(state.database.write())
+            .await?;
+        }
+
+        let mut skipped_mounts = Vec::new();
+        for mount_name in data.server.mounts {
+            let mount = match Mount::by_node_uuid_egg_uuid_name(
+                &state.database,
+                data.node_uuid,
+                egg.uuid,
+                &mount_name,
+            )
+            .await?
+            {
+                Some(mount) => mount,
+                None => {
+                    skipped_mounts.push(mount_name);
+                    continue;
+                }
+            };
+
+            let options = shared::models::server_mount::CreateServerMountOptions {
+                server_uuid: server.uuid,
+                mount_uuid: mount.uuid,
+            };
+            if let Err(err) = ServerMount::create(&state, options).await {
+                tracing::warn!(
+                    server = %server.uuid,
+                    mount = %mount.uuid,
+                    "failed to attach imported mount: {:?}",
+                    err
+                );
+
+                skipped_mounts.push(mount_name);
+            }
+        }
+
+        activity_logger
+            .log(
+                "server:import",
+                serde_json::json!({
+                    "uuid": server.uuid,
+                    "node_uuid": server.node.uuid,
+                    "owner_uuid": server.owner.uuid,
+                    "egg_uuid": server.egg.uuid,
+
+                    "allocation_uuid": allocation_uuid,
+                    "allocation_uuids": allocation_uuids,
+                    "external_id": server.external_id,
+
+                    "name": server.name,
+                    "skipped_mounts": skipped_mounts,
+                    "skipped_allocations": skipped_allocations,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            server: server
+                .into_admin_api_object(&state.database, &state.storage.retrieve_urls().await?)
+                .await?,
+            skipped_mounts,
+            skipped_allocations,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(post::route))
+        .with_state(state.clone())
+}
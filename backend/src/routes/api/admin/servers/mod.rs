@@ -3,6 +3,7 @@ use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod _server_;
 mod external;
+mod import;
 
 mod get {
     use axum::{extract::Query, http::StatusCode};
@@ -81,12 +82,12 @@ mod post {
     use shared::{
         ApiError, GetState,
         models::{
-            CreatableModel, admin_activity::GetAdminActivityLogger,
+            ByUuid, CreatableModel, admin_activity::GetAdminActivityLogger, nest_egg::NestEgg,
             nest_egg_variable::NestEggVariable, server::Server, user::GetPermissionManager,
         },
         response::{ApiResponse, ApiResponseResult},
     };
-    use std::collections::HashMap;
+    use std::collections::HashSet;
     use utoipa::ToSchema;
 
     #[derive(ToSchema, Validate, Serialize, Deserialize)]
@@ -141,9 +142,12 @@ mod post {
         #[garde(length(chars, min = 2, max = 255))]
         #[schema(min_length = 2, max_length = 255)]
         image: compact_str::CompactString,
-        #[garde(skip)]
-        #[schema(value_type = Option<String>)]
-        timezone: Option<chrono_tz::Tz>,
+        #[garde(
+            length(chars, min = 3, max = 255),
+            inner(custom(shared::utils::validate_timezone))
+        )]
+        #[schema(min_length = 3, max_length = 255)]
+        timezone: Option<compact_str::CompactString>,
 
         #[garde(skip)]
         hugepages_passthrough_enabled: bool,
@@ -182,31 +186,26 @@ mod post {
 
         permissions.has_admin_permission("servers.create")?;
 
-        let variables = NestEggVariable::all_by_egg_uuid(&state.database, data.egg_uuid).await?;
+        if let Some(egg) = NestEgg::by_uuid_optional(&state.database, data.egg_uuid).await? {
+            let allocations = data
+                .allocation_uuid
+                .iter()
+                .chain(data.allocation_uuids.iter())
+                .collect::<HashSet<_>>();
 
-        let mut validator_variables = HashMap::new();
-        validator_variables.reserve(variables.len());
-
-        for variable in variables.iter() {
-            validator_variables.insert(
-                variable.env_variable.as_str(),
-                (
-                    variable.rules.as_slice(),
-                    if let Some(value) = data
-                        .variables
-                        .iter()
-                        .find(|v| v.env_variable == variable.env_variable)
-                    {
-                        value.value.as_str()
-                    } else {
-                        variable.default_value.as_ref().map_or("", |v| v.as_str())
-                    },
-                ),
-            );
+            egg.config_allocations.ensure_minimum(allocations.len())?;
         }
 
-        let validator = match rule_validator::Validator::new(validator_variables) {
-            Ok(validator) => validator,
+        let variables = NestEggVariable::all_by_egg_uuid(&state.database, data.egg_uuid).await?;
+        let server_variables = match NestEggVariable::validate_server_values(
+            &variables,
+            &data
+                .variables
+                .iter()
+                .map(|v| (v.env_variable.as_str(), v.value.as_str()))
+                .collect::<Vec<_>>(),
+        ) {
+            Ok(server_variables) => server_variables,
             Err(error) => {
                 return ApiResponse::error(&error)
                     .with_status(StatusCode::BAD_REQUEST)
@@ -214,27 +213,6 @@ mod post {
             }
         };
 
-        if let Err(error) = validator.validate() {
-            return ApiResponse::error(&error)
-                .with_status(StatusCode::BAD_REQUEST)
-                .ok();
-        }
-
-        let mut server_variables = HashMap::new();
-        server_variables.reserve(variables.len());
-
-        for data_variable in &data.variables {
-            let variable_uuid = match variables
-                .iter()
-                .find(|v| v.env_variable == data_variable.env_variable)
-            {
-                Some(variable) => variable.uuid,
-                None => continue,
-            };
-
-            server_variables.insert(variable_uuid, data_variable.value.clone().into());
-        }
-
         let options = shared::models::server::CreateServerOptions {
             node_uuid: data.node_uuid,
             owner_uuid: data.owner_uuid,
@@ -317,5 +295,6 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .routes(routes!(post::route))
         .nest("/{server}", _server_::router(state))
         .nest("/external", external::router(state))
+        .nest("/import", import::router(state))
         .with_state(state.clone())
 }
@@ -37,7 +37,9 @@ mod put {
     use serde::{Deserialize, Serialize};
     use shared::{
         ApiError, GetState,
-        models::{admin_activity::GetAdminActivityLogger, user::GetPermissionManager},
+        models::{
+            ByUuid, admin_activity::GetAdminActivityLogger, role::Role, user::GetPermissionManager,
+        },
         response::{ApiResponse, ApiResponseResult},
     };
     use utoipa::ToSchema;
@@ -58,9 +60,13 @@ mod put {
         #[garde(skip)]
         two_factor_requirement: Option<shared::settings::app::TwoFactorRequirement>,
         #[garde(skip)]
+        avatar_fallback: Option<shared::settings::app::AvatarFallback>,
+        #[garde(skip)]
         telemetry_enabled: Option<bool>,
         #[garde(skip)]
         registration_enabled: Option<bool>,
+        #[garde(range(min = 5, max = 1440))]
+        password_reset_expiry_minutes: Option<u16>,
     }
 
     #[derive(ToSchema, Validate, Deserialize)]
@@ -81,6 +87,12 @@ mod put {
         max_file_manager_search_results: Option<u64>,
         #[garde(skip)]
         max_schedules_step_count: Option<u64>,
+        #[garde(range(max = 300))]
+        power_state_cache_seconds: Option<u64>,
+        #[garde(skip)]
+        name_uniqueness: Option<shared::settings::server::ServerNameUniqueness>,
+        #[garde(skip)]
+        render_description_markdown: Option<bool>,
 
         #[garde(skip)]
         allow_overwriting_custom_docker_image: Option<bool>,
@@ -90,6 +102,15 @@ mod put {
         allow_viewing_installation_logs: Option<bool>,
         #[garde(skip)]
         allow_viewing_transfer_progress: Option<bool>,
+
+        #[garde(skip)]
+        resolve_allocation_aliases: Option<bool>,
+
+        #[garde(
+            length(max = 32),
+            inner(inner(length(chars, min = 1, max = 255), pattern("^[a-zA-Z0-9.:-]+$")))
+        )]
+        allowed_image_registries: Option<Vec<compact_str::CompactString>>,
     }
 
     #[derive(ToSchema, Validate, Deserialize)]
@@ -100,6 +121,8 @@ mod put {
         user_log_retention_days: Option<u16>,
         #[garde(range(min = 1, max = 3650))]
         server_log_retention_days: Option<u16>,
+        #[garde(range(min = 100, max = 100000))]
+        log_prune_batch_size: Option<u32>,
 
         #[garde(skip)]
         server_log_admin_activity: Option<bool>,
@@ -107,6 +130,101 @@ mod put {
         server_log_schedule_activity: Option<bool>,
     }
 
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct PayloadPagination {
+        #[garde(range(min = 1, max = 1000))]
+        default_per_page: Option<u16>,
+        #[garde(range(min = 1, max = 1000))]
+        max_per_page: Option<u16>,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct PayloadMail {
+        #[garde(range(min = 1, max = 64))]
+        smtp_pool_size: Option<u32>,
+        #[garde(range(max = 10))]
+        max_retries: Option<u32>,
+        #[garde(range(min = 100, max = 60000))]
+        retry_backoff_ms: Option<u64>,
+        #[garde(range(min = 1, max = 10000))]
+        queue_size: Option<u32>,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct PayloadExternalId {
+        #[garde(skip)]
+        format: Option<shared::settings::external_id::ExternalIdFormat>,
+        #[garde(length(max = 255))]
+        pattern: Option<compact_str::CompactString>,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct PayloadJwt {
+        #[garde(range(min = 60, max = 3600))]
+        two_factor_ttl: Option<u32>,
+        #[garde(range(min = 60, max = 86400))]
+        websocket_ttl: Option<u32>,
+        #[garde(range(min = 60, max = 86400))]
+        file_ttl: Option<u32>,
+        #[garde(range(min = 60, max = 86400))]
+        transfer_ttl: Option<u32>,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct PayloadApprovals {
+        #[garde(skip)]
+        actions: Option<Vec<shared::settings::approvals::ApprovalAction>>,
+        #[garde(range(min = 5, max = 10080))]
+        expiry_minutes: Option<u16>,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct PayloadUsers {
+        #[garde(skip)]
+        #[serde(default, with = "::serde_with::rust::double_option")]
+        default_role_uuid: Option<Option<uuid::Uuid>>,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct PayloadFeatureFlags {
+        #[garde(inner(custom(shared::settings::feature_flags::validate_feature_flags)))]
+        flags: Option<
+            std::collections::BTreeMap<
+                compact_str::CompactString,
+                shared::settings::feature_flags::FeatureFlag,
+            >,
+        >,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct PayloadSupport {
+        #[garde(email, length(max = 255))]
+        #[serde(default, with = "::serde_with::rust::double_option")]
+        email: Option<Option<compact_str::CompactString>>,
+        #[garde(
+            inner(inner(custom(shared::utils::validate_http_url))),
+            length(max = 255)
+        )]
+        #[serde(default, with = "::serde_with::rust::double_option")]
+        docs_url: Option<Option<compact_str::CompactString>>,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct PayloadBranding {
+        #[garde(
+            inner(inner(custom(shared::utils::validate_http_url))),
+            length(max = 255)
+        )]
+        #[serde(default, with = "::serde_with::rust::double_option")]
+        logo_url: Option<Option<compact_str::CompactString>>,
+        #[garde(pattern("^#[0-9a-fA-F]{6}$"))]
+        #[serde(default, with = "::serde_with::rust::double_option")]
+        primary_color: Option<Option<compact_str::CompactString>>,
+        #[garde(length(chars, max = 255))]
+        #[serde(default, with = "::serde_with::rust::double_option")]
+        footer_text: Option<Option<compact_str::CompactString>>,
+    }
+
     #[derive(ToSchema, Validate, Deserialize)]
     pub struct Payload {
         #[garde(skip)]
@@ -131,6 +249,33 @@ mod put {
         #[schema(inline)]
         #[garde(dive)]
         activity: Option<PayloadActivity>,
+        #[schema(inline)]
+        #[garde(dive)]
+        pagination: Option<PayloadPagination>,
+        #[schema(inline)]
+        #[garde(dive)]
+        support: Option<PayloadSupport>,
+        #[schema(inline)]
+        #[garde(dive)]
+        branding: Option<PayloadBranding>,
+        #[schema(inline)]
+        #[garde(dive)]
+        mail: Option<PayloadMail>,
+        #[schema(inline)]
+        #[garde(dive)]
+        external_id: Option<PayloadExternalId>,
+        #[schema(inline)]
+        #[garde(dive)]
+        jwt: Option<PayloadJwt>,
+        #[schema(inline)]
+        #[garde(dive)]
+        approvals: Option<PayloadApprovals>,
+        #[schema(inline)]
+        #[garde(dive)]
+        users: Option<PayloadUsers>,
+        #[schema(inline)]
+        #[garde(dive)]
+        feature_flags: Option<PayloadFeatureFlags>,
     }
 
     #[derive(ToSchema, Serialize)]
@@ -153,6 +298,33 @@ mod put {
 
         permissions.has_admin_permission("settings.update")?;
 
+        if let Some(external_id) = &data.external_id {
+            let mut external_id_settings = state.settings.get().await?.external_id.clone();
+            if let Some(format) = external_id.format {
+                external_id_settings.format = format;
+            }
+            if let Some(pattern) = &external_id.pattern {
+                external_id_settings.pattern = pattern.clone();
+            }
+
+            if let Err(err) = external_id_settings.regex() {
+                return ApiResponse::error(format!("invalid external id pattern: {err}"))
+                    .with_status(StatusCode::BAD_REQUEST)
+                    .ok();
+            }
+        }
+
+        if let Some(users) = &data.users
+            && let Some(Some(default_role_uuid)) = users.default_role_uuid
+            && Role::by_uuid_optional_cached(&state.database, default_role_uuid)
+                .await?
+                .is_none()
+        {
+            return ApiResponse::error("default role not found")
+                .with_status(StatusCode::NOT_FOUND)
+                .ok();
+        }
+
         let mut settings = state.settings.get_mut().await?;
 
         if let Some(oobe_step) = data.oobe_step {
@@ -189,12 +361,18 @@ mod put {
             if let Some(two_factor_requirement) = app.two_factor_requirement {
                 settings.app.two_factor_requirement = two_factor_requirement;
             }
+            if let Some(avatar_fallback) = app.avatar_fallback {
+                settings.app.avatar_fallback = avatar_fallback;
+            }
             if let Some(telemetry_enabled) = app.telemetry_enabled {
                 settings.app.telemetry_enabled = telemetry_enabled;
             }
             if let Some(registration_enabled) = app.registration_enabled {
                 settings.app.registration_enabled = registration_enabled;
             }
+            if let Some(password_reset_expiry_minutes) = app.password_reset_expiry_minutes {
+                settings.app.password_reset_expiry_minutes = password_reset_expiry_minutes;
+            }
         }
         if let Some(webauthn) = data.webauthn {
             if let Some(rp_id) = webauthn.rp_id {
@@ -220,6 +398,15 @@ mod put {
             if let Some(max_schedules_step_count) = server.max_schedules_step_count {
                 settings.server.max_schedules_step_count = max_schedules_step_count;
             }
+            if let Some(power_state_cache_seconds) = server.power_state_cache_seconds {
+                settings.server.power_state_cache_seconds = power_state_cache_seconds;
+            }
+            if let Some(name_uniqueness) = server.name_uniqueness {
+                settings.server.name_uniqueness = name_uniqueness;
+            }
+            if let Some(render_description_markdown) = server.render_description_markdown {
+                settings.server.render_description_markdown = render_description_markdown;
+            }
             if let Some(allow_overwriting_custom_docker_image) =
                 server.allow_overwriting_custom_docker_image
             {
@@ -235,6 +422,12 @@ mod put {
             if let Some(allow_viewing_transfer_progress) = server.allow_viewing_transfer_progress {
                 settings.server.allow_viewing_transfer_progress = allow_viewing_transfer_progress;
             }
+            if let Some(resolve_allocation_aliases) = server.resolve_allocation_aliases {
+                settings.server.resolve_allocation_aliases = resolve_allocation_aliases;
+            }
+            if let Some(allowed_image_registries) = server.allowed_image_registries {
+                settings.server.allowed_image_registries = allowed_image_registries;
+            }
         }
         if let Some(activity) = data.activity {
             if let Some(admin_log_retention_days) = activity.admin_log_retention_days {
@@ -246,6 +439,9 @@ mod put {
             if let Some(server_log_retention_days) = activity.server_log_retention_days {
                 settings.activity.server_log_retention_days = server_log_retention_days;
             }
+            if let Some(log_prune_batch_size) = activity.log_prune_batch_size {
+                settings.activity.log_prune_batch_size = log_prune_batch_size;
+            }
             if let Some(server_log_admin_activity) = activity.server_log_admin_activity {
                 settings.activity.server_log_admin_activity = server_log_admin_activity;
             }
@@ -253,6 +449,102 @@ mod put {
                 settings.activity.server_log_schedule_activity = server_log_schedule_activity;
             }
         }
+        if let Some(pagination) = data.pagination {
+            if let Some(default_per_page) = pagination.default_per_page {
+                settings.pagination.default_per_page = default_per_page;
+            }
+            if let Some(max_per_page) = pagination.max_per_page {
+                settings.pagination.max_per_page = max_per_page;
+            }
+
+            if settings.pagination.max_per_page < settings.pagination.default_per_page {
+                return ApiResponse::error(
+                    "pagination.max_per_page must be greater than or equal to pagination.default_per_page",
+                )
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+            }
+        }
+
+        if let Some(support) = data.support {
+            if let Some(email) = support.email {
+                settings.support.email = email;
+            }
+            if let Some(docs_url) = support.docs_url {
+                settings.support.docs_url = docs_url;
+            }
+        }
+        if let Some(branding) = data.branding {
+            if let Some(logo_url) = branding.logo_url {
+                settings.branding.logo_url = logo_url;
+            }
+            if let Some(primary_color) = branding.primary_color {
+                settings.branding.primary_color = primary_color;
+            }
+            if let Some(footer_text) = branding.footer_text {
+                settings.branding.footer_text = footer_text;
+            }
+        }
+
+        if let Some(mail) = data.mail {
+            if let Some(smtp_pool_size) = mail.smtp_pool_size {
+                settings.mail.smtp_pool_size = smtp_pool_size;
+            }
+            if let Some(max_retries) = mail.max_retries {
+                settings.mail.max_retries = max_retries;
+            }
+            if let Some(retry_backoff_ms) = mail.retry_backoff_ms {
+                settings.mail.retry_backoff_ms = retry_backoff_ms;
+            }
+            if let Some(queue_size) = mail.queue_size {
+                settings.mail.queue_size = queue_size;
+            }
+        }
+
+        if let Some(external_id) = data.external_id {
+            if let Some(format) = external_id.format {
+                settings.external_id.format = format;
+            }
+            if let Some(pattern) = external_id.pattern {
+                settings.external_id.pattern = pattern;
+            }
+        }
+
+        if let Some(jwt) = data.jwt {
+            if let Some(two_factor_ttl) = jwt.two_factor_ttl {
+                settings.jwt.two_factor_ttl = two_factor_ttl;
+            }
+            if let Some(websocket_ttl) = jwt.websocket_ttl {
+                settings.jwt.websocket_ttl = websocket_ttl;
+            }
+            if let Some(file_ttl) = jwt.file_ttl {
+                settings.jwt.file_ttl = file_ttl;
+            }
+            if let Some(transfer_ttl) = jwt.transfer_ttl {
+                settings.jwt.transfer_ttl = transfer_ttl;
+            }
+        }
+
+        if let Some(approvals) = data.approvals {
+            if let Some(actions) = approvals.actions {
+                settings.approvals.actions = actions;
+            }
+            if let Some(expiry_minutes) = approvals.expiry_minutes {
+                settings.approvals.expiry_minutes = expiry_minutes;
+            }
+        }
+
+        if let Some(users) = data.users
+            && let Some(default_role_uuid) = users.default_role_uuid
+        {
+            settings.users.default_role_uuid = default_role_uuid;
+        }
+
+        if let Some(feature_flags) = data.feature_flags
+            && let Some(flags) = feature_flags.flags
+        {
+            settings.feature_flags.flags = flags;
+        }
 
         let settings_json = settings.censored();
         settings.save().await?;
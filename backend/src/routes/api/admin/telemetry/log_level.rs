@@ -0,0 +1,81 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod put {
+    use axum::http::StatusCode;
+    use garde::Validate;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError,
+        models::{admin_activity::GetAdminActivityLogger, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct Payload {
+        /// The new filter directives, e.g. `info,shared::models::node=debug`.
+        /// `null` restores the default filter.
+        #[garde(length(chars, min = 1, max = 1024))]
+        #[schema(min_length = 1, max_length = 1024)]
+        directives: Option<compact_str::CompactString>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        directives: compact_str::CompactString,
+    }
+
+    #[utoipa::path(put, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        permissions: GetPermissionManager,
+        activity_logger: GetAdminActivityLogger,
+        shared::Payload(data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&data) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        permissions.has_admin_permission("settings.update")?;
+
+        let old_directives = shared::telemetry::log_filter_directives();
+        let directives =
+            match shared::telemetry::set_log_filter_directives(data.directives.as_deref()) {
+                Ok(directives) => directives,
+                Err(err) => {
+                    return ApiResponse::error(format!("invalid log filter directives: {err}"))
+                        .with_status(StatusCode::BAD_REQUEST)
+                        .ok();
+                }
+            };
+
+        tracing::info!(
+            "log filter changed from `{}` to `{}`",
+            old_directives,
+            directives
+        );
+
+        activity_logger
+            .log(
+                "telemetry:log-level.update",
+                serde_json::json!({
+                    "old_directives": old_directives,
+                    "directives": directives,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response { directives }).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(put::route))
+        .with_state(state.clone())
+}
@@ -0,0 +1,10 @@
+use super::State;
+use utoipa_axum::router::OpenApiRouter;
+
+mod log_level;
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .nest("/log-level", log_level::router(state))
+        .with_state(state.clone())
+}
@@ -0,0 +1,142 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use crate::routes::api::admin::users::_user_::GetParamUser;
+    use axum::{extract::Query, http::StatusCode};
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            Pagination, PaginationParamsWithSearch, user::GetPermissionManager,
+            user_api_key::UserApiKey,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        #[schema(inline)]
+        api_keys: Pagination<shared::models::user_api_key::ApiUserApiKey>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ), params(
+        (
+            "user" = uuid::Uuid,
+            description = "The user ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "page" = i64, Query,
+            description = "The page number",
+            example = "1",
+        ),
+        (
+            "per_page" = i64, Query,
+            description = "The number of items per page",
+            example = "10",
+        ),
+        (
+            "search" = Option<String>, Query,
+            description = "Search term for items",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        user: GetParamUser,
+        Query(params): Query<PaginationParamsWithSearch>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&params) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        permissions.has_admin_permission("users.api-keys")?;
+
+        let api_keys = UserApiKey::by_user_uuid_with_pagination(
+            &state.database,
+            user.uuid,
+            params.page,
+            params.per_page,
+            params.search.as_deref(),
+        )
+        .await?;
+
+        ApiResponse::new_serialized(Response {
+            api_keys: Pagination {
+                total: api_keys.total,
+                per_page: api_keys.per_page,
+                page: api_keys.page,
+                data: api_keys
+                    .data
+                    .into_iter()
+                    .map(|api_key| api_key.into_api_object())
+                    .collect(),
+            },
+        })
+        .ok()
+    }
+}
+
+mod delete {
+    use crate::routes::api::admin::users::_user_::GetParamUser;
+    use serde::Serialize;
+    use shared::{
+        GetState,
+        models::{
+            admin_activity::GetAdminActivityLogger, user::GetPermissionManager,
+            user_api_key::UserApiKey,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        revoked: u64,
+    }
+
+    #[utoipa::path(delete, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ), params(
+        (
+            "user" = uuid::Uuid,
+            description = "The user ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        user: GetParamUser,
+        activity_logger: GetAdminActivityLogger,
+    ) -> ApiResponseResult {
+        permissions.has_admin_permission("users.api-keys")?;
+
+        let revoked = UserApiKey::delete_by_user_uuid(&state.database, user.uuid).await?;
+
+        activity_logger
+            .log(
+                "user:api-keys.revoke",
+                serde_json::json!({
+                    "uuid": user.uuid,
+                    "revoked": revoked,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response { revoked }).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .routes(routes!(delete::route))
+        .with_state(state.clone())
+}
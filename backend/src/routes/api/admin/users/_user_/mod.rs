@@ -17,6 +17,7 @@ use std::ops::{Deref, DerefMut};
 use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod activity;
+mod api_keys;
 mod oauth_links;
 mod servers;
 mod two_factor;
@@ -240,6 +241,7 @@ mod patch {
                     "name_last": user.name_last,
                     "admin": user.admin,
                     "language": user.language,
+                    "backup_storage_limit": user.backup_storage_limit,
                 }),
             )
             .await;
@@ -257,6 +259,7 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .nest("/servers", servers::router(state))
         .nest("/activity", activity::router(state))
         .nest("/oauth-links", oauth_links::router(state))
+        .nest("/api-keys", api_keys::router(state))
         .route_layer(axum::middleware::from_fn_with_state(state.clone(), auth))
         .with_state(state.clone())
 }
@@ -18,7 +18,7 @@ mod get {
         #[garde(range(min = 1))]
         #[serde(default = "Pagination::default_page")]
         page: i64,
-        #[garde(range(min = 1, max = 100))]
+        #[garde(custom(shared::models::validate_per_page))]
         #[serde(default = "Pagination::default_per_page")]
         per_page: i64,
         #[garde(length(chars, min = 1, max = 100))]
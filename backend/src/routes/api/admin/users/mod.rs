@@ -134,6 +134,7 @@ mod post {
                     "name_last": user.name_last,
                     "admin": user.admin,
                     "language": user.language,
+                    "backup_storage_limit": user.backup_storage_limit,
                 }),
             )
             .await;
@@ -242,13 +242,13 @@ mod post {
         .execute(state.database.write())
         .await?;
 
-        let settings = state.settings.get().await?;
+        let app_settings = state.settings.app().await?;
 
         cookies.add(
             Cookie::build(("session", key))
                 .http_only(true)
                 .same_site(tower_cookies::cookie::SameSite::Strict)
-                .secure(settings.app.url.starts_with("https://"))
+                .secure(app_settings.url.starts_with("https://"))
                 .path("/")
                 .expires(
                     tower_cookies::cookie::time::OffsetDateTime::now_utc()
@@ -257,8 +257,6 @@ mod post {
                 .build(),
         );
 
-        drop(settings);
-
         ApiResponse::new_serialized(Response {
             user: user.into_api_full_object(&state.storage.retrieve_urls().await?),
         })
@@ -93,12 +93,14 @@ mod post {
         };
 
         if user.totp_enabled {
+            let two_factor_ttl = state.settings.jwt().await?.two_factor_ttl;
+
             let token = state.jwt.create(&TwoFactorRequiredJwt {
                 base: BasePayload {
                     issuer: "panel".into(),
                     subject: None,
                     audience: Vec::new(),
-                    expiration_time: Some(chrono::Utc::now().timestamp() + 300),
+                    expiration_time: Some(chrono::Utc::now().timestamp() + two_factor_ttl as i64),
                     not_before: None,
                     issued_at: Some(chrono::Utc::now().timestamp()),
                     jwt_id: user.uuid.to_string(),
@@ -152,13 +154,13 @@ mod post {
             )
             .await?;
 
-            let settings = state.settings.get().await?;
+            let app_settings = state.settings.app().await?;
 
             cookies.add(
                 Cookie::build(("session", key))
                     .http_only(true)
                     .same_site(tower_cookies::cookie::SameSite::Strict)
-                    .secure(settings.app.url.starts_with("https://"))
+                    .secure(app_settings.url.starts_with("https://"))
                     .path("/")
                     .expires(
                         tower_cookies::cookie::time::OffsetDateTime::now_utc()
@@ -167,8 +169,6 @@ mod post {
                     .build(),
             );
 
-            drop(settings);
-
             if let Err(err) = UserActivity::create(
                 &state,
                 shared::models::user_activity::CreateUserActivityOptions {
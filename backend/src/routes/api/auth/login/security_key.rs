@@ -65,7 +65,9 @@ mod get {
             .cache
             .client
             .set_with_options(
-                format!("security_key_authentication::{uuid}"),
+                state
+                    .cache
+                    .key(format!("security_key_authentication::{uuid}")),
                 serde_json::to_string(&authentication)?,
                 None,
                 SetExpiration::Ex(options.public_key.timeout.unwrap_or(300000) as u64 / 1000),
@@ -124,14 +126,22 @@ mod post {
         let authentication: PasskeyAuthentication = match state
             .cache
             .client
-            .get::<String>(format!("security_key_authentication::{}", data.uuid))
+            .get::<String>(
+                state
+                    .cache
+                    .key(format!("security_key_authentication::{}", data.uuid)),
+            )
             .await
         {
             Ok(authentication) => {
                 state
                     .cache
                     .client
-                    .del(format!("security_key_authentication::{}", data.uuid))
+                    .del(
+                        state
+                            .cache
+                            .key(format!("security_key_authentication::{}", data.uuid)),
+                    )
                     .await?;
 
                 serde_json::from_str(&authentication)?
@@ -197,13 +207,13 @@ mod post {
         )
         .await?;
 
-        let settings = state.settings.get().await?;
+        let app_settings = state.settings.app().await?;
 
         cookies.add(
             Cookie::build(("session", key))
                 .http_only(true)
                 .same_site(tower_cookies::cookie::SameSite::Strict)
-                .secure(settings.app.url.starts_with("https://"))
+                .secure(app_settings.url.starts_with("https://"))
                 .path("/")
                 .expires(
                     tower_cookies::cookie::time::OffsetDateTime::now_utc()
@@ -212,8 +222,6 @@ mod post {
                 .build(),
         );
 
-        drop(settings);
-
         if let Err(err) = UserActivity::create(
             &state,
             shared::models::user_activity::CreateUserActivityOptions {
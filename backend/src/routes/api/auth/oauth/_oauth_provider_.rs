@@ -15,7 +15,7 @@ use serde::Deserialize;
 use shared::{
     GetState,
     models::{
-        ByUuid, CreatableModel, oauth_provider::OAuthProvider, user::User,
+        ByUuid, CreatableModel, oauth_provider::OAuthProvider, role::Role, user::User,
         user_activity::UserActivity, user_oauth_link::UserOAuthLink, user_session::UserSession,
     },
     response::ApiResponse,
@@ -61,7 +61,9 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
             if state
                 .cache
                 .client
-                .get::<u16>(format!("oauth_state::{}", params.state))
+                .get::<u16>(
+                    state.cache.key(format!("oauth_state::{}", params.state)),
+                )
                 .await
                 .is_err()
             {
@@ -70,7 +72,7 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
                     .ok();
             }
 
-            let settings = state.settings.get().await?;
+            let app_settings = state.settings.app().await?;
 
             let client = BasicClient::new(ClientId::new(oauth_provider.client_id.to_string()))
                 .set_client_secret(ClientSecret::new(
@@ -88,12 +90,10 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
                 })
                 .set_redirect_uri(RedirectUrl::new(format!(
                     "{}/api/auth/oauth/{}",
-                    settings.app.url.trim_end_matches('/'),
+                    app_settings.url.trim_end_matches('/'),
                     oauth_provider.uuid
                 ))?);
 
-            drop(settings);
-
             let http_client = |req: HttpRequest| {
                 let client = state.client.clone();
                 async move {
@@ -143,9 +143,9 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
                     )
                     .await;
 
-                let settings = state.settings.get().await?;
-                let secure = settings.app.url.starts_with("https://");
-                drop(settings);
+                let app_settings = state.settings.app().await?;
+                let secure = app_settings.url.starts_with("https://");
+                drop(app_settings);
 
                 cookies.add(
                     Cookie::build(("session", session_id.value().to_string()))
@@ -260,9 +260,9 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
                         )
                         .await?;
 
-                        let settings = state.settings.get().await?;
-                        let secure = settings.app.url.starts_with("https://");
-                        drop(settings);
+                        let app_settings = state.settings.app().await?;
+                        let secure = app_settings.url.starts_with("https://");
+                        drop(app_settings);
 
                         cookies.add(
                             Cookie::build(("session", key))
@@ -336,8 +336,18 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
                         let name_first = oauth_provider.extract_name_first(&info)?.into();
                         let name_last = oauth_provider.extract_name_last(&info)?.into();
 
+                        // the default role may have been deleted since it was configured
+                        let role_uuid = match settings.users.default_role_uuid {
+                            Some(role_uuid) => {
+                                Role::by_uuid_optional_cached(&state.database, role_uuid)
+                                    .await?
+                                    .map(|role| role.uuid)
+                            }
+                            None => None,
+                        };
+
                         let options = shared::models::user::CreateUserOptions {
-                            role_uuid: None,
+                            role_uuid,
                             external_id: None,
                             username,
                             email,
@@ -346,6 +356,7 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
                             password: None,
                             admin: false,
                             language: settings.app.language.clone(),
+                            backup_storage_limit: None,
                         };
                         drop(settings);
                         let user = match User::create(&state, options).await {
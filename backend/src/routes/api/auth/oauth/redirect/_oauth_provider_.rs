@@ -46,18 +46,16 @@ mod get {
                 .ok();
         }
 
-        let settings = state.settings.get().await?;
+        let app_settings = state.settings.app().await?;
 
         let client = BasicClient::new(ClientId::new(oauth_provider.client_id.to_string()))
             .set_auth_uri(AuthUrl::new(oauth_provider.auth_url.clone())?)
             .set_redirect_uri(RedirectUrl::new(format!(
                 "{}/api/auth/oauth/{}",
-                settings.app.url.trim_end_matches('/'),
+                app_settings.url.trim_end_matches('/'),
                 oauth_provider.uuid
             ))?);
 
-        drop(settings);
-
         let mut url = client.authorize_url(CsrfToken::new_random);
         for scope in oauth_provider.scopes {
             url = url.add_scope(Scope::new(scope.into()));
@@ -69,7 +67,9 @@ mod get {
             .cache
             .client
             .set_with_options(
-                format!("oauth_state::{}", csrf_state.secret()),
+                state
+                    .cache
+                    .key(format!("oauth_state::{}", csrf_state.secret())),
                 0,
                 None,
                 SetExpiration::Ex(10 * 60),
@@ -65,24 +65,30 @@ mod post {
         };
 
         tokio::spawn(async move {
-            let token = match UserPasswordReset::create(&state.database, user.uuid).await {
-                Ok(token) => token,
+            let settings = match state.settings.get().await {
+                Ok(settings) => settings,
                 Err(err) => {
                     tracing::warn!(
                         user = %user.uuid,
-                        "failed to create password reset token: {:#?}",
+                        "failed to get settings for password reset email: {:#?}",
                         err
                     );
                     return;
                 }
             };
 
-            let settings = match state.settings.get().await {
-                Ok(settings) => settings,
+            let token = match UserPasswordReset::create(
+                &state.database,
+                user.uuid,
+                settings.app.password_reset_expiry_minutes,
+            )
+            .await
+            {
+                Ok(token) => token,
                 Err(err) => {
                     tracing::warn!(
                         user = %user.uuid,
-                        "failed to get settings for password reset email: {:#?}",
+                        "failed to create password reset token: {:#?}",
                         err
                     );
                     return;
@@ -43,8 +43,17 @@ mod post {
                 .ok();
         }
 
+        state
+            .cache
+            .ratelimit("auth/password/reset", 10, 300, ip.to_string())
+            .await?;
+
+        let expiry_minutes = state.settings.app().await?.password_reset_expiry_minutes;
+
         let mut token =
-            match UserPasswordReset::delete_by_token(&state.database, &data.token).await? {
+            match UserPasswordReset::delete_by_token(&state.database, &data.token, expiry_minutes)
+                .await?
+            {
                 Some(token) => token,
                 None => {
                     return ApiResponse::error("invalid or expired token")
@@ -83,6 +92,7 @@ mod post {
             .user
             .update_password(&state.database, Some(&data.new_password))
             .await?;
+        UserPasswordReset::delete_by_user_uuid(&state.database, token.user.uuid).await?;
 
         ApiResponse::new_serialized(Response {}).ok()
     }
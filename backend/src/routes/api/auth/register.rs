@@ -65,6 +65,7 @@ mod post {
                 .ok();
         }
         let secure = settings.app.url.starts_with("https://");
+        let default_role_uuid = settings.users.default_role_uuid;
         drop(settings);
 
         state
@@ -85,10 +86,11 @@ mod post {
             &data.name_first,
             &data.name_last,
             &data.password,
+            default_role_uuid,
         )
         .await
         {
-            Ok(user_uuid) => User::by_uuid(&state.database, user_uuid).await?,
+            Ok(user_uuid) => User::by_uuid(&state.database.primary(), user_uuid).await?,
             Err(err) if err.is_unique_violation() => {
                 return ApiResponse::error("user with username or email already exists")
                     .with_status(StatusCode::BAD_REQUEST)
@@ -101,7 +101,7 @@ mod post {
         #[garde(length(chars, min = 3, max = 31))]
         #[schema(min_length = 3, max_length = 31)]
         name: compact_str::CompactString,
-        #[garde(skip)]
+        #[garde(custom(shared::utils::validate_ip_networks))]
         #[schema(value_type = Vec<String>)]
         allowed_ips: Vec<sqlx::types::ipnetwork::IpNetwork>,
 
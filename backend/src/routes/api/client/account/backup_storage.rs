@@ -0,0 +1,39 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        GetState,
+        models::{server_backup::ServerBackup, user::GetUser},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        used_bytes: u64,
+        limit_bytes: Option<u64>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ))]
+    pub async fn route(state: GetState, user: GetUser) -> ApiResponseResult {
+        let used = ServerBackup::storage_used_by_owner_uuid(&state.database, user.uuid).await?;
+
+        ApiResponse::new_serialized(Response {
+            used_bytes: used.max(0) as u64,
+            limit_bytes: user
+                .backup_storage_limit
+                .map(|limit| limit.max(0) as u64 * 1024 * 1024),
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
@@ -93,6 +93,8 @@ mod put {
                     }),
                 )
                 .await;
+            user.send_security_alert(&state, "email changed", activity_logger.ip)
+                .await;
         }
 
         ApiResponse::new_serialized(Response {}).ok()
@@ -4,9 +4,11 @@ use utoipa_axum::{router::OpenApiRouter, routes};
 mod activity;
 mod api_keys;
 mod avatar;
+mod backup_storage;
 mod command_snippets;
 mod email;
 mod logout;
+mod notifications;
 mod oauth_links;
 mod password;
 mod security_keys;
@@ -26,13 +28,17 @@ mod get {
     #[derive(ToSchema, Serialize)]
     struct Response {
         user: shared::models::user::ApiFullUser,
+        feature_flags: Vec<compact_str::CompactString>,
     }
 
     #[utoipa::path(get, path = "/", responses(
         (status = OK, body = inline(Response)),
     ))]
     pub async fn route(state: GetState, user: GetUser) -> ApiResponseResult {
+        let feature_flags = state.features.enabled_for(Some(&user)).await?;
+
         ApiResponse::new_serialized(Response {
+            feature_flags,
             user: user
                 .0
                 .into_api_full_object(&state.storage.retrieve_urls().await?),
@@ -158,6 +164,7 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .nest("/avatar", avatar::router(state))
         .nest("/email", email::router(state))
         .nest("/password", password::router(state))
+        .nest("/notifications", notifications::router(state))
         .nest("/two-factor", two_factor::router(state))
         .nest("/security-keys", security_keys::router(state))
         .nest("/oauth-links", oauth_links::router(state))
@@ -166,5 +173,6 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .nest("/ssh-keys", ssh_keys::router(state))
         .nest("/sessions", sessions::router(state))
         .nest("/activity", activity::router(state))
+        .nest("/backup-storage", backup_storage::router(state))
         .with_state(state.clone())
 }
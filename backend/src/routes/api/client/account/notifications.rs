@@ -0,0 +1,108 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        GetState,
+        models::{user::GetUser, user_notification_preferences::UserNotificationPreferences},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        preferences: shared::models::user_notification_preferences::ApiUserNotificationPreferences,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ))]
+    pub async fn route(state: GetState, user: GetUser) -> ApiResponseResult {
+        let preferences =
+            UserNotificationPreferences::by_user_uuid(&state.database, user.uuid).await?;
+
+        ApiResponse::new_serialized(Response {
+            preferences: preferences.into_api_object(),
+        })
+        .ok()
+    }
+}
+
+mod patch {
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        GetState,
+        models::{
+            user::GetUser, user_activity::GetUserActivityLogger,
+            user_notification_preferences::UserNotificationPreferences,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Deserialize)]
+    pub struct Payload {
+        backup_completed: Option<bool>,
+        server_suspended: Option<bool>,
+        security_alerts: Option<bool>,
+        resource_alerts: Option<bool>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        preferences: shared::models::user_notification_preferences::ApiUserNotificationPreferences,
+    }
+
+    #[utoipa::path(patch, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        state: GetState,
+        user: GetUser,
+        activity_logger: GetUserActivityLogger,
+        shared::Payload(data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        let mut preferences =
+            UserNotificationPreferences::by_user_uuid(&state.database, user.uuid).await?;
+
+        if let Some(backup_completed) = data.backup_completed {
+            preferences.backup_completed = backup_completed;
+        }
+        if let Some(server_suspended) = data.server_suspended {
+            preferences.server_suspended = server_suspended;
+        }
+        if let Some(security_alerts) = data.security_alerts {
+            preferences.security_alerts = security_alerts;
+        }
+        if let Some(resource_alerts) = data.resource_alerts {
+            preferences.resource_alerts = resource_alerts;
+        }
+
+        preferences.save(&state.database).await?;
+
+        activity_logger
+            .log(
+                "user:account.notifications.update",
+                serde_json::json!({
+                    "backup_completed": preferences.backup_completed,
+                    "server_suspended": preferences.server_suspended,
+                    "security_alerts": preferences.security_alerts,
+                    "resource_alerts": preferences.resource_alerts,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            preferences: preferences.into_api_object(),
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .routes(routes!(patch::route))
+        .with_state(state.clone())
+}
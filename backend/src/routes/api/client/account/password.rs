@@ -62,6 +62,8 @@ mod put {
         activity_logger
             .log("account:password-changed", serde_json::json!({}))
             .await;
+        user.send_security_alert(&state, "password changed", activity_logger.ip)
+            .await;
 
         ApiResponse::new_serialized(Response {}).ok()
     }
@@ -167,6 +167,12 @@ mod post {
         activity_logger
             .log("account:two-factor.enable", serde_json::json!({}))
             .await;
+        user.send_security_alert(
+            &state,
+            "two-factor authentication enabled",
+            activity_logger.ip,
+        )
+        .await;
 
         ApiResponse::new_serialized(Response { recovery_codes }).ok()
     }
@@ -287,6 +293,12 @@ mod delete {
         activity_logger
             .log("account:two-factor.disable", serde_json::json!({}))
             .await;
+        user.send_security_alert(
+            &state,
+            "two-factor authentication disabled",
+            activity_logger.ip,
+        )
+        .await;
 
         ApiResponse::new_serialized(Response {}).ok()
     }
@@ -72,13 +72,12 @@ pub async fn auth(
             )
             .await;
 
-        let settings = match state.settings.get().await {
-            Ok(settings) => settings,
+        let app_settings = match state.settings.app().await {
+            Ok(app_settings) => app_settings,
             Err(err) => return Ok(ApiResponse::from(err).into_response()),
         };
-        let require_two_factor = auth_user.require_two_factor(&settings);
-        let secure = settings.app.url.starts_with("https://");
-        drop(settings);
+        let require_two_factor = auth_user.require_two_factor(&app_settings);
+        let secure = app_settings.url.starts_with("https://");
 
         cookies.add(
             Cookie::build(("session", session_id.value().to_string()))
@@ -175,7 +174,7 @@ pub async fn auth(
             && !api_key
                 .allowed_ips
                 .iter()
-                .any(|allowed_ip| allowed_ip.contains(ip.0))
+                .any(|allowed_ip| allowed_ip.contains(ip.0.to_canonical()))
         {
             return Ok(
                 ApiResponse::error("ip address not allowed for this api key")
@@ -186,12 +185,11 @@ pub async fn auth(
 
         api_key.update_last_used(&state.database).await;
 
-        let settings = match state.settings.get().await {
-            Ok(settings) => settings,
+        let app_settings = match state.settings.app().await {
+            Ok(app_settings) => app_settings,
             Err(err) => return Ok(ApiResponse::from(err).into_response()),
         };
-        let require_two_factor = auth_user.require_two_factor(&settings);
-        drop(settings);
+        let require_two_factor = auth_user.require_two_factor(&app_settings);
 
         if !IGNORED_TWO_FACTOR_PATHS.contains(&matched_path.as_str())
             && !auth_user.totp_enabled
@@ -1,6 +1,8 @@
 use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
+mod primary;
+
 mod delete {
     use axum::{extract::Path, http::StatusCode};
     use serde::Serialize;
@@ -23,6 +25,7 @@ mod delete {
         (status = OK, body = inline(Response)),
         (status = UNAUTHORIZED, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
+        (status = BAD_REQUEST, body = ApiError),
     ), params(
         (
             "server" = uuid::Uuid,
@@ -71,6 +74,13 @@ mod delete {
                 .ok();
         }
 
+        let allocations =
+            ServerAllocation::count_by_server_uuid(&state.database, server.uuid).await?;
+        server
+            .egg
+            .config_allocations
+            .ensure_minimum((allocations as usize).saturating_sub(1))?;
+
         allocation.delete(&state, ()).await?;
 
         activity_logger
@@ -256,5 +266,6 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
     OpenApiRouter::new()
         .routes(routes!(delete::route))
         .routes(routes!(patch::route))
+        .nest("/primary", primary::router(state))
         .with_state(state.clone())
 }
@@ -0,0 +1,104 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod post {
+    use axum::{extract::Path, http::StatusCode};
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            server::{GetServer, GetServerActivityLogger},
+            server_allocation::ServerAllocation,
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        allocation: shared::models::server_allocation::ApiServerAllocation,
+    }
+
+    #[utoipa::path(post, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+        (status = NOT_FOUND, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "allocation" = uuid::Uuid,
+            description = "The allocation ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+        activity_logger: GetServerActivityLogger,
+        Path((_server, allocation)): Path<(String, uuid::Uuid)>,
+    ) -> ApiResponseResult {
+        permissions.has_server_permission("allocations.update")?;
+
+        let allocation =
+            match ServerAllocation::by_server_uuid_uuid(&state.database, server.uuid, allocation)
+                .await?
+            {
+                Some(allocation) => allocation,
+                None => {
+                    return ApiResponse::error("allocation not found")
+                        .with_status(StatusCode::NOT_FOUND)
+                        .ok();
+                }
+            };
+
+        if !ServerAllocation::set_primary(&state.database, server.uuid, allocation.uuid).await? {
+            return ApiResponse::error("allocation not found")
+                .with_status(StatusCode::NOT_FOUND)
+                .ok();
+        }
+
+        activity_logger
+            .log(
+                "server:allocation.update",
+                serde_json::json!({
+                    "ip": allocation.allocation.ip,
+                    "ip_alias": allocation.allocation.ip_alias,
+                    "port": allocation.allocation.port,
+
+                    "primary": true,
+                }),
+            )
+            .await;
+
+        let mut server = server.0;
+        server.allocation = Some(allocation.clone());
+
+        state
+            .database
+            .batch_action("sync_server", server.uuid, {
+                let state = state.clone();
+
+                async move { server.sync(&state.database).await }
+            })
+            .await;
+
+        let primary = Some(allocation.uuid);
+
+        ApiResponse::new_serialized(Response {
+            allocation: allocation.into_api_object(primary),
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(post::route))
+        .with_state(state.clone())
+}
@@ -110,6 +110,7 @@ mod post {
         (status = OK, body = inline(Response)),
         (status = BAD_REQUEST, body = ApiError),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = CONFLICT, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
         (
@@ -142,17 +143,22 @@ mod post {
             .await?;
 
         let allocations =
-            ServerAllocation::count_by_server_uuid(&state.database, server.uuid).await;
+            ServerAllocation::count_by_server_uuid(&state.database, server.uuid).await?;
         if allocations >= server.allocation_limit as i64 {
-            return ApiResponse::error("maximum number of allocations reached")
-                .with_status(StatusCode::EXPECTATION_FAILED)
-                .ok();
+            return ApiResponse::error(format!(
+                "allocation limit reached ({allocations}/{})",
+                server.allocation_limit
+            ))
+            .with_status(StatusCode::CONFLICT)
+            .ok();
         }
 
         let allocation = match ServerAllocation::create_random(&state.database, &server).await {
-            Ok(allocation_uuid) => ServerAllocation::by_uuid(&state.database, allocation_uuid)
-                .await?
-                .ok_or_else(|| anyhow::anyhow!("allocation not found after creation"))?,
+            Ok(allocation_uuid) => {
+                ServerAllocation::by_uuid(&state.database.primary(), allocation_uuid)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("allocation not found after creation"))?
+            }
             Err(err) if err.to_string().contains("null value in column") => {
                 return ApiResponse::error("no node allocations are available")
                     .with_status(StatusCode::EXPECTATION_FAILED)
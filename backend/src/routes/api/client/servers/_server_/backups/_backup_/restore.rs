@@ -18,6 +18,8 @@ mod post {
     #[derive(ToSchema, Deserialize)]
     pub struct Payload {
         truncate_directory: bool,
+        #[serde(default)]
+        force: bool,
     }
 
     #[derive(ToSchema, Serialize)]
@@ -28,6 +30,7 @@ mod post {
         (status = UNAUTHORIZED, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
         (status = BAD_REQUEST, body = ApiError),
+        (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
         (
             "server" = uuid::Uuid,
@@ -56,6 +59,12 @@ mod post {
                 .ok();
         }
 
+        if !data.force {
+            backup
+                .ensure_fits_disk_limit(&state.database, &server, data.truncate_directory)
+                .await?;
+        }
+
         let mut transaction = state.database.write().begin().await?;
 
         let rows_affected = sqlx::query!(
@@ -102,6 +111,7 @@ mod post {
                     "uuid": backup_uuid,
                     "name": backup_name,
                     "truncate_directory": data.truncate_directory,
+                    "force": data.force,
                 }),
             )
             .await;
@@ -2,6 +2,7 @@ use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod _backup_;
+mod prune;
 
 mod get {
     use axum::{extract::Query, http::StatusCode};
@@ -199,6 +200,7 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
     OpenApiRouter::new()
         .routes(routes!(get::route))
         .routes(routes!(post::route))
+        .nest("/prune", prune::router(state))
         .nest("/{backup}", _backup_::router(state))
         .with_state(state.clone())
 }
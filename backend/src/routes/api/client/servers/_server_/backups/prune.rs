@@ -0,0 +1,129 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod post {
+    use axum::http::StatusCode;
+    use garde::Validate;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{
+            DeletableModel,
+            server::{GetServer, GetServerActivityLogger},
+            server_backup::ServerBackup,
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Deserialize, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    #[schema(rename_all = "snake_case")]
+    pub enum PruneStatus {
+        Any,
+        Successful,
+        Failed,
+    }
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct Payload {
+        #[garde(skip)]
+        #[schema(inline)]
+        status: PruneStatus,
+        #[garde(skip)]
+        older_than: Option<chrono::DateTime<chrono::Utc>>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        deleted: usize,
+    }
+
+    #[utoipa::path(post, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+        (status = UNAUTHORIZED, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ), request_body = inline(Payload))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+        activity_logger: GetServerActivityLogger,
+        shared::Payload(data): shared::Payload<Payload>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&data) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        permissions.has_server_permission("backups.delete")?;
+
+        state
+            .cache
+            .ratelimit(
+                "client/servers/backups/prune",
+                2,
+                300,
+                server.uuid.to_string(),
+            )
+            .await?;
+
+        let backups = ServerBackup::all_by_server_uuid(&state.database, server.uuid)
+            .await?
+            .into_iter()
+            .filter(|backup| backup.completed.is_some() && !backup.locked)
+            .filter(|backup| match data.status {
+                PruneStatus::Any => true,
+                PruneStatus::Successful => backup.successful,
+                PruneStatus::Failed => !backup.successful,
+            })
+            .filter(|backup| {
+                data.older_than
+                    .is_none_or(|older_than| backup.created < older_than.naive_utc())
+            });
+
+        let mut deleted = Vec::new();
+        for backup in backups {
+            if let Err(err) = backup.delete(&state, Default::default()).await {
+                tracing::error!(server = %server.uuid, backup = %backup.uuid, "failed to delete backup: {:?}", err);
+                continue;
+            }
+
+            deleted.push(backup.uuid);
+        }
+
+        activity_logger
+            .log(
+                "server:backup.prune",
+                serde_json::json!({
+                    "status": match data.status {
+                        PruneStatus::Any => "any",
+                        PruneStatus::Successful => "successful",
+                        PruneStatus::Failed => "failed",
+                    },
+                    "older_than": data.older_than,
+                    "uuids": deleted,
+                }),
+            )
+            .await;
+
+        ApiResponse::new_serialized(Response {
+            deleted: deleted.len(),
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(post::route))
+        .with_state(state.clone())
+}
@@ -0,0 +1,155 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            server::GetServer, server_allocation::ServerAllocation,
+            server_variable::ServerVariable, user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseEgg<'a> {
+        uuid: uuid::Uuid,
+        name: &'a str,
+        description: Option<&'a str>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseVariable<'a> {
+        name: &'a str,
+        env_variable: &'a str,
+        /// The resolved value, omitted for secret variables.
+        value: Option<String>,
+        is_secret: bool,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response<'a> {
+        name: &'a str,
+        #[schema(inline)]
+        egg: ResponseEgg<'a>,
+        image: &'a str,
+        #[schema(inline)]
+        limits: shared::models::server::ApiServerLimits,
+
+        /// The resolved startup command, only set with the `startup.read` permission.
+        startup: Option<String>,
+        /// The user viewable variables, only set with the `startup.read` permission.
+        #[schema(inline)]
+        variables: Option<Vec<ResponseVariable<'a>>>,
+        /// The allocations of the server, only set with the `allocations.read` permission.
+        allocations: Option<Vec<shared::models::server_allocation::ApiServerAllocation>>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+        (status = BAD_REQUEST, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+    ) -> ApiResponseResult {
+        let variables = if permissions.has_server_permission("startup.read").is_ok() {
+            Some(
+                ServerVariable::all_by_server_uuid_egg_uuid(
+                    &state.database,
+                    server.uuid,
+                    server.egg.uuid,
+                )
+                .await?,
+            )
+        } else {
+            None
+        };
+
+        let allocations = if permissions
+            .has_server_permission("allocations.read")
+            .is_ok()
+        {
+            let allocation_uuid = server.allocation.as_ref().map(|a| a.uuid);
+
+            Some(
+                ServerAllocation::all_by_server_uuid(&state.database, server.uuid)
+                    .await?
+                    .into_iter()
+                    .map(|allocation| allocation.into_api_object(allocation_uuid))
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
+        let (startup, response_variables) = match &variables {
+            Some(variables) => {
+                let resolved = ServerVariable::resolve_user_viewable_references(variables)?;
+
+                let mut startup = server.startup.to_string();
+                let mut response_variables = Vec::new();
+
+                for variable in variables
+                    .iter()
+                    .filter(|variable| variable.variable.user_viewable)
+                {
+                    let env_variable = variable.variable.env_variable.as_str();
+                    let value = resolved
+                        .get(env_variable)
+                        .map_or(variable.value.as_str(), |value| value.as_str());
+
+                    if !variable.variable.secret {
+                        startup = startup.replace(&format!("{{{{{env_variable}}}}}"), value);
+                    }
+
+                    response_variables.push(ResponseVariable {
+                        name: &variable.variable.name,
+                        env_variable,
+                        value: (!variable.variable.secret).then(|| value.to_string()),
+                        is_secret: variable.variable.secret,
+                    });
+                }
+
+                (Some(startup), Some(response_variables))
+            }
+            None => (None, None),
+        };
+
+        ApiResponse::new_serialized(Response {
+            name: &server.name,
+            egg: ResponseEgg {
+                uuid: server.egg.uuid,
+                name: &server.egg.name,
+                description: server.egg.description.as_deref(),
+            },
+            image: &server.image,
+            limits: shared::models::server::ApiServerLimits {
+                cpu: server.cpu,
+                memory: server.memory,
+                swap: server.swap,
+                disk: server.disk,
+            },
+            startup,
+            variables: response_variables,
+            allocations,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
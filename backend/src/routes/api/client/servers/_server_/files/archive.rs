@@ -0,0 +1,158 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use axum::{body::Body, http::StatusCode};
+    use axum_extra::extract::Query;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        jwt::BasePayload,
+        models::{
+            server::{GetServer, GetServerActivityLogger},
+            user::{GetPermissionManager, GetUser},
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use std::path::PathBuf;
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Deserialize)]
+    pub struct Params {
+        #[serde(default)]
+        directory: String,
+
+        #[serde(default)]
+        archive_format: wings_api::StreamableArchiveFormat,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = String, content_type = "application/octet-stream"),
+        (status = UNAUTHORIZED, body = ApiError),
+        (status = NOT_FOUND, body = ApiError),
+        (status = BAD_GATEWAY, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "directory" = String, Query,
+            description = "The directory to archive, defaults to the server root",
+            example = "/path/to/directory",
+        ),
+        (
+            "archive_format" = wings_api::StreamableArchiveFormat, Query,
+            description = "The format of the archive to download",
+            example = "tar_gz",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        user: GetUser,
+        mut server: GetServer,
+        activity_logger: GetServerActivityLogger,
+        Query(params): Query<Params>,
+    ) -> ApiResponseResult {
+        permissions.has_server_permission("files.read")?;
+        permissions.has_server_permission("files.read-content")?;
+
+        if server.is_ignored(&params.directory, true) {
+            return ApiResponse::new_serialized(ApiError::new_value(&["directory not found"]))
+                .with_status(StatusCode::NOT_FOUND)
+                .ok();
+        }
+
+        let node = server.node.fetch_cached(&state.database).await?;
+
+        #[derive(Serialize)]
+        struct DirectoryDownloadJwt {
+            #[serde(flatten)]
+            base: BasePayload,
+
+            file_path: PathBuf,
+            server_uuid: uuid::Uuid,
+            unique_id: uuid::Uuid,
+        }
+
+        let file_ttl = state.settings.jwt().await?.file_ttl;
+
+        let token = node.create_jwt(
+            &state.database,
+            &state.jwt,
+            &DirectoryDownloadJwt {
+                base: BasePayload {
+                    issuer: "panel".into(),
+                    subject: None,
+                    audience: Vec::new(),
+                    expiration_time: Some(chrono::Utc::now().timestamp() + file_ttl as i64),
+                    not_before: None,
+                    issued_at: Some(chrono::Utc::now().timestamp()),
+                    jwt_id: user.uuid.to_string(),
+                },
+                file_path: PathBuf::from(&params.directory),
+                server_uuid: server.uuid,
+                unique_id: uuid::Uuid::new_v4(),
+            },
+        )?;
+
+        let mut url = node.public_url();
+        url.set_path("/download/directory");
+        url.set_query(Some(&format!(
+            "token={}&archive_format={}",
+            urlencoding::encode(&token),
+            params.archive_format
+        )));
+
+        let response = match state.client.get(url).send().await {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                tracing::error!(
+                    server = %server.uuid,
+                    "failed to archive server files: node responded with {}",
+                    response.status()
+                );
+
+                return ApiResponse::error("failed to archive server files")
+                    .with_status(StatusCode::BAD_GATEWAY)
+                    .ok();
+            }
+            Err(err) => {
+                tracing::error!(server = %server.uuid, "failed to archive server files: {:?}", err);
+
+                return ApiResponse::error("failed to archive server files")
+                    .with_status(StatusCode::BAD_GATEWAY)
+                    .ok();
+            }
+        };
+
+        activity_logger
+            .log(
+                "server:file.archive-download",
+                serde_json::json!({
+                    "directory": params.directory,
+                    "archive_format": params.archive_format,
+                }),
+            )
+            .await;
+
+        let headers = response.headers().clone();
+        let mut api_response = ApiResponse::new(Body::from_stream(response.bytes_stream()));
+
+        for header in ["content-type", "content-disposition", "content-length"] {
+            if let Some(value) = headers.get(header).and_then(|v| v.to_str().ok()) {
+                api_response = api_response.with_header(header, value);
+            }
+        }
+
+        api_response.ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
@@ -12,6 +12,7 @@ mod post {
         },
         response::{ApiResponse, ApiResponseResult},
     };
+    use std::path::Path;
     use utoipa::ToSchema;
 
     #[derive(ToSchema, Deserialize)]
@@ -42,6 +43,7 @@ mod post {
         (status = OK, body = inline(Response)),
         (status = ACCEPTED, body = inline(Response)),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
@@ -60,6 +62,14 @@ mod post {
     ) -> ApiResponseResult {
         permissions.has_server_permission("files.archive")?;
 
+        if let Some(name) = &data.name
+            && server.is_denylisted(Path::new(&data.root).join(name))
+        {
+            return ApiResponse::error("file is denylisted by the server egg")
+                .with_status(StatusCode::FORBIDDEN)
+                .ok();
+        }
+
         let request_body = wings_api::servers_server_files_compress::post::RequestBody {
             name: data.name,
             format: data.format,
@@ -109,9 +119,13 @@ mod post {
                 .log(
                     "server:file.compress",
                     serde_json::json!({
-                        "directory": request_body.root,
-                        "name": request_body.name,
-                        "files": request_body.files.iter().collect::<Vec<_>>(),
+                        "directory": shared::models::server_activity::cap_logged_path(&request_body.root),
+                        "name": request_body.name.as_deref().map(shared::models::server_activity::cap_logged_path),
+                        "files": request_body
+                            .files
+                            .iter()
+                            .map(|file| shared::models::server_activity::cap_logged_path(file))
+                            .collect::<Vec<_>>(),
                     }),
                 )
                 .await;
@@ -20,7 +20,7 @@ mod get {
     }
 
     #[utoipa::path(get, path = "/", responses(
-        (status = OK, body = String),
+        (status = OK, description = "The file contents, the `ETag` header contains the sha256 fingerprint of the file", body = String),
         (status = UNAUTHORIZED, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
     ), params(
@@ -52,6 +52,13 @@ mod get {
 
         let settings = state.settings.get().await?;
 
+        // fetched before the contents, a change in between results in a conflict on save rather than a lost edit
+        let fingerprint = server
+            .file_fingerprint(&state.database, params.file.clone())
+            .await
+            .ok()
+            .flatten();
+
         let contents = match server
             .node
             .fetch_cached(&state.database)
@@ -89,7 +96,12 @@ mod get {
             )
             .await;
 
-        ApiResponse::new_stream(contents).ok()
+        let mut response = ApiResponse::new_stream(contents);
+        if let Some(fingerprint) = fingerprint {
+            response = response.with_header("ETag", fingerprint);
+        }
+
+        response.ok()
     }
 }
 
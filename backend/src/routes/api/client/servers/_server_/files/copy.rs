@@ -37,6 +37,7 @@ mod post {
         (status = OK, body = inline(Response)),
         (status = ACCEPTED, body = inline(ResponseAccepted)),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
@@ -69,6 +70,14 @@ mod post {
                 .ok();
         }
 
+        if let Some(destination) = &data.destination
+            && server.is_denylisted(destination)
+        {
+            return ApiResponse::error("destination is denylisted by the server egg")
+                .with_status(StatusCode::FORBIDDEN)
+                .ok();
+        }
+
         let request_body = wings_api::servers_server_files_copy::post::RequestBody {
             path: data.path,
             name: data.destination,
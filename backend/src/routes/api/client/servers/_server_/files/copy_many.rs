@@ -41,6 +41,7 @@ mod post {
         (status = OK, body = inline(Response)),
         (status = ACCEPTED, body = inline(ResponseAccepted)),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
@@ -59,6 +60,16 @@ mod post {
     ) -> ApiResponseResult {
         permissions.has_server_permission("files.create")?;
 
+        if data
+            .files
+            .iter()
+            .any(|f| server.is_denylisted(Path::new(&data.root).join(&f.to)))
+        {
+            return ApiResponse::error("destination is denylisted by the server egg")
+                .with_status(StatusCode::FORBIDDEN)
+                .ok();
+        }
+
         let request_body = wings_api::servers_server_files_copy_many::post::RequestBody {
             files: data
                 .files
@@ -43,6 +43,7 @@ mod post {
         (status = OK, body = inline(Response)),
         (status = ACCEPTED, body = inline(ResponseAccepted)),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
@@ -62,7 +63,7 @@ mod post {
     ) -> ApiResponseResult {
         permissions.has_server_permission("files.read")?;
 
-        let destination_server =
+        let mut destination_server =
             match Server::by_user_identifier(&state.database, &user, &data.destination_server)
                 .await?
             {
@@ -80,6 +81,14 @@ mod post {
                 .ok();
         }
 
+        if data.files.iter().any(|file| {
+            destination_server.is_denylisted(std::path::Path::new(&data.destination).join(file))
+        }) {
+            return ApiResponse::error("destination is denylisted by the destination server egg")
+                .with_status(StatusCode::FORBIDDEN)
+                .ok();
+        }
+
         let permissions = permissions
             .0
             .set_user_server_owner(user.uuid == destination_server.uuid)
@@ -103,6 +112,8 @@ mod post {
             .fetch_cached(&state.database)
             .await?;
 
+        let transfer_ttl = state.settings.jwt().await?.transfer_ttl;
+
         let token = destination_node.create_jwt(
             &state.database,
             &state.jwt,
@@ -111,7 +122,7 @@ mod post {
                     issuer: "panel".into(),
                     subject: Some(destination_server.uuid.to_string()),
                     audience: Vec::new(),
-                    expiration_time: Some(chrono::Utc::now().timestamp() + 600),
+                    expiration_time: Some(chrono::Utc::now().timestamp() + transfer_ttl as i64),
                     not_before: None,
                     issued_at: Some(chrono::Utc::now().timestamp()),
                     jwt_id: destination_server.node.uuid.to_string(),
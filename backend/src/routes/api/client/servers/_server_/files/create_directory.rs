@@ -12,6 +12,7 @@ mod post {
         },
         response::{ApiResponse, ApiResponseResult},
     };
+    use std::path::Path;
     use utoipa::ToSchema;
 
     #[derive(ToSchema, Deserialize)]
@@ -29,6 +30,7 @@ mod post {
     #[utoipa::path(post, path = "/", responses(
         (status = OK, body = inline(Response)),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
@@ -59,6 +61,14 @@ mod post {
                 .ok();
         }
 
+        if server.is_denylisted(&data.root)
+            || server.is_denylisted(Path::new(&data.root).join(&data.name))
+        {
+            return ApiResponse::error("directory is denylisted by the server egg")
+                .with_status(StatusCode::FORBIDDEN)
+                .ok();
+        }
+
         let request_body = wings_api::servers_server_files_create_directory::post::RequestBody {
             root: data.root,
             name: data.name,
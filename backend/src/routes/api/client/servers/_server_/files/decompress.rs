@@ -98,8 +98,8 @@ mod post {
                 .log(
                     "server:file.decompress",
                     serde_json::json!({
-                        "directory": request_body.root,
-                        "file": request_body.file,
+                        "directory": shared::models::server_activity::cap_logged_path(&request_body.root),
+                        "file": shared::models::server_activity::cap_logged_path(&request_body.file),
                     }),
                 )
                 .await;
@@ -85,8 +85,12 @@ mod post {
             .log(
                 "server:file.delete",
                 serde_json::json!({
-                    "directory": request_body.root,
-                    "files": request_body.files.iter().collect::<Vec<_>>(),
+                    "directory": shared::models::server_activity::cap_logged_path(&request_body.root),
+                    "files": request_body
+                        .files
+                        .iter()
+                        .map(|file| shared::models::server_activity::cap_logged_path(file))
+                        .collect::<Vec<_>>(),
                 }),
             )
             .await;
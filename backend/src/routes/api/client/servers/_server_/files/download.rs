@@ -96,6 +96,8 @@ mod get {
                 unique_id: uuid::Uuid,
             }
 
+            let file_ttl = state.settings.jwt().await?.file_ttl;
+
             let token = node.create_jwt(
                 &state.database,
                 &state.jwt,
@@ -104,7 +106,7 @@ mod get {
                         issuer: "panel".into(),
                         subject: None,
                         audience: Vec::new(),
-                        expiration_time: Some(chrono::Utc::now().timestamp() + 900),
+                        expiration_time: Some(chrono::Utc::now().timestamp() + file_ttl as i64),
                         not_before: None,
                         issued_at: Some(chrono::Utc::now().timestamp()),
                         jwt_id: user.uuid.to_string(),
@@ -140,6 +142,8 @@ mod get {
                 unique_id: uuid::Uuid,
             }
 
+            let file_ttl = state.settings.jwt().await?.file_ttl;
+
             let token = node.create_jwt(
                 &state.database,
                 &state.jwt,
@@ -148,7 +152,7 @@ mod get {
                         issuer: "panel".into(),
                         subject: None,
                         audience: Vec::new(),
-                        expiration_time: Some(chrono::Utc::now().timestamp() + 900),
+                        expiration_time: Some(chrono::Utc::now().timestamp() + file_ttl as i64),
                         not_before: None,
                         issued_at: Some(chrono::Utc::now().timestamp()),
                         jwt_id: user.uuid.to_string(),
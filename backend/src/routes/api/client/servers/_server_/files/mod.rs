@@ -1,6 +1,7 @@
 use super::State;
 use utoipa_axum::router::OpenApiRouter;
 
+mod archive;
 mod chmod;
 mod compress;
 mod contents;
@@ -26,6 +27,7 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .nest("/contents", contents::router(state))
         .nest("/fingerprint", fingerprint::router(state))
         .nest("/download", download::router(state))
+        .nest("/archive", archive::router(state))
         .nest("/upload", upload::router(state))
         .nest("/rename", rename::router(state))
         .nest("/copy", copy::router(state))
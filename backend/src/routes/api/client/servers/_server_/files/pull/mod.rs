@@ -15,6 +15,7 @@ mod post {
         },
         response::{ApiResponse, ApiResponseResult},
     };
+    use std::path::Path;
     use utoipa::ToSchema;
 
     #[derive(ToSchema, Validate, Deserialize)]
@@ -49,7 +50,9 @@ mod post {
     #[utoipa::path(post, path = "/", responses(
         (status = OK, body = inline(Response)),
         (status = ACCEPTED, body = inline(ResponseAccepted)),
+        (status = BAD_REQUEST, body = ApiError),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
         (
@@ -80,6 +83,22 @@ mod post {
                 .ok();
         }
 
+        match &data.name {
+            Some(name) if server.is_denylisted(Path::new(&data.root).join(name)) => {
+                return ApiResponse::error("file is denylisted by the server egg")
+                    .with_status(StatusCode::FORBIDDEN)
+                    .ok();
+            }
+            None if !server.egg.file_denylist.is_empty() => {
+                return ApiResponse::error(
+                    "a file name is required on a server with a file denylist",
+                )
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+            }
+            _ => {}
+        }
+
         let request_body = wings_api::servers_server_files_pull::post::RequestBody {
             root: data.root,
             url: data.url.into(),
@@ -33,6 +33,7 @@ mod put {
     #[utoipa::path(put, path = "/", responses(
         (status = OK, body = inline(Response)),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
@@ -51,6 +52,16 @@ mod put {
     ) -> ApiResponseResult {
         permissions.has_server_permission("files.update")?;
 
+        if data
+            .files
+            .iter()
+            .any(|f| server.is_denylisted(Path::new(&data.root).join(&f.to)))
+        {
+            return ApiResponse::error("destination is denylisted by the server egg")
+                .with_status(StatusCode::FORBIDDEN)
+                .ok();
+        }
+
         let request_body = wings_api::servers_server_files_rename::put::RequestBody {
             files: data
                 .files
@@ -90,8 +101,15 @@ mod put {
             .log(
                 "server:file.rename",
                 serde_json::json!({
-                    "directory": request_body.root,
-                    "files": request_body.files,
+                    "directory": shared::models::server_activity::cap_logged_path(&request_body.root),
+                    "files": request_body
+                        .files
+                        .iter()
+                        .map(|file| serde_json::json!({
+                            "from": shared::models::server_activity::cap_logged_path(&file.from),
+                            "to": shared::models::server_activity::cap_logged_path(&file.to),
+                        }))
+                        .collect::<Vec<_>>(),
                 }),
             )
             .await;
@@ -1,6 +1,182 @@
 use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
+mod get {
+    use axum::{extract::Query, http::StatusCode};
+    use garde::Validate;
+    use serde::{Deserialize, Serialize};
+    use shared::{
+        ApiError, GetState,
+        models::{Pagination, server::GetServer, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Validate, Deserialize)]
+    pub struct Params {
+        #[garde(range(min = 1))]
+        #[serde(default = "Pagination::default_page")]
+        page: i64,
+        #[garde(custom(shared::models::validate_per_page))]
+        #[serde(default = "Pagination::default_per_page")]
+        per_page: i64,
+
+        #[garde(length(chars, min = 1, max = 255))]
+        q: compact_str::CompactString,
+        #[garde(skip)]
+        #[serde(default)]
+        path: compact_str::CompactString,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        #[schema(inline)]
+        entries: Pagination<wings_api::DirectoryEntry>,
+        /// Whether the search hit the maximum amount of results, more files may match.
+        is_truncated: bool,
+    }
+
+    /// Escapes glob metacharacters so the query is matched literally.
+    fn escape_glob(query: &str) -> compact_str::CompactString {
+        let mut escaped = compact_str::CompactString::with_capacity(query.len());
+
+        for c in query.chars() {
+            if matches!(c, '*' | '?' | '[' | ']' | '{' | '}' | '!' | '\\') {
+                escaped.push('\\');
+            }
+
+            escaped.push(c);
+        }
+
+        escaped
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = BAD_REQUEST, body = ApiError),
+        (status = UNAUTHORIZED, body = ApiError),
+        (status = NOT_FOUND, body = ApiError),
+        (status = EXPECTATION_FAILED, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+        (
+            "page" = i64, Query,
+            description = "The page number for pagination",
+            example = "1",
+        ),
+        (
+            "per_page" = i64, Query,
+            description = "The number of items per page",
+            example = "10",
+        ),
+        (
+            "q" = String, Query,
+            description = "The text to search for in file names",
+            example = "server.properties",
+        ),
+        (
+            "path" = String, Query,
+            description = "The directory to search in",
+            example = "/",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        mut server: GetServer,
+        Query(params): Query<Params>,
+    ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&params) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
+        permissions.has_server_permission("files.read")?;
+
+        if server.is_ignored(&params.path, true) {
+            return ApiResponse::error("path not found")
+                .with_status(StatusCode::NOT_FOUND)
+                .ok();
+        }
+
+        let max_results = state
+            .settings
+            .server()
+            .await?
+            .max_file_manager_search_results;
+
+        let request_body = wings_api::servers_server_files_search::post::RequestBody {
+            root: params.path.clone(),
+            path_filter: Some(
+                wings_api::servers_server_files_search::post::RequestBodyPathFilter {
+                    include: vec![compact_str::format_compact!("*{}*", escape_glob(&params.q))],
+                    exclude: Vec::new(),
+                    case_insensitive: true,
+                },
+            ),
+            size_filter: None,
+            content_filter: None,
+            per_page: max_results,
+        };
+
+        let entries = match server
+            .node
+            .fetch_cached(&state.database)
+            .await?
+            .api_client(&state.database)
+            .await?
+            .post_servers_server_files_search(server.uuid, &request_body)
+            .await
+        {
+            Ok(data) => data.results,
+            Err(wings_api::client::ApiHttpError::Http(StatusCode::NOT_FOUND, err)) => {
+                return ApiResponse::new_serialized(ApiError::new_wings_value(err))
+                    .with_status(StatusCode::NOT_FOUND)
+                    .ok();
+            }
+            Err(wings_api::client::ApiHttpError::Http(StatusCode::EXPECTATION_FAILED, err)) => {
+                return ApiResponse::new_serialized(ApiError::new_wings_value(err))
+                    .with_status(StatusCode::EXPECTATION_FAILED)
+                    .ok();
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let is_truncated = entries.len() as u64 >= max_results;
+
+        let root = std::path::Path::new(params.path.as_str());
+        let entries = entries
+            .into_iter()
+            .filter(|entry| !server.is_ignored(root.join(entry.name.as_str()), entry.directory))
+            .collect::<Vec<_>>();
+
+        let total = entries.len() as i64;
+        let data = entries
+            .into_iter()
+            .skip(
+                usize::try_from((params.page - 1).saturating_mul(params.per_page))
+                    .unwrap_or(usize::MAX),
+            )
+            .take(usize::try_from(params.per_page).unwrap_or_default())
+            .collect();
+
+        ApiResponse::new_serialized(Response {
+            entries: Pagination {
+                total,
+                per_page: params.per_page,
+                page: params.page,
+                data,
+            },
+            is_truncated,
+        })
+        .ok()
+    }
+}
+
 mod post {
     use axum::http::StatusCode;
     use serde::{Deserialize, Serialize};
@@ -105,6 +281,7 @@ mod post {
 
 pub fn router(state: &State) -> OpenApiRouter<State> {
     OpenApiRouter::new()
+        .routes(routes!(get::route))
         .routes(routes!(post::route))
         .with_state(state.clone())
 }
@@ -2,18 +2,26 @@ use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod get {
-    use serde::Serialize;
+    use axum::{extract::Query, http::StatusCode};
+    use serde::{Deserialize, Serialize};
     use shared::{
         ApiError, GetState,
         jwt::BasePayload,
         models::{
-            server::GetServer,
+            server::{GetServer, GetServerActivityLogger},
             user::{GetPermissionManager, GetUser},
         },
         response::{ApiResponse, ApiResponseResult},
     };
+    use std::path::Path;
     use utoipa::ToSchema;
 
+    #[derive(ToSchema, Deserialize)]
+    pub struct Params {
+        #[serde(default)]
+        directory: Option<compact_str::CompactString>,
+    }
+
     #[derive(ToSchema, Serialize)]
     struct Response {
         #[schema(format = "uri")]
@@ -23,21 +31,41 @@ mod get {
     #[utoipa::path(get, path = "/", responses(
         (status = OK, body = inline(Response)),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
     ), params(
         (
             "server" = uuid::Uuid,
             description = "The server ID",
             example = "123e4567-e89b-12d3-a456-426614174000",
         ),
+        (
+            "directory" = Option<String>, Query,
+            description = "The directory files will be uploaded to, bound into the upload token",
+            example = "/",
+        ),
     ))]
     pub async fn route(
         state: GetState,
         permissions: GetPermissionManager,
         user: GetUser,
-        server: GetServer,
+        mut server: GetServer,
+        activity_logger: GetServerActivityLogger,
+        Query(params): Query<Params>,
     ) -> ApiResponseResult {
         permissions.has_server_permission("files.create")?;
 
+        let directory = shared::cap::CapFilesystem::resolve_path(Path::new(
+            params.directory.as_deref().unwrap_or("/"),
+        ))
+        .to_string_lossy()
+        .to_string();
+
+        if server.is_denylisted(&directory) {
+            return ApiResponse::error("directory is denylisted by the server egg")
+                .with_status(StatusCode::FORBIDDEN)
+                .ok();
+        }
+
         #[derive(Serialize)]
         struct FileUploadJwt<'a> {
             #[serde(flatten)]
@@ -47,11 +75,14 @@ mod get {
             user_uuid: uuid::Uuid,
             unique_id: uuid::Uuid,
 
-            ignored_files: &'a [compact_str::CompactString],
+            directory: &'a str,
+            ignored_files: Vec<&'a compact_str::CompactString>,
         }
 
         let node = server.node.fetch_cached(&state.database).await?;
 
+        let file_ttl = state.settings.jwt().await?.file_ttl;
+
         let token = node.create_jwt(
             &state.database,
             &state.jwt,
@@ -60,7 +91,7 @@ mod get {
                     issuer: "panel".into(),
                     subject: None,
                     audience: Vec::new(),
-                    expiration_time: Some(chrono::Utc::now().timestamp() + 900),
+                    expiration_time: Some(chrono::Utc::now().timestamp() + file_ttl as i64),
                     not_before: None,
                     issued_at: Some(chrono::Utc::now().timestamp()),
                     jwt_id: user.uuid.to_string(),
@@ -68,13 +99,32 @@ mod get {
                 server_uuid: server.uuid,
                 user_uuid: user.uuid,
                 unique_id: uuid::Uuid::new_v4(),
-                ignored_files: server.subuser_ignored_files.as_deref().unwrap_or(&[]),
+                directory: &directory,
+                ignored_files: server
+                    .subuser_ignored_files
+                    .iter()
+                    .flatten()
+                    .chain(server.egg.file_denylist.iter())
+                    .collect(),
             },
         )?;
 
+        activity_logger
+            .log(
+                "server:file.upload",
+                serde_json::json!({
+                    "directory": shared::models::server_activity::cap_logged_path(&directory),
+                }),
+            )
+            .await;
+
         let mut url = node.public_url();
         url.set_path("/upload/file");
-        url.set_query(Some(&format!("token={}", urlencoding::encode(&token))));
+        url.set_query(Some(&format!(
+            "token={}&directory={}",
+            urlencoding::encode(&token),
+            urlencoding::encode(&directory)
+        )));
 
         ApiResponse::new_serialized(Response {
             url: url.to_string(),
@@ -2,8 +2,12 @@ use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod post {
-    use axum::{extract::Query, http::StatusCode};
+    use axum::{
+        extract::Query,
+        http::{HeaderMap, StatusCode},
+    };
     use serde::{Deserialize, Serialize};
+    use sha2::Digest;
     use shared::{
         ApiError, GetState,
         models::{
@@ -23,9 +27,11 @@ mod post {
     struct Response {}
 
     #[utoipa::path(post, path = "/", responses(
-        (status = OK, body = inline(Response)),
+        (status = OK, description = "The `ETag` header contains the sha256 fingerprint of the written file", body = inline(Response)),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
         (status = NOT_FOUND, body = ApiError),
+        (status = CONFLICT, body = ApiError),
         (status = EXPECTATION_FAILED, body = ApiError),
     ), params(
         (
@@ -38,6 +44,10 @@ mod post {
             description = "The file to write contents to",
             example = "/path/to/file.txt",
         ),
+        (
+            "If-Match" = Option<String>, Header,
+            description = "The `ETag` returned when reading the file, the write is rejected if the file changed since",
+        ),
     ), request_body = String)]
     pub async fn route(
         state: GetState,
@@ -45,6 +55,7 @@ mod post {
         mut server: GetServer,
         activity_logger: GetServerActivityLogger,
         Query(params): Query<Params>,
+        headers: HeaderMap,
         body: String,
     ) -> ApiResponseResult {
         permissions.has_server_permission("files.create")?;
@@ -55,6 +66,27 @@ mod post {
                 .ok();
         }
 
+        if server.is_denylisted(&params.file) {
+            return ApiResponse::error("file is denylisted by the server egg")
+                .with_status(StatusCode::FORBIDDEN)
+                .ok();
+        }
+
+        if let Some(expected) = headers.get("If-Match").and_then(|v| v.to_str().ok()) {
+            let expected = expected.trim().trim_start_matches("W/").trim_matches('"');
+            let fingerprint = server
+                .file_fingerprint(&state.database, params.file.as_str())
+                .await?;
+
+            if fingerprint.as_deref() != Some(expected) {
+                return ApiResponse::error("file has been modified since it was read")
+                    .with_status(StatusCode::CONFLICT)
+                    .ok();
+            }
+        }
+
+        let fingerprint = format!("{:x}", sha2::Sha256::digest(body.as_bytes()));
+
         match server
             .node
             .fetch_cached(&state.database)
@@ -87,7 +119,9 @@ mod post {
             )
             .await;
 
-        ApiResponse::new_serialized(Response {}).ok()
+        ApiResponse::new_serialized(Response {})
+            .with_header("ETag", fingerprint)
+            .ok()
     }
 }
 
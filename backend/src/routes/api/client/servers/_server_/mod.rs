@@ -20,14 +20,17 @@ mod activity;
 mod allocations;
 mod backups;
 mod command;
+mod config;
 mod databases;
 mod files;
 mod logs;
 mod mounts;
 mod power;
+mod queue;
 mod resources;
 mod schedules;
 mod settings;
+mod start;
 mod startup;
 mod subusers;
 mod websocket;
@@ -60,6 +63,7 @@ pub async fn auth(
         "/api/client/servers/{server}",
         "/api/client/servers/{server}/websocket",
         "/api/client/servers/{server}/settings/install/cancel",
+        "/api/client/servers/{server}/settings/install/progress",
     ];
 
     if !IGNORED_STATUS_PATHS.contains(&matched_path.as_str()) {
@@ -137,10 +141,12 @@ mod get {
         ),
     ))]
     pub async fn route(state: GetState, user: GetUser, server: GetServer) -> ApiResponseResult {
-        ApiResponse::new_serialized(Response {
-            server: server.0.into_api_object(&state.database, &user).await?,
-        })
-        .ok()
+        let power_state = server.fetch_power_state_optional(&state).await;
+
+        let mut server = server.0.into_api_object(&state, &user).await?;
+        server.power_state = power_state;
+
+        ApiResponse::new_serialized(Response { server }).ok()
     }
 }
 
@@ -149,13 +155,16 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .routes(routes!(get::route))
         .nest("/activity", activity::router(state))
         .nest("/resources", resources::router(state))
+        .nest("/queue", queue::router(state))
         .nest("/logs", logs::router(state))
         .nest("/websocket", websocket::router(state))
         .nest("/command", command::router(state))
         .nest("/power", power::router(state))
+        .nest("/start", start::router(state))
         .nest("/files", files::router(state))
         .nest("/settings", settings::router(state))
         .nest("/startup", startup::router(state))
+        .nest("/config", config::router(state))
         .nest("/subusers", subusers::router(state))
         .nest("/backups", backups::router(state))
         .nest("/allocations", allocations::router(state))
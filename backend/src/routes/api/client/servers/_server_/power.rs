@@ -1,12 +1,43 @@
 use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
+mod get {
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::server::GetServer,
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        state: wings_api::ServerState,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(state: GetState, server: GetServer) -> ApiResponseResult {
+        let power_state = server.fetch_power_state(&state).await?;
+
+        ApiResponse::new_serialized(Response { state: power_state }).ok()
+    }
+}
+
 mod post {
     use serde::{Deserialize, Serialize};
     use shared::{
         ApiError, GetState,
         models::{
-            server::{GetServer, GetServerActivityLogger},
+            server::{GetServer, GetServerActivityLogger, Server},
             user::GetPermissionManager,
         },
         response::{ApiResponse, ApiResponseResult},
@@ -61,6 +92,9 @@ mod post {
             )
             .await?;
 
+        Server::invalidate_power_state(&state.database, server.uuid).await;
+        server.relay_power_state_in_background(&state).await;
+
         activity_logger
             .log(
                 "server:power.action",
@@ -76,6 +110,7 @@ mod post {
 
 pub fn router(state: &State) -> OpenApiRouter<State> {
     OpenApiRouter::new()
+        .routes(routes!(get::route))
         .routes(routes!(post::route))
         .with_state(state.clone())
 }
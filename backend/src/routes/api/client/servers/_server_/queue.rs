@@ -0,0 +1,53 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        GetState,
+        models::{
+            job::{Job, JobPayload},
+            server::GetServer,
+            user::GetPermissionManager,
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        jobs: Vec<shared::models::job::ApiQueuedJob>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+    ) -> ApiResponseResult {
+        let jobs = Job::queued_by_server_uuid(&state.database, server.uuid)
+            .await?
+            .into_iter()
+            .filter(|job| match JobPayload::server_permission(&job.r#type) {
+                Some(permission) => permissions.has_server_permission(permission).is_ok(),
+                None => true,
+            })
+            .collect();
+
+        ApiResponse::new_serialized(Response { jobs }).ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
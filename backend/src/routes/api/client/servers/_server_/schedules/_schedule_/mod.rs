@@ -228,6 +228,7 @@ mod patch {
                     "enabled": schedule.enabled,
                     "triggers": schedule.triggers,
                     "condition": schedule.condition,
+                    "overlap_behavior": schedule.overlap_behavior,
                 }),
             )
             .await;
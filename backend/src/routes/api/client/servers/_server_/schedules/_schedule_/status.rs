@@ -14,6 +14,9 @@ mod get {
     #[derive(ToSchema, Serialize)]
     struct Response {
         status: wings_api::ScheduleStatus,
+        queued: bool,
+        skipped_runs: i32,
+        last_skipped: Option<chrono::DateTime<chrono::Utc>>,
     }
 
     #[utoipa::path(get, path = "/", responses(
@@ -51,6 +54,9 @@ mod get {
 
         ApiResponse::new_serialized(Response {
             status: schedule_status.status,
+            queued: schedule.has_queued_run(&state.cache).await?,
+            skipped_runs: schedule.skipped_runs,
+            last_skipped: schedule.last_skipped.map(|dt| dt.and_utc()),
         })
         .ok()
     }
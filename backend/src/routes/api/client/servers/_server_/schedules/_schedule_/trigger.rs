@@ -9,6 +9,7 @@ mod post {
         ApiError, GetState,
         models::{
             server::{GetServer, GetServerActivityLogger},
+            server_schedule::ServerScheduleTriggerResult,
             user::GetPermissionManager,
         },
         response::{ApiResponse, ApiResponseResult},
@@ -22,7 +23,9 @@ mod post {
     }
 
     #[derive(ToSchema, Serialize)]
-    struct Response {}
+    struct Response {
+        result: ServerScheduleTriggerResult,
+    }
 
     #[utoipa::path(post, path = "/", responses(
         (status = OK, body = inline(Response)),
@@ -46,7 +49,7 @@ mod post {
         ip: shared::GetIp,
         server: GetServer,
         activity_logger: GetServerActivityLogger,
-        schedule: GetServerSchedule,
+        mut schedule: GetServerSchedule,
         shared::Payload(data): shared::Payload<Payload>,
     ) -> ApiResponseResult {
         permissions.has_server_permission("schedules.update")?;
@@ -72,19 +75,8 @@ mod post {
             }
         }
 
-        server
-            .node
-            .fetch_cached(&state.database)
-            .await?
-            .api_client(&state.database)
-            .await?
-            .post_servers_server_schedules_schedule_trigger(
-                server.uuid,
-                schedule.uuid,
-                &wings_api::servers_server_schedules_schedule_trigger::post::RequestBody {
-                    skip_condition: data.skip_condition,
-                },
-            )
+        let result = schedule
+            .trigger(&state, &server, data.skip_condition)
             .await?;
 
         activity_logger
@@ -94,11 +86,12 @@ mod post {
                     "uuid": schedule.uuid,
                     "name": schedule.name,
                     "skip_condition": data.skip_condition,
+                    "result": result,
                 }),
             )
             .await;
 
-        ApiResponse::new_serialized(Response {}).ok()
+        ApiResponse::new_serialized(Response { result }).ok()
     }
 }
 
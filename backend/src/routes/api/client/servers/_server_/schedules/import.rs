@@ -70,6 +70,7 @@ mod post {
             enabled: data.enabled,
             triggers: data.triggers,
             condition: data.condition,
+            overlap_behavior: data.overlap_behavior,
         };
         let schedule = match ServerSchedule::create(&state, options).await {
             Ok(schedule) => schedule,
@@ -108,6 +109,7 @@ mod post {
                     "enabled": schedule.enabled,
                     "triggers": schedule.triggers,
                     "condition": schedule.condition,
+                    "overlap_behavior": schedule.overlap_behavior,
                     "steps": data.steps,
                 }),
             )
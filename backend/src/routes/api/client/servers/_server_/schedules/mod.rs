@@ -113,6 +113,9 @@ mod post {
         triggers: Vec<wings_api::ScheduleTrigger>,
         #[garde(dive)]
         condition: wings_api::SchedulePreCondition,
+        #[garde(skip)]
+        #[serde(default)]
+        overlap_behavior: shared::models::server_schedule::ServerScheduleOverlapBehavior,
     }
 
     #[derive(ToSchema, Serialize)]
@@ -168,6 +171,7 @@ mod post {
             enabled: data.enabled,
             triggers: data.triggers,
             condition: data.condition,
+            overlap_behavior: data.overlap_behavior,
         };
         let schedule = match ServerSchedule::create(&state, options).await {
             Ok(schedule) => schedule,
@@ -190,6 +194,7 @@ mod post {
                     "enabled": schedule.enabled,
                     "triggers": schedule.triggers,
                     "condition": schedule.condition,
+                    "overlap_behavior": schedule.overlap_behavior,
                 }),
             )
             .await;
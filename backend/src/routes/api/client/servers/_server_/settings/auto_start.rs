@@ -16,6 +16,7 @@ mod put {
     #[derive(ToSchema, Deserialize)]
     pub struct Payload {
         behavior: shared::models::server::ServerAutoStartBehavior,
+        on_reconnect: Option<bool>,
     }
 
     #[derive(ToSchema, Serialize)]
@@ -42,12 +43,16 @@ mod put {
         permissions.has_server_permission("settings.auto-start")?;
 
         server.auto_start_behavior = data.behavior;
+        if let Some(on_reconnect) = data.on_reconnect {
+            server.auto_start_on_reconnect = on_reconnect;
+        }
 
         sqlx::query!(
             "UPDATE servers
-            SET auto_start_behavior = $1
-            WHERE servers.uuid = $2",
+            SET auto_start_behavior = $1, auto_start_on_reconnect = $2
+            WHERE servers.uuid = $3",
             server.auto_start_behavior as shared::models::server::ServerAutoStartBehavior,
+            server.auto_start_on_reconnect,
             server.uuid
         )
         .execute(state.database.write())
@@ -58,6 +63,7 @@ mod put {
                 "server:settings.auto-start",
                 serde_json::json!({
                     "auto_start_behavior": server.auto_start_behavior,
+                    "auto_start_on_reconnect": server.auto_start_on_reconnect,
                 }),
             )
             .await;
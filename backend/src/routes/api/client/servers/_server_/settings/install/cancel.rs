@@ -77,6 +77,15 @@ mod post {
                 .execute(state.database.write())
                 .await?;
 
+                shared::models::server::Server::publish_install_progress(
+                    server.uuid,
+                    shared::models::server::InstallProgress::new(
+                        shared::models::server::InstallProgressStage::Failed,
+                    )
+                    .with_message("installation cancelled"),
+                )
+                .await;
+
                 return ApiResponse::new_serialized(Response {}).ok();
             }
             Err(err) => return Err(err.into()),
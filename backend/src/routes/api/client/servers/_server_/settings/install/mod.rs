@@ -2,6 +2,7 @@ use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod cancel;
+mod progress;
 mod unlock;
 
 mod post {
@@ -10,8 +11,9 @@ mod post {
     use shared::{
         ApiError, GetState,
         models::{
+            job::{Job, JobPayload, ServerReinstallJob},
             server::{GetServer, GetServerActivityLogger},
-            user::GetPermissionManager,
+            user::{GetPermissionManager, GetUser},
         },
         response::{ApiResponse, ApiResponseResult},
     };
@@ -23,7 +25,9 @@ mod post {
     }
 
     #[derive(ToSchema, Serialize)]
-    struct Response {}
+    struct Response {
+        job: uuid::Uuid,
+    }
 
     #[utoipa::path(post, path = "/", responses(
         (status = ACCEPTED, body = inline(Response)),
@@ -39,15 +43,31 @@ mod post {
     pub async fn route(
         state: GetState,
         permissions: GetPermissionManager,
+        user: GetUser,
         server: GetServer,
         activity_logger: GetServerActivityLogger,
         shared::Payload(data): shared::Payload<Payload>,
     ) -> ApiResponseResult {
         permissions.has_server_permission("settings.install")?;
 
-        server
-            .install(&state, data.truncate_directory, None)
-            .await?;
+        if server.status.is_some() {
+            return ApiResponse::error(
+                "server is already installing or in an invalid state for reinstalling",
+            )
+            .with_status(StatusCode::EXPECTATION_FAILED)
+            .ok();
+        }
+
+        let job = Job::create(
+            &state.database,
+            Some(user.uuid),
+            &JobPayload::ServerReinstall(ServerReinstallJob {
+                server_uuid: server.uuid,
+                user_uuid: Some(user.uuid),
+                truncate_directory: data.truncate_directory,
+            }),
+        )
+        .await?;
 
         activity_logger
             .log(
@@ -58,7 +78,7 @@ mod post {
             )
             .await;
 
-        ApiResponse::new_serialized(Response {})
+        ApiResponse::new_serialized(Response { job: job.uuid })
             .with_status(StatusCode::ACCEPTED)
             .ok()
     }
@@ -68,6 +88,7 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
     OpenApiRouter::new()
         .routes(routes!(post::route))
         .nest("/cancel", cancel::router(state))
+        .nest("/progress", progress::router(state))
         .nest("/unlock", unlock::router(state))
         .with_state(state.clone())
 }
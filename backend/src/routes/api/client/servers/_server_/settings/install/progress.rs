@@ -0,0 +1,125 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use axum::{
+        http::StatusCode,
+        response::{
+            IntoResponse,
+            sse::{Event, KeepAlive, Sse},
+        },
+    };
+    use futures_util::StreamExt;
+    use shared::{
+        ApiError, GetState,
+        models::{
+            server::{GetServer, InstallProgress, InstallProgressStage, Server, ServerStatus},
+            user::{GetPermissionManager, GetUser},
+        },
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use tokio::sync::broadcast;
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, description = "A `text/event-stream` of `progress` events", body = InstallProgress),
+        (status = UNAUTHORIZED, body = ApiError),
+        (status = FORBIDDEN, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        user: GetUser,
+        permissions: GetPermissionManager,
+        server: GetServer,
+    ) -> ApiResponseResult {
+        permissions.has_server_permission("control.read-console")?;
+
+        if !state
+            .settings
+            .get()
+            .await?
+            .server
+            .allow_viewing_installation_logs
+            && !user.admin
+        {
+            return ApiResponse::error("viewing installation progress is not allowed")
+                .with_status(StatusCode::FORBIDDEN)
+                .ok();
+        }
+
+        // subscribe before reading the status, so a final stage cannot slip in between
+        let (last_progress, receiver) = Server::subscribe_install_progress(server.uuid).await;
+        let status: Option<ServerStatus> = sqlx::query_scalar(
+            r#"
+            SELECT servers.status
+            FROM servers
+            WHERE servers.uuid = $1
+            "#,
+        )
+        .bind(server.uuid)
+        .fetch_one(state.database.write())
+        .await?;
+
+        let (last_progress, receiver) = match status {
+            Some(ServerStatus::Installing) => {
+                server.relay_install_progress_in_background(&state).await;
+
+                (last_progress, Some(receiver))
+            }
+            Some(ServerStatus::InstallFailed) => (
+                Some(InstallProgress::new(InstallProgressStage::Failed)),
+                None,
+            ),
+            _ => (
+                Some(InstallProgress::new(InstallProgressStage::Completed)),
+                None,
+            ),
+        };
+
+        // the stream ends after a final stage was sent, closing the connection
+        let events = futures_util::stream::unfold(
+            (last_progress, receiver),
+            |(pending, receiver)| async move {
+                if let Some(progress) = pending {
+                    let receiver = receiver.filter(|_| !progress.stage.is_final());
+
+                    return Some((progress, (None, receiver)));
+                }
+
+                let mut receiver = receiver?;
+                loop {
+                    match receiver.recv().await {
+                        Ok(progress) => {
+                            let receiver = Some(receiver).filter(|_| !progress.stage.is_final());
+
+                            return Some((progress, (None, receiver)));
+                        }
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+                }
+            },
+        )
+        .map(|progress| Event::default().event("progress").json_data(progress));
+
+        let response = Sse::new(events)
+            .keep_alive(KeepAlive::default())
+            .into_response();
+
+        ApiResponse::new(response.into_body())
+            .with_header("Content-Type", "text/event-stream")
+            .with_header("Cache-Control", "no-cache")
+            .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
@@ -8,7 +8,7 @@ mod post {
     use shared::{
         ApiError, GetState,
         models::{
-            server::{GetServer, GetServerActivityLogger},
+            server::{GetServer, GetServerActivityLogger, Server},
             user::GetPermissionManager,
         },
         response::{ApiResponse, ApiResponseResult},
@@ -32,6 +32,7 @@ mod post {
         (status = OK, body = inline(Response)),
         (status = BAD_REQUEST, body = ApiError),
         (status = UNAUTHORIZED, body = ApiError),
+        (status = CONFLICT, body = ApiError),
     ), params(
         (
             "server" = uuid::Uuid,
@@ -54,7 +55,20 @@ mod post {
 
         permissions.has_server_permission("settings.rename")?;
 
+        let mut transaction = state.database.write().begin().await?;
+
         if let Some(name) = data.name {
+            if name != server.name {
+                Server::validate_name_uniqueness(
+                    &state,
+                    &mut transaction,
+                    &name,
+                    server.owner.uuid,
+                    Some(server.uuid),
+                )
+                .await?;
+            }
+
             server.name = name;
         }
         if let Some(description) = data.description {
@@ -73,9 +87,11 @@ mod post {
             server.description.as_deref(),
             server.uuid
         )
-        .execute(state.database.write())
+        .execute(&mut *transaction)
         .await?;
 
+        transaction.commit().await?;
+
         activity_logger
             .log(
                 "server:settings.rename",
@@ -2,6 +2,8 @@ use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod put {
+    use axum::http::StatusCode;
+    use garde::Validate;
     use serde::{Deserialize, Serialize};
     use shared::{
         ApiError, GetState,
@@ -13,10 +15,14 @@ mod put {
     };
     use utoipa::ToSchema;
 
-    #[derive(ToSchema, Deserialize)]
+    #[derive(ToSchema, Validate, Deserialize)]
     pub struct Payload {
-        #[schema(min_length = 3, max_length = 255, value_type = Option<String>)]
-        timezone: Option<chrono_tz::Tz>,
+        #[garde(
+            length(chars, min = 3, max = 255),
+            inner(custom(shared::utils::validate_timezone))
+        )]
+        #[schema(min_length = 3, max_length = 255)]
+        timezone: Option<compact_str::CompactString>,
     }
 
     #[derive(ToSchema, Serialize)]
@@ -40,13 +46,19 @@ mod put {
         activity_logger: GetServerActivityLogger,
         shared::Payload(data): shared::Payload<Payload>,
     ) -> ApiResponseResult {
+        if let Err(errors) = shared::utils::validate_data(&data) {
+            return ApiResponse::new_serialized(ApiError::new_strings_value(errors))
+                .with_status(StatusCode::BAD_REQUEST)
+                .ok();
+        }
+
         permissions.has_server_permission("settings.timezone")?;
 
         sqlx::query!(
             "UPDATE servers
             SET timezone = $1
             WHERE servers.uuid = $2",
-            data.timezone.map(|tz| tz.name()),
+            data.timezone.as_deref(),
             server.uuid
         )
         .execute(state.database.write())
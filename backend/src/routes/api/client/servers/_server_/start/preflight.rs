@@ -0,0 +1,104 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{server::GetServer, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize, Clone, Copy)]
+    #[serde(rename_all = "snake_case")]
+    enum PreflightResource {
+        Memory,
+        Disk,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response {
+        ok: bool,
+        #[schema(inline)]
+        limiting_resource: Option<PreflightResource>,
+
+        memory_required_bytes: u64,
+        memory_available_bytes: u64,
+        disk_required_bytes: u64,
+        disk_available_bytes: u64,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+    ) -> ApiResponseResult {
+        permissions.has_server_permission("control.start")?;
+
+        let node = server.node.fetch_cached(&state.database).await?;
+        let (stats, resource_usages) = tokio::try_join!(
+            node.fetch_system_stats(&state.database),
+            node.fetch_server_resources(&state.database),
+        )?;
+
+        // whatever the server is already using counts towards its own limits
+        let (memory_used_bytes, disk_used_bytes) = resource_usages
+            .get(&server.uuid)
+            .map(|usage| (usage.memory_bytes, usage.disk_bytes))
+            .unwrap_or_default();
+
+        let memory_limit_bytes =
+            (server.memory.max(0) + server.memory_overhead.max(0)) as u64 * 1024 * 1024;
+        let disk_limit_bytes = server.disk.max(0) as u64 * 1024 * 1024;
+
+        // a limit of 0 means unlimited, nothing has to be reserved up front
+        let memory_required_bytes = if server.memory == 0 {
+            0
+        } else {
+            memory_limit_bytes.saturating_sub(memory_used_bytes)
+        };
+        let disk_required_bytes = if server.disk == 0 {
+            0
+        } else {
+            disk_limit_bytes.saturating_sub(disk_used_bytes)
+        };
+
+        let memory_available_bytes = stats.memory.total.saturating_sub(stats.memory.used);
+        let disk_available_bytes = stats.disk.total.saturating_sub(stats.disk.used);
+
+        let limiting_resource = if memory_required_bytes > memory_available_bytes {
+            Some(PreflightResource::Memory)
+        } else if disk_required_bytes > disk_available_bytes {
+            Some(PreflightResource::Disk)
+        } else {
+            None
+        };
+
+        ApiResponse::new_serialized(Response {
+            ok: limiting_resource.is_none(),
+            limiting_resource,
+            memory_required_bytes,
+            memory_available_bytes,
+            disk_required_bytes,
+            disk_available_bytes,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
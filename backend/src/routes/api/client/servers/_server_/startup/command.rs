@@ -8,6 +8,7 @@ mod put {
     use shared::{
         ApiError, GetState,
         models::{
+            nest_egg::NestEgg,
             server::{GetServer, GetServerActivityLogger},
             user::GetPermissionManager,
         },
@@ -60,6 +61,8 @@ mod put {
                 .ok();
         }
 
+        NestEgg::validate_startup(&state.database, server.egg.uuid, &data.command).await?;
+
         sqlx::query!(
             "UPDATE servers
             SET startup = $1
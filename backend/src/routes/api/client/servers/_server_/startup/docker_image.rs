@@ -52,12 +52,7 @@ mod put {
 
         permissions.has_server_permission("startup.docker-image")?;
 
-        if !server
-            .egg
-            .docker_images
-            .values()
-            .any(|image| image == data.image)
-        {
+        if !server.egg.has_docker_image(&data.image) {
             return ApiResponse::error("the specified docker image is not available")
                 .with_status(StatusCode::EXPECTATION_FAILED)
                 .ok();
@@ -65,12 +60,14 @@ mod put {
 
         let settings = state.settings.get().await?;
 
+        if let Err(registry) = settings.server.validate_image_registry(&data.image) {
+            return ApiResponse::error(format!("images from registry {registry} are not allowed"))
+                .with_status(StatusCode::EXPECTATION_FAILED)
+                .ok();
+        }
+
         if !settings.server.allow_overwriting_custom_docker_image
-            && !server
-                .egg
-                .docker_images
-                .iter()
-                .any(|(_, image)| image == server.image)
+            && !server.egg.has_docker_image(&server.image)
         {
             return ApiResponse::error("overwriting custom docker images is not allowed")
                 .with_status(StatusCode::EXPECTATION_FAILED)
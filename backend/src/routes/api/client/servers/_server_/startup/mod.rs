@@ -3,6 +3,7 @@ use utoipa_axum::router::OpenApiRouter;
 
 mod command;
 mod docker_image;
+mod preview;
 mod variables;
 
 pub fn router(state: &State) -> OpenApiRouter<State> {
@@ -10,5 +11,6 @@ pub fn router(state: &State) -> OpenApiRouter<State> {
         .nest("/variables", variables::router(state))
         .nest("/docker-image", docker_image::router(state))
         .nest("/command", command::router(state))
+        .nest("/preview", preview::router(state))
         .with_state(state.clone())
 }
@@ -0,0 +1,87 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        ApiError, GetState,
+        models::{server::GetServer, server_variable::ServerVariable, user::GetPermissionManager},
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseVariable<'a> {
+        env_variable: &'a str,
+        value: &'a str,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response<'a> {
+        startup: String,
+        #[schema(inline)]
+        variables: Vec<ResponseVariable<'a>>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+        (status = UNAUTHORIZED, body = ApiError),
+        (status = BAD_REQUEST, body = ApiError),
+    ), params(
+        (
+            "server" = uuid::Uuid,
+            description = "The server ID",
+            example = "123e4567-e89b-12d3-a456-426614174000",
+        ),
+    ))]
+    pub async fn route(
+        state: GetState,
+        permissions: GetPermissionManager,
+        server: GetServer,
+    ) -> ApiResponseResult {
+        permissions.has_server_permission("startup.read")?;
+
+        let variables = ServerVariable::all_by_server_uuid_egg_uuid(
+            &state.database,
+            server.uuid,
+            server.egg.uuid,
+        )
+        .await?;
+
+        let resolved = ServerVariable::resolve_user_viewable_references(&variables)?;
+
+        let mut startup = server.startup.to_string();
+        let mut response_variables = Vec::new();
+
+        for variable in variables
+            .iter()
+            .filter(|variable| variable.variable.user_viewable)
+        {
+            let env_variable = variable.variable.env_variable.as_str();
+            let value = resolved
+                .get(env_variable)
+                .map_or(variable.value.as_str(), |value| value.as_str());
+
+            if !variable.variable.secret {
+                startup = startup.replace(&format!("{{{{{env_variable}}}}}"), value);
+            }
+
+            response_variables.push(ResponseVariable {
+                env_variable,
+                value,
+            });
+        }
+
+        ApiResponse::new_serialized(Response {
+            startup,
+            variables: response_variables,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
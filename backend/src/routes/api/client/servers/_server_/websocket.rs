@@ -41,6 +41,8 @@ mod get {
 
         let node = server.node.fetch_cached(&state.database).await?;
 
+        let websocket_ttl = state.settings.jwt().await?.websocket_ttl;
+
         let token = node.create_jwt(
             &state.database,
             &state.jwt,
@@ -49,7 +51,7 @@ mod get {
                     issuer: "panel".into(),
                     subject: None,
                     audience: Vec::new(),
-                    expiration_time: Some(chrono::Utc::now().timestamp() + 600),
+                    expiration_time: Some(chrono::Utc::now().timestamp() + websocket_ttl as i64),
                     not_before: None,
                     issued_at: Some(chrono::Utc::now().timestamp()),
                     jwt_id: user.uuid.to_string(),
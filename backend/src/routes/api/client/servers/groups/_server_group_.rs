@@ -87,7 +87,7 @@ mod get {
 
         ApiResponse::new_serialized(Response {
             servers: servers
-                .try_async_map(|server| server.into_api_object(&state.database, &user))
+                .try_async_map(|server| server.into_api_object(&state, &user))
                 .await?,
         })
         .ok()
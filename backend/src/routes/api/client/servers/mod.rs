@@ -26,7 +26,7 @@ mod get {
         #[garde(range(min = 1))]
         #[serde(default = "Pagination::default_page")]
         page: i64,
-        #[garde(range(min = 1, max = 100))]
+        #[garde(custom(shared::models::validate_per_page))]
         #[serde(default = "Pagination::default_per_page")]
         per_page: i64,
         #[garde(length(chars, min = 1, max = 100))]
@@ -108,7 +108,14 @@ mod get {
 
         ApiResponse::new_serialized(Response {
             servers: servers
-                .try_async_map(|server| server.into_api_object(&state.database, &user))
+                .try_async_map(|server| async {
+                    let power_state = server.fetch_power_state_optional(&state).await;
+
+                    let mut server = server.into_api_object(&state, &user).await?;
+                    server.power_state = power_state;
+
+                    Ok::<_, anyhow::Error>(server)
+                })
                 .await?,
         })
         .ok()
@@ -144,6 +144,15 @@ mod get {
             }
         };
 
+        if server.exceeds_backup_size_limit(params.size) {
+            return ApiResponse::error(format!(
+                "backup exceeds the maximum backup size of {} MiB",
+                server.backup_size_limit.unwrap_or_default()
+            ))
+            .with_status(StatusCode::EXPECTATION_FAILED)
+            .ok();
+        }
+
         let part_size = s3_configuration.part_size;
         let part_count = (params.size as f64 / s3_configuration.part_size as f64).ceil() as usize;
         let mut parts = Vec::new();
@@ -273,6 +282,27 @@ mod post {
         backup: GetBackup,
         axum::Json(mut data): axum::Json<Payload>,
     ) -> ApiResponseResult {
+        let (oversized, backup_size_limit) = match &backup.0.server {
+            Some(server) => {
+                let server = server.fetch_cached(&state.database).await?;
+
+                (
+                    data.successful && server.exceeds_backup_size_limit(data.size),
+                    server.backup_size_limit,
+                )
+            }
+            None => (false, None),
+        };
+        if oversized {
+            tracing::warn!(
+                backup = %backup.0.uuid,
+                size = data.size,
+                "backup exceeds the maximum backup size of the server, discarding"
+            );
+
+            data.successful = false;
+        }
+
         if backup.disk == BackupDisk::S3 {
             let upload_id = match backup.0.upload_id {
                 Some(id) => id,
@@ -399,6 +429,26 @@ mod post {
             }
         }
 
+        if oversized
+            && backup.0.disk != BackupDisk::S3
+            && let Err(err) = node
+                .api_client(&state.database)
+                .await?
+                .delete_backups_backup(
+                    backup.0.uuid,
+                    &wings_api::backups_backup::delete::RequestBody {
+                        adapter: backup.0.disk.to_wings_adapter(),
+                    },
+                )
+                .await
+        {
+            tracing::error!(
+                backup = %backup.0.uuid,
+                "failed to delete oversized backup on node: {:?}",
+                err
+            );
+        }
+
         if data.successful {
             sqlx::query!(
                 "UPDATE server_backups
@@ -440,10 +490,21 @@ mod post {
                     }
                     .into(),
                     ip: None,
-                    data: serde_json::json!({
-                        "uuid": backup.0.uuid,
-                        "name": backup.0.name,
-                    }),
+                    data: if oversized {
+                        serde_json::json!({
+                            "uuid": backup.0.uuid,
+                            "name": backup.0.name,
+                            "error": format!(
+                                "backup exceeds the maximum backup size of {} MiB",
+                                backup_size_limit.unwrap_or_default()
+                            ),
+                        })
+                    } else {
+                        serde_json::json!({
+                            "uuid": backup.0.uuid,
+                            "name": backup.0.name,
+                        })
+                    },
                     created: None,
                 },
             )
@@ -456,6 +517,15 @@ mod post {
             );
         }
 
+        if let Some(server) = &backup.0.server
+            && let Ok(server) = server.fetch_cached(&state.database).await
+        {
+            backup
+                .0
+                .send_completed_notification(&state, &server, data.successful)
+                .await;
+        }
+
         ApiResponse::new_serialized(Response {}).ok()
     }
 }
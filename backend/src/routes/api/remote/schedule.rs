@@ -36,8 +36,10 @@ mod post {
         state: GetState,
         shared::Payload(data): shared::Payload<Payload>,
     ) -> ApiResponseResult {
+        let mut finished_schedules = Vec::new();
+
         for schedule_status in data.data {
-            let schedule = match ServerSchedule::by_uuid_optional_cached(
+            let mut schedule = match ServerSchedule::by_uuid_optional_cached(
                 &state.database,
                 schedule_status.uuid,
             )
@@ -100,8 +102,27 @@ mod post {
             }
 
             futures_util::future::try_join_all(futures).await?;
+
+            finished_schedules.push(schedule);
         }
 
+        // starting queued runs waits on the trigger locks and the node, keep it off the request
+        tokio::spawn({
+            let state = state.clone();
+
+            async move {
+                for mut schedule in finished_schedules {
+                    if let Err(err) = schedule.finish_execution(&state).await {
+                        tracing::warn!(
+                            schedule = %schedule.uuid,
+                            "failed to finish schedule execution: {:?}",
+                            err
+                        );
+                    }
+                }
+            }
+        });
+
         ApiResponse::new_serialized(Response {}).ok()
     }
 }
@@ -77,6 +77,26 @@ mod post {
         .execute(state.database.write())
         .await?;
 
+        shared::models::server_reinstall_history::ServerReinstallHistory::complete_latest(
+            &state.database,
+            server.uuid,
+            data.successful,
+        )
+        .await?;
+
+        // the server may have been started on completion
+        shared::models::server::Server::invalidate_power_state(&state.database, server.uuid).await;
+
+        shared::models::server::Server::publish_install_progress(
+            server.uuid,
+            shared::models::server::InstallProgress::new(if data.successful {
+                shared::models::server::InstallProgressStage::Completed
+            } else {
+                shared::models::server::InstallProgressStage::Failed
+            }),
+        )
+        .await;
+
         shared::models::server::Server::get_event_emitter().emit(
             state.0,
             shared::models::server::ServerEvent::InstallCompleted {
@@ -50,12 +50,7 @@ mod put {
                 .ok();
         }
 
-        if !server
-            .egg
-            .docker_images
-            .values()
-            .any(|image| image == data.image)
-        {
+        if !server.egg.has_docker_image(&data.image) {
             return ApiResponse::error("the specified docker image is not available")
                 .with_status(StatusCode::EXPECTATION_FAILED)
                 .ok();
@@ -64,11 +59,7 @@ mod put {
         let settings = state.settings.get().await?;
 
         if !settings.server.allow_overwriting_custom_docker_image
-            && !server
-                .egg
-                .docker_images
-                .iter()
-                .any(|(_, image)| image == server.image)
+            && !server.egg.has_docker_image(&server.image)
         {
             return ApiResponse::error("overwriting custom docker images is not allowed")
                 .with_status(StatusCode::EXPECTATION_FAILED)
@@ -80,6 +80,14 @@ mod post {
 
         transaction.commit().await?;
 
+        shared::models::job::Job::finish_server_transfer(
+            &state.database,
+            server.uuid,
+            destination_node.uuid,
+            false,
+        )
+        .await?;
+
         shared::models::server::Server::get_event_emitter().emit(
             state.0,
             shared::models::server::ServerEvent::TransferCompleted {
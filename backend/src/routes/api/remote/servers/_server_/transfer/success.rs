@@ -6,7 +6,11 @@ mod post {
     use serde::{Deserialize, Serialize};
     use shared::{
         ApiError, GetState,
-        models::{EventEmittingModel, node::GetNode, server::GetServer},
+        models::{
+            EventEmittingModel,
+            node::GetNode,
+            server::{GetServer, Server},
+        },
         response::{ApiResponse, ApiResponseResult},
     };
     use utoipa::ToSchema;
@@ -77,6 +81,9 @@ mod post {
             .execute(&mut *transaction)
         )?;
 
+        Server::adjust_server_counters(&mut transaction, server.node.uuid, -1).await?;
+        Server::adjust_server_counters(&mut transaction, destination_node.uuid, 1).await?;
+
         sqlx::query!(
             r#"
             UPDATE server_backups
@@ -101,6 +108,14 @@ mod post {
 
         transaction.commit().await?;
 
+        shared::models::job::Job::finish_server_transfer(
+            &state.database,
+            server.uuid,
+            destination_node.uuid,
+            true,
+        )
+        .await?;
+
         if let Err(err) = server
             .node
             .fetch_cached(&state.database)
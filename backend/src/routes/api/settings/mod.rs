@@ -1,6 +1,8 @@
 use super::State;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
+mod public;
+
 mod get {
     use serde::Serialize;
     use shared::{
@@ -85,6 +87,7 @@ mod get {
 
 pub fn router(state: &State) -> OpenApiRouter<State> {
     OpenApiRouter::new()
+        .nest("/public", public::router(state))
         .routes(routes!(get::route))
         .with_state(state.clone())
 }
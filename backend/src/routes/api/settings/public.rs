@@ -0,0 +1,77 @@
+use super::State;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+mod get {
+    use serde::Serialize;
+    use shared::{
+        GetState,
+        response::{ApiResponse, ApiResponseResult},
+    };
+    use utoipa::ToSchema;
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseApp<'a> {
+        name: &'a str,
+        icon: &'a str,
+        url: &'a str,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseSupport<'a> {
+        email: Option<&'a str>,
+        docs_url: Option<&'a str>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct ResponseBranding<'a> {
+        logo_url: Option<&'a str>,
+        primary_color: Option<&'a str>,
+        footer_text: Option<&'a str>,
+    }
+
+    #[derive(ToSchema, Serialize)]
+    struct Response<'a> {
+        #[schema(inline)]
+        app: ResponseApp<'a>,
+        #[schema(inline)]
+        support: ResponseSupport<'a>,
+        #[schema(inline)]
+        branding: ResponseBranding<'a>,
+        /// Feature flags enabled for everyone, user targeted flags are part of the account.
+        feature_flags: Vec<compact_str::CompactString>,
+    }
+
+    #[utoipa::path(get, path = "/", responses(
+        (status = OK, body = inline(Response)),
+    ))]
+    pub async fn route(state: GetState) -> ApiResponseResult {
+        let feature_flags = state.features.enabled_for(None).await?;
+        let settings = state.settings.get().await?;
+
+        // only fields listed here are exposed, never serialize settings sections directly
+        ApiResponse::new_serialized(Response {
+            app: ResponseApp {
+                name: &settings.app.name,
+                icon: &settings.app.icon,
+                url: &settings.app.url,
+            },
+            support: ResponseSupport {
+                email: settings.support.email.as_deref(),
+                docs_url: settings.support.docs_url.as_deref(),
+            },
+            branding: ResponseBranding {
+                logo_url: settings.branding.logo_url.as_deref(),
+                primary_color: settings.branding.primary_color.as_deref(),
+                footer_text: settings.branding.footer_text.as_deref(),
+            },
+            feature_flags,
+        })
+        .ok()
+    }
+}
+
+pub fn router(state: &State) -> OpenApiRouter<State> {
+    OpenApiRouter::new()
+        .routes(routes!(get::route))
+        .with_state(state.clone())
+}
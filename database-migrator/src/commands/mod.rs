@@ -1,6 +1,7 @@
 use shared::extensions::commands::CliCommandGroupBuilder;
 
 mod migrate;
+mod rollback;
 mod status;
 mod version;
 
@@ -15,6 +16,11 @@ pub fn commands(cli: CliCommandGroupBuilder) -> CliCommandGroupBuilder {
         "Applies pending database migrations.",
         migrate::MigrateCommand,
     )
+    .add_command(
+        "rollback",
+        "Reverts the most recently applied database migrations.",
+        rollback::RollbackCommand,
+    )
     .add_command(
         "version",
         "Prints the current executable version and exits.",
@@ -0,0 +1,131 @@
+use clap::{Args, FromArgMatches};
+use colored::Colorize;
+use std::sync::Arc;
+
+#[derive(Args)]
+pub struct RollbackArgs {
+    #[arg(
+        long = "live",
+        help = "whether to use the on-disk migrations instead of the embedded migrations",
+        default_value = "false"
+    )]
+    live: bool,
+    #[arg(
+        long = "steps",
+        help = "the amount of most recently applied migrations to revert",
+        default_value = "1"
+    )]
+    steps: usize,
+}
+
+pub struct RollbackCommand;
+
+impl shared::extensions::commands::CliCommand<RollbackArgs> for RollbackCommand {
+    fn get_command(&self, command: clap::Command) -> clap::Command {
+        command
+    }
+
+    fn get_executor(self) -> Box<shared::extensions::commands::ExecutorFunc> {
+        Box::new(|env, arg_matches| {
+            Box::pin(async move {
+                let args = RollbackArgs::from_arg_matches(&arg_matches)?;
+
+                let env = match env {
+                    Some(env) => env,
+                    None => {
+                        eprintln!(
+                            "{}",
+                            "please setup the panel environment before using this tool.".red()
+                        );
+
+                        return Ok(1);
+                    }
+                };
+
+                let cache = shared::cache::Cache::new(&env).await;
+                let database = Arc::new(shared::database::Database::new(&env, cache.clone()).await);
+
+                crate::ensure_migrations_table(database.write()).await?;
+
+                tracing::info!("fetching applied migrations...");
+                let mut applied_migrations =
+                    crate::fetch_applied_migrations(database.write()).await?;
+                applied_migrations.sort_by_key(|am| std::cmp::Reverse((am.created, am.applied)));
+
+                let migrations = if args.live {
+                    let live_path = match () {
+                        _ if tokio::fs::metadata("migrations").await.is_ok() => "migrations",
+                        _ if tokio::fs::metadata("database/migrations").await.is_ok() => {
+                            "database/migrations"
+                        }
+                        _ if tokio::fs::metadata("../database/migrations").await.is_ok() => {
+                            "../database/migrations"
+                        }
+                        _ => {
+                            tracing::error!(
+                                "failed to find live migrations folder, expected one of: ./migrations, ./database/migrations, ../database/migrations"
+                            );
+                            return Ok(1);
+                        }
+                    };
+
+                    tracing::info!("collecting migrations from filesystem...");
+                    crate::collect_migrations(live_path).await?
+                } else {
+                    tracing::info!("collecting embedded migrations...");
+                    crate::collect_embedded_migrations()?
+                };
+
+                let mut rollback_migrations = Vec::new();
+                for applied_migration in applied_migrations.iter().take(args.steps) {
+                    let migration = match migrations
+                        .iter()
+                        .find(|m| m.snapshot.id == applied_migration.id)
+                    {
+                        Some(migration) => migration,
+                        None => {
+                            eprintln!(
+                                "{}: {}",
+                                "applied migration not found in available migrations".red(),
+                                applied_migration.name
+                            );
+                            return Ok(1);
+                        }
+                    };
+
+                    if migration.down_sql.is_none() {
+                        eprintln!(
+                            "{}: {}",
+                            "migration has no down.sql, cannot roll back".red(),
+                            migration.name
+                        );
+                        return Ok(1);
+                    }
+
+                    rollback_migrations.push(migration);
+                }
+
+                tracing::info!("rolling back {} migrations.", rollback_migrations.len());
+
+                let mut reverted_migrations = 0;
+                for migration in rollback_migrations {
+                    tracing::info!(name = %migration.name, "rolling back migration");
+
+                    if let Err(err) = crate::rollback_migration(database.write(), migration).await {
+                        eprintln!("{}: {}", "failed to roll back migration".red(), err);
+                        return Ok(1);
+                    }
+
+                    tracing::info!(name = %migration.name, "successfully rolled back migration");
+                    tracing::info!("");
+
+                    reverted_migrations += 1;
+                }
+
+                tracing::info!("rolled back {} migrations.", reverted_migrations);
+
+                Ok(0)
+            })
+        })
+    }
+}
@@ -119,6 +119,7 @@ pub struct Migration {
     pub name: String,
     pub date: chrono::DateTime<chrono::Utc>,
     pub sql: String,
+    pub down_sql: Option<String>,
     pub snapshot: MigrationSnapshot,
 }
 
@@ -127,9 +128,11 @@ impl Migration {
         path: &Path,
         snapshot: impl std::io::Read,
         sql: impl std::io::Read,
+        down_sql: Option<impl std::io::Read>,
     ) -> Result<Self, std::io::Error> {
         // 20260125115245_xxx_xxx
         //   - migration.sql
+        //   - down.sql (optional)
         //   - snapshot.json
 
         let date = path
@@ -165,6 +168,15 @@ impl Migration {
                 buf.shrink_to_fit();
                 buf
             },
+            down_sql: match down_sql {
+                Some(down_sql) => {
+                    let mut buf = String::new();
+                    std::io::BufReader::new(down_sql).read_to_string(&mut buf)?;
+                    buf.shrink_to_fit();
+                    Some(buf)
+                }
+                None => None,
+            },
             snapshot,
         })
     }
@@ -172,6 +184,7 @@ impl Migration {
     pub async fn from_directory(path: &Path) -> Result<Self, std::io::Error> {
         // 20260125115245_xxx_xxx
         //   - migration.sql
+        //   - down.sql (optional)
         //   - snapshot.json
 
         let date = path
@@ -213,6 +226,15 @@ impl Migration {
         let mut sql = tokio::fs::read_to_string(path.join("migration.sql")).await?;
         sql.shrink_to_fit();
 
+        let down_sql = match tokio::fs::read_to_string(path.join("down.sql")).await {
+            Ok(mut down_sql) => {
+                down_sql.shrink_to_fit();
+                Some(down_sql)
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+
         Ok(Migration {
             name: path
                 .file_name()
@@ -221,6 +243,7 @@ impl Migration {
                 .to_string(),
             date,
             sql,
+            down_sql,
             snapshot,
         })
     }
@@ -259,6 +282,9 @@ pub fn collect_embedded_migrations() -> Result<Vec<Migration>, std::io::Error> {
                     ));
                 }
             },
+            MIGRATIONS
+                .get_file(entry.path().join("down.sql"))
+                .map(|file| file.contents()),
         )?;
         migrations.push(migration);
     }
@@ -364,3 +390,48 @@ pub async fn run_migration(pool: &sqlx::PgPool, migration: &Migration) -> Result
 
     Ok(())
 }
+
+pub async fn unmark_migration_as_applied(
+    pool: impl Executor<'_, Database = sqlx::Postgres>,
+    migration: &Migration,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM migrations WHERE id = $1")
+        .bind(migration.snapshot.id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Reverts a single migration within a transaction using its `down.sql`.
+/// Like `run_migration`, either all changes are reverted or none are.
+///
+/// If everything goes well, the transaction is committed and the migration is no longer marked as applied.
+/// Returns an error without touching the database if the migration has no down script.
+pub async fn rollback_migration(
+    pool: &sqlx::PgPool,
+    migration: &Migration,
+) -> Result<(), sqlx::Error> {
+    let down_sql = match &migration.down_sql {
+        Some(down_sql) => down_sql,
+        None => {
+            return Err(sqlx::Error::Configuration(
+                format!("migration {} has no down.sql", migration.name).into(),
+            ));
+        }
+    };
+
+    let mut transaction = pool.begin().await?;
+
+    let mut query_stream = (&mut transaction).execute_many(&**down_sql);
+    while let Some(result) = query_stream.next().await {
+        result?;
+    }
+    drop(query_stream);
+
+    unmark_migration_as_applied(&mut *transaction, migration).await?;
+
+    transaction.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+CREATE TABLE "node_tags" (
+	"node_uuid" uuid NOT NULL,
+	"name" varchar(124) NOT NULL,
+	"created" timestamp DEFAULT now() NOT NULL,
+	CONSTRAINT "node_tags_pk" PRIMARY KEY("node_uuid","name")
+);
+
+CREATE INDEX "node_tags_node_uuid_idx" ON "node_tags" ("node_uuid");
+CREATE INDEX "node_tags_name_idx" ON "node_tags" ("name");
+ALTER TABLE "node_tags" ADD CONSTRAINT "node_tags_node_uuid_nodes_uuid_fkey" FOREIGN KEY ("node_uuid") REFERENCES "nodes"("uuid") ON DELETE CASCADE;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE "nest_egg_variables" DROP COLUMN "resolve_references";
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE "nest_egg_variables" ADD COLUMN "resolve_references" boolean DEFAULT false NOT NULL;
\ No newline at end of file
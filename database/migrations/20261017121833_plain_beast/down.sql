@@ -0,0 +1 @@
+ALTER TABLE "nodes" DROP COLUMN "deployment_weight";
\ No newline at end of file
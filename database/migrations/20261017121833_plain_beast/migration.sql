@@ -0,0 +1 @@
+ALTER TABLE "nodes" ADD COLUMN "deployment_weight" smallint DEFAULT 100 NOT NULL;
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE "locations" DROP COLUMN "server_count";
+ALTER TABLE "nodes" DROP COLUMN "server_count";
+ALTER TABLE "servers" DROP COLUMN "backup_count";
\ No newline at end of file
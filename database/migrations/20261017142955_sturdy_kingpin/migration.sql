@@ -0,0 +1,6 @@
+ALTER TABLE "locations" ADD COLUMN "server_count" integer DEFAULT 0 NOT NULL;
+ALTER TABLE "nodes" ADD COLUMN "server_count" integer DEFAULT 0 NOT NULL;
+ALTER TABLE "servers" ADD COLUMN "backup_count" integer DEFAULT 0 NOT NULL;
+UPDATE "nodes" SET "server_count" = (SELECT COUNT(*) FROM "servers" WHERE "servers"."node_uuid" = "nodes"."uuid");
+UPDATE "locations" SET "server_count" = (SELECT COALESCE(SUM("nodes"."server_count"), 0) FROM "nodes" WHERE "nodes"."location_uuid" = "locations"."uuid");
+UPDATE "servers" SET "backup_count" = (SELECT COUNT(*) FROM "server_backups" WHERE "server_backups"."server_uuid" = "servers"."uuid" AND "server_backups"."deleted" IS NULL);
\ No newline at end of file
@@ -0,0 +1,2 @@
+DROP TABLE "jobs";
+DROP TYPE "public"."job_status";
\ No newline at end of file
@@ -0,0 +1,18 @@
+CREATE TYPE "public"."job_status" AS ENUM('PENDING', 'RUNNING', 'COMPLETED', 'FAILED');
+CREATE TABLE "jobs" (
+	"uuid" uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+	"user_uuid" uuid,
+	"type" varchar(124) NOT NULL,
+	"status" "job_status" DEFAULT 'PENDING' NOT NULL,
+	"progress" smallint DEFAULT 0 NOT NULL,
+	"payload" jsonb NOT NULL,
+	"result" jsonb,
+	"error" text,
+	"started" timestamp,
+	"completed" timestamp,
+	"created" timestamp DEFAULT now() NOT NULL
+);
+
+CREATE INDEX "jobs_user_uuid_idx" ON "jobs" ("user_uuid");
+CREATE INDEX "jobs_status_idx" ON "jobs" ("status");
+ALTER TABLE "jobs" ADD CONSTRAINT "jobs_user_uuid_users_uuid_fkey" FOREIGN KEY ("user_uuid") REFERENCES "users"("uuid") ON DELETE SET NULL;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP INDEX "servers_name_idx";
\ No newline at end of file
@@ -0,0 +1 @@
+CREATE INDEX "servers_name_idx" ON "servers" ("name");
\ No newline at end of file
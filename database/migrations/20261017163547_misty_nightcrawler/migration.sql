@@ -0,0 +1,15 @@
+CREATE TABLE "admin_approvals" (
+	"uuid" uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+	"action" varchar(124) NOT NULL,
+	"target_uuid" uuid NOT NULL,
+	"requester_uuid" uuid NOT NULL,
+	"approver_uuid" uuid,
+	"approved" timestamp,
+	"expires" timestamp NOT NULL,
+	"created" timestamp DEFAULT now() NOT NULL
+);
+
+CREATE INDEX "admin_approvals_action_target_uuid_idx" ON "admin_approvals" ("action","target_uuid");
+CREATE INDEX "admin_approvals_requester_uuid_idx" ON "admin_approvals" ("requester_uuid");
+ALTER TABLE "admin_approvals" ADD CONSTRAINT "admin_approvals_requester_uuid_users_uuid_fkey" FOREIGN KEY ("requester_uuid") REFERENCES "users"("uuid") ON DELETE CASCADE;
+ALTER TABLE "admin_approvals" ADD CONSTRAINT "admin_approvals_approver_uuid_users_uuid_fkey" FOREIGN KEY ("approver_uuid") REFERENCES "users"("uuid") ON DELETE SET NULL;
\ No newline at end of file
@@ -0,0 +1,9 @@
+CREATE TABLE "user_notification_preferences" (
+	"user_uuid" uuid PRIMARY KEY,
+	"backup_completed" boolean DEFAULT true NOT NULL,
+	"server_suspended" boolean DEFAULT true NOT NULL,
+	"security_alerts" boolean DEFAULT true NOT NULL,
+	"updated" timestamp DEFAULT now() NOT NULL
+);
+
+ALTER TABLE "user_notification_preferences" ADD CONSTRAINT "user_notification_preferences_user_uuid_users_uuid_fkey" FOREIGN KEY ("user_uuid") REFERENCES "users"("uuid") ON DELETE CASCADE;
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE "user_notification_preferences" DROP COLUMN "resource_alerts";
+DROP TABLE "server_resource_alerts";
\ No newline at end of file
@@ -0,0 +1,10 @@
+CREATE TABLE "server_resource_alerts" (
+	"server_uuid" uuid PRIMARY KEY,
+	"cpu_threshold" smallint,
+	"memory_threshold" smallint,
+	"window_seconds" integer DEFAULT 300 NOT NULL,
+	"created" timestamp DEFAULT now() NOT NULL
+);
+
+ALTER TABLE "user_notification_preferences" ADD COLUMN "resource_alerts" boolean DEFAULT true NOT NULL;
+ALTER TABLE "server_resource_alerts" ADD CONSTRAINT "server_resource_alerts_server_uuid_servers_uuid_fkey" FOREIGN KEY ("server_uuid") REFERENCES "servers"("uuid") ON DELETE CASCADE;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE "servers" DROP COLUMN "backup_size_limit";
\ No newline at end of file
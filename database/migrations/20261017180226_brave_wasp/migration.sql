@@ -0,0 +1 @@
+ALTER TABLE "servers" ADD COLUMN "backup_size_limit" bigint;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE "server_reinstall_history";
\ No newline at end of file
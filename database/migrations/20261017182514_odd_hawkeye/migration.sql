@@ -0,0 +1,17 @@
+CREATE TABLE "server_reinstall_history" (
+	"uuid" uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+	"server_uuid" uuid NOT NULL,
+	"old_egg_uuid" uuid,
+	"new_egg_uuid" uuid,
+	"user_uuid" uuid,
+	"truncate_directory" boolean NOT NULL,
+	"successful" boolean,
+	"completed" timestamp,
+	"created" timestamp DEFAULT now() NOT NULL
+);
+
+CREATE INDEX "server_reinstall_history_server_uuid_created_idx" ON "server_reinstall_history" ("server_uuid","created");
+ALTER TABLE "server_reinstall_history" ADD CONSTRAINT "server_reinstall_history_server_uuid_servers_uuid_fkey" FOREIGN KEY ("server_uuid") REFERENCES "servers"("uuid") ON DELETE CASCADE;
+ALTER TABLE "server_reinstall_history" ADD CONSTRAINT "server_reinstall_history_old_egg_uuid_nest_eggs_uuid_fkey" FOREIGN KEY ("old_egg_uuid") REFERENCES "nest_eggs"("uuid") ON DELETE SET NULL;
+ALTER TABLE "server_reinstall_history" ADD CONSTRAINT "server_reinstall_history_new_egg_uuid_nest_eggs_uuid_fkey" FOREIGN KEY ("new_egg_uuid") REFERENCES "nest_eggs"("uuid") ON DELETE SET NULL;
+ALTER TABLE "server_reinstall_history" ADD CONSTRAINT "server_reinstall_history_user_uuid_users_uuid_fkey" FOREIGN KEY ("user_uuid") REFERENCES "users"("uuid") ON DELETE SET NULL;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE "users" DROP COLUMN "backup_storage_limit";
\ No newline at end of file
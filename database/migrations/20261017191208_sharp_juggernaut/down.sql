@@ -0,0 +1,4 @@
+ALTER TABLE "server_schedules" DROP COLUMN "last_skipped";
+ALTER TABLE "server_schedules" DROP COLUMN "skipped_runs";
+ALTER TABLE "server_schedules" DROP COLUMN "overlap_behavior";
+DROP TYPE "public"."server_schedule_overlap_behavior";
\ No newline at end of file
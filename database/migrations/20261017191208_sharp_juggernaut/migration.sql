@@ -0,0 +1,4 @@
+CREATE TYPE "public"."server_schedule_overlap_behavior" AS ENUM('SKIP', 'QUEUE');
+ALTER TABLE "server_schedules" ADD COLUMN "overlap_behavior" "server_schedule_overlap_behavior" DEFAULT 'SKIP' NOT NULL;
+ALTER TABLE "server_schedules" ADD COLUMN "skipped_runs" integer DEFAULT 0 NOT NULL;
+ALTER TABLE "server_schedules" ADD COLUMN "last_skipped" timestamp;
\ No newline at end of file
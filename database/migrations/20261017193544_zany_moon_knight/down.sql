@@ -0,0 +1 @@
+ALTER TABLE "servers" DROP COLUMN "auto_start_on_reconnect";
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE "servers" ADD COLUMN "auto_start_on_reconnect" boolean DEFAULT false NOT NULL;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE "admin_approvals" DROP COLUMN "payload";
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE "admin_approvals" ADD COLUMN "payload" jsonb DEFAULT '{}'::jsonb NOT NULL;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE "servers" DROP COLUMN "allow_custom_image";
\ No newline at end of file
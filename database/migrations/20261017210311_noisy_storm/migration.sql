@@ -0,0 +1 @@
+ALTER TABLE "servers" ADD COLUMN "allow_custom_image" boolean DEFAULT false NOT NULL;
\ No newline at end of file
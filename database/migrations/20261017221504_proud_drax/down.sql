@@ -0,0 +1 @@
+DROP TABLE "nest_egg_resource_alerts";
\ No newline at end of file
@@ -0,0 +1,9 @@
+CREATE TABLE "nest_egg_resource_alerts" (
+	"egg_uuid" uuid PRIMARY KEY,
+	"cpu_threshold" smallint,
+	"memory_threshold" smallint,
+	"window_seconds" integer DEFAULT 300 NOT NULL,
+	"created" timestamp DEFAULT now() NOT NULL
+);
+
+ALTER TABLE "nest_egg_resource_alerts" ADD CONSTRAINT "nest_egg_resource_alerts_egg_uuid_nest_eggs_uuid_fkey" FOREIGN KEY ("egg_uuid") REFERENCES "nest_eggs"("uuid") ON DELETE CASCADE;
\ No newline at end of file
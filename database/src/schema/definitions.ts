@@ -34,6 +34,8 @@ export const userToastPositionEnum = new DatabaseEnum('user_toast_position', [
   'BOTTOM_CENTER',
   'BOTTOM_RIGHT',
 ]);
+export const serverScheduleOverlapBehaviorEnum = new DatabaseEnum('server_schedule_overlap_behavior', ['SKIP', 'QUEUE']);
+export const jobStatusEnum = new DatabaseEnum('job_status', ['PENDING', 'RUNNING', 'COMPLETED', 'FAILED']);
 
 export const settingsTable = new DatabaseTable('settings')
   .addColumn('key', varchar({ length: 255 }).primaryKey().notNull())
@@ -83,6 +85,7 @@ export const usersTable = new DatabaseTable('users')
   .addColumn('language', varchar({ length: 15 }).default('en').notNull())
   .addColumn('toast_position', userToastPositionEnum.intoDrizzleEnum()().default('BOTTOM_RIGHT').notNull())
   .addColumn('start_on_grouped_servers', boolean().default(false).notNull())
+  .addColumn('backup_storage_limit', bigint({ mode: 'number' }))
   .addColumn('created', timestamp().defaultNow().notNull())
   .addConfigBuilder((cols) => [
     uniqueIndex('users_external_id_idx').on(cols.external_id),
@@ -163,6 +166,49 @@ export const userPasswordResetsTable = new DatabaseTable('user_password_resets')
     uniqueIndex('user_password_resets_token_idx').on(cols.token),
   ]);
 
+export const jobsTable = new DatabaseTable('jobs')
+  .addColumn('uuid', uuid().default(sql`gen_random_uuid()`).primaryKey().notNull())
+  .addColumn(
+    'user_uuid',
+    uuid().references(() => usersTable.join().uuid, { onDelete: 'set null' }),
+  )
+  .addColumn('type', varchar({ length: 31 * UTF8_MAX_SCALAR_SIZE }).notNull())
+  .addColumn('status', jobStatusEnum.intoDrizzleEnum()().default('PENDING').notNull())
+  .addColumn('progress', smallint().default(0).notNull())
+  .addColumn('payload', jsonb().notNull())
+  .addColumn('result', jsonb())
+  .addColumn('error', text())
+  .addColumn('started', timestamp())
+  .addColumn('completed', timestamp())
+  .addColumn('created', timestamp().defaultNow().notNull())
+  .addConfigBuilder((cols) => [
+    index('jobs_user_uuid_idx').on(cols.user_uuid),
+    index('jobs_status_idx').on(cols.status),
+  ]);
+
+export const adminApprovalsTable = new DatabaseTable('admin_approvals')
+  .addColumn('uuid', uuid().default(sql`gen_random_uuid()`).primaryKey().notNull())
+  .addColumn('action', varchar({ length: 31 * UTF8_MAX_SCALAR_SIZE }).notNull())
+  .addColumn('target_uuid', uuid().notNull())
+  .addColumn('payload', jsonb().default({}).notNull())
+  .addColumn(
+    'requester_uuid',
+    uuid()
+      .references(() => usersTable.join().uuid, { onDelete: 'cascade' })
+      .notNull(),
+  )
+  .addColumn(
+    'approver_uuid',
+    uuid().references(() => usersTable.join().uuid, { onDelete: 'set null' }),
+  )
+  .addColumn('approved', timestamp())
+  .addColumn('expires', timestamp().notNull())
+  .addColumn('created', timestamp().defaultNow().notNull())
+  .addConfigBuilder((cols) => [
+    index('admin_approvals_action_target_uuid_idx').on(cols.action, cols.target_uuid),
+    index('admin_approvals_requester_uuid_idx').on(cols.requester_uuid),
+  ]);
+
 export const userSecurityKeysTable = new DatabaseTable('user_security_keys')
   .addColumn('uuid', uuid().default(sql`gen_random_uuid()`).primaryKey().notNull())
   .addColumn(
@@ -201,6 +247,20 @@ export const userSshKeysTable = new DatabaseTable('user_ssh_keys')
     uniqueIndex('user_ssh_keys_user_uuid_fingerprint_idx').on(cols.user_uuid, cols.fingerprint),
   ]);
 
+export const userNotificationPreferencesTable = new DatabaseTable('user_notification_preferences')
+  .addColumn(
+    'user_uuid',
+    uuid()
+      .references(() => usersTable.join().uuid, { onDelete: 'cascade' })
+      .primaryKey()
+      .notNull(),
+  )
+  .addColumn('backup_completed', boolean().default(true).notNull())
+  .addColumn('server_suspended', boolean().default(true).notNull())
+  .addColumn('security_alerts', boolean().default(true).notNull())
+  .addColumn('resource_alerts', boolean().default(true).notNull())
+  .addColumn('updated', timestamp().defaultNow().notNull());
+
 export const userApiKeysTable = new DatabaseTable('user_api_keys')
   .addColumn('uuid', uuid().default(sql`gen_random_uuid()`).primaryKey().notNull())
   .addColumn(
@@ -346,6 +406,7 @@ export const locationsTable = new DatabaseTable('locations')
   )
   .addColumn('name', varchar({ length: 255 * UTF8_MAX_SCALAR_SIZE }).notNull())
   .addColumn('description', text())
+  .addColumn('server_count', integer().default(0).notNull())
   .addColumn('created', timestamp().defaultNow().notNull())
   .addConfigBuilder((cols) => [
     index('locations_backup_configuration_uuid_idx').on(cols.backup_configuration_uuid),
@@ -387,6 +448,7 @@ export const nodesTable = new DatabaseTable('nodes')
   .addColumn('name', varchar({ length: 255 * UTF8_MAX_SCALAR_SIZE }).notNull())
   .addColumn('deployment_enabled', boolean().default(false).notNull())
   .addColumn('maintenance_enabled', boolean().default(false).notNull())
+  .addColumn('deployment_weight', smallint().default(100).notNull())
   .addColumn('description', text())
   .addColumn('public_url', varchar({ length: 255 * UTF8_MAX_SCALAR_SIZE }))
   .addColumn('url', varchar({ length: 255 * UTF8_MAX_SCALAR_SIZE }).notNull())
@@ -394,6 +456,7 @@ export const nodesTable = new DatabaseTable('nodes')
   .addColumn('sftp_port', integer().notNull())
   .addColumn('memory', bigint({ mode: 'number' }).notNull())
   .addColumn('disk', bigint({ mode: 'number' }).notNull())
+  .addColumn('server_count', integer().default(0).notNull())
   .addColumn('token_id', char({ length: 16 }).notNull())
   .addColumn('token', bytea().notNull())
   .addColumn('created', timestamp().defaultNow().notNull())
@@ -426,6 +489,21 @@ export const nodeMountsTable = new DatabaseTable('node_mounts')
     index('node_mounts_mount_uuid_idx').on(cols.mount_uuid),
   ]);
 
+export const nodeTagsTable = new DatabaseTable('node_tags')
+  .addColumn(
+    'node_uuid',
+    uuid()
+      .references(() => nodesTable.join().uuid, { onDelete: 'cascade' })
+      .notNull(),
+  )
+  .addColumn('name', varchar({ length: 31 * UTF8_MAX_SCALAR_SIZE }).notNull())
+  .addColumn('created', timestamp().defaultNow().notNull())
+  .addConfigBuilder((cols) => [
+    primaryKey({ name: 'node_tags_pk', columns: [cols.node_uuid, cols.name] }),
+    index('node_tags_node_uuid_idx').on(cols.node_uuid),
+    index('node_tags_name_idx').on(cols.name),
+  ]);
+
 export const nodeAllocationsTable = new DatabaseTable('node_allocations')
   .addColumn('uuid', uuid().default(sql`gen_random_uuid()`).primaryKey().notNull())
   .addColumn(
@@ -550,6 +628,7 @@ export const nestEggVariablesTable = new DatabaseTable('nest_egg_variables')
   .addColumn('user_viewable', boolean().default(true).notNull())
   .addColumn('user_editable', boolean().default(false).notNull())
   .addColumn('secret', boolean().default(false).notNull())
+  .addColumn('resolve_references', boolean().default(false).notNull())
   .addColumn('rules', text().array().notNull())
   .addColumn('created', timestamp().defaultNow().notNull())
   .addConfigBuilder((cols) => [
@@ -627,15 +706,19 @@ export const serversTable = new DatabaseTable('servers')
   .addColumn('pinned_cpus', smallint().array().notNull())
   .addColumn('startup', text().notNull())
   .addColumn('image', varchar({ length: 255 }).notNull())
+  .addColumn('allow_custom_image', boolean().default(false).notNull())
   .addColumn('auto_kill', jsonb().default({ enabled: false, seconds: 30 }).notNull())
   .addColumn('auto_start_behavior', serverAutoStartBehaviorEnum.intoDrizzleEnum()().default('UNLESS_STOPPED').notNull())
+  .addColumn('auto_start_on_reconnect', boolean().default(false).notNull())
   .addColumn('timezone', varchar({ length: 255 }))
   .addColumn('hugepages_passthrough_enabled', boolean().default(false).notNull())
   .addColumn('kvm_passthrough_enabled', boolean().default(false).notNull())
   .addColumn('allocation_limit', integer().default(0).notNull())
   .addColumn('database_limit', integer().default(0).notNull())
   .addColumn('backup_limit', integer().default(0).notNull())
+  .addColumn('backup_size_limit', bigint({ mode: 'number' }))
   .addColumn('schedule_limit', integer().default(0).notNull())
+  .addColumn('backup_count', integer().default(0).notNull())
   .addColumn('created', timestamp().defaultNow().notNull())
   .addConfigBuilder((cols) => [
     index('servers_external_id_idx').on(cols.external_id),
@@ -644,6 +727,7 @@ export const serversTable = new DatabaseTable('servers')
     index('servers_owner_uuid_idx').on(cols.owner_uuid),
     index('servers_egg_uuid_idx').on(cols.egg_uuid),
     index('servers_backup_configuration_uuid_idx').on(cols.backup_configuration_uuid),
+    index('servers_name_idx').on(cols.name),
     uniqueIndex('servers_uuid_short_idx').on(cols.uuid_short),
   ]);
 
@@ -725,6 +809,57 @@ export const serverActivitiesTable = new DatabaseTable('server_activities')
     index('server_activities_user_uuid_event_idx').on(cols.user_uuid, cols.event),
   ]);
 
+export const serverResourceAlertsTable = new DatabaseTable('server_resource_alerts')
+  .addColumn(
+    'server_uuid',
+    uuid()
+      .references(() => serversTable.join().uuid, { onDelete: 'cascade' })
+      .primaryKey()
+      .notNull(),
+  )
+  .addColumn('cpu_threshold', smallint())
+  .addColumn('memory_threshold', smallint())
+  .addColumn('window_seconds', integer().default(300).notNull())
+  .addColumn('created', timestamp().defaultNow().notNull());
+
+export const nestEggResourceAlertsTable = new DatabaseTable('nest_egg_resource_alerts')
+  .addColumn(
+    'egg_uuid',
+    uuid()
+      .references(() => nestEggsTable.join().uuid, { onDelete: 'cascade' })
+      .primaryKey()
+      .notNull(),
+  )
+  .addColumn('cpu_threshold', smallint())
+  .addColumn('memory_threshold', smallint())
+  .addColumn('window_seconds', integer().default(300).notNull())
+  .addColumn('created', timestamp().defaultNow().notNull());
+
+export const serverReinstallHistoryTable = new DatabaseTable('server_reinstall_history')
+  .addColumn('uuid', uuid().default(sql`gen_random_uuid()`).primaryKey().notNull())
+  .addColumn(
+    'server_uuid',
+    uuid()
+      .references(() => serversTable.join().uuid, { onDelete: 'cascade' })
+      .notNull(),
+  )
+  .addColumn(
+    'old_egg_uuid',
+    uuid().references(() => nestEggsTable.join().uuid, { onDelete: 'set null' }),
+  )
+  .addColumn(
+    'new_egg_uuid',
+    uuid().references(() => nestEggsTable.join().uuid, { onDelete: 'set null' }),
+  )
+  .addColumn('user_uuid', uuid().references(() => usersTable.join().uuid, { onDelete: 'set null' }))
+  .addColumn('truncate_directory', boolean().notNull())
+  .addColumn('successful', boolean())
+  .addColumn('completed', timestamp())
+  .addColumn('created', timestamp().defaultNow().notNull())
+  .addConfigBuilder((cols) => [
+    index('server_reinstall_history_server_uuid_created_idx').on(cols.server_uuid, cols.created),
+  ]);
+
 export const serverVariablesTable = new DatabaseTable('server_variables')
   .addColumn(
     'server_uuid',
@@ -842,8 +977,11 @@ export const serverSchedulesTable = new DatabaseTable('server_schedules')
   .addColumn('enabled', boolean().notNull())
   .addColumn('triggers', jsonb().notNull())
   .addColumn('condition', jsonb().notNull())
+  .addColumn('overlap_behavior', serverScheduleOverlapBehaviorEnum.intoDrizzleEnum()().default('SKIP').notNull())
   .addColumn('last_run', timestamp())
   .addColumn('last_failure', timestamp())
+  .addColumn('skipped_runs', integer().default(0).notNull())
+  .addColumn('last_skipped', timestamp())
   .addColumn('created', timestamp().defaultNow().notNull())
   .addConfigBuilder((cols) => [
     index('server_schedules_server_uuid_idx').on(cols.server_uuid),
@@ -0,0 +1,20 @@
+import { axiosInstance } from '@/api/axios.ts';
+
+interface Response {
+  usedBytes: number;
+  limitBytes: number | null;
+}
+
+export default async (): Promise<Response> => {
+  return new Promise((resolve, reject) => {
+    axiosInstance
+      .get('/api/client/account/backup-storage')
+      .then(({ data }) =>
+        resolve({
+          usedBytes: data.used_bytes,
+          limitBytes: data.limit_bytes,
+        }),
+      )
+      .catch(reject);
+  });
+};
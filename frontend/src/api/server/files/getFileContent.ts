@@ -1,13 +1,13 @@
 import { axiosInstance } from '@/api/axios.ts';
 
-export default async (uuid: string, path: string): Promise<Blob> => {
+export default async (uuid: string, path: string): Promise<{ content: Blob; fingerprint: string | null }> => {
   return new Promise((resolve, reject) => {
     axiosInstance
       .get(`/api/client/servers/${uuid}/files/contents`, {
         params: { file: path },
         responseType: 'blob',
       })
-      .then(({ data }) => resolve(data))
+      .then(({ data, headers }) => resolve({ content: data, fingerprint: headers.etag ?? null }))
       .catch(reject);
   });
 };
@@ -3,8 +3,8 @@ import { axiosInstance } from '@/api/axios.ts';
 export default async (uuid: string, directory: string): Promise<{ url: string }> => {
   return new Promise((resolve, reject) => {
     axiosInstance
-      .get(`/api/client/servers/${uuid}/files/upload`)
-      .then(({ data }) => resolve({ url: data.url + `&directory=${encodeURIComponent(directory)}` }))
+      .get(`/api/client/servers/${uuid}/files/upload`, { params: { directory } })
+      .then(({ data }) => resolve({ url: data.url }))
       .catch(reject);
   });
 };
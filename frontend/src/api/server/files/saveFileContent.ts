@@ -1,15 +1,21 @@
 import { axiosInstance } from '@/api/axios.ts';
 
-export default async (uuid: string, file: string, content: string): Promise<void> => {
+export default async (
+  uuid: string,
+  file: string,
+  content: string,
+  fingerprint?: string | null,
+): Promise<string | null> => {
   return new Promise((resolve, reject) => {
     axiosInstance
       .post(`/api/client/servers/${uuid}/files/write`, content, {
         params: { file },
         headers: {
           'Content-Type': 'text/plain',
+          ...(fingerprint ? { 'If-Match': fingerprint } : {}),
         },
       })
-      .then(() => resolve())
+      .then(({ headers }) => resolve(headers.etag ?? null))
       .catch(reject);
   });
 };
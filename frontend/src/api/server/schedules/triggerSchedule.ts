@@ -1,10 +1,16 @@
+import { z } from 'zod';
 import { axiosInstance } from '@/api/axios.ts';
+import { serverScheduleTriggerResult } from '@/lib/schemas/server/schedules.ts';
 
-export default async (serverUuid: string, scheduleUuid: string, skipCondition: boolean): Promise<void> => {
+export default async (
+  serverUuid: string,
+  scheduleUuid: string,
+  skipCondition: boolean,
+): Promise<z.infer<typeof serverScheduleTriggerResult>> => {
   return new Promise((resolve, reject) => {
     axiosInstance
       .post(`/api/client/servers/${serverUuid}/schedules/${scheduleUuid}/trigger`, { skip_condition: skipCondition })
-      .then(() => resolve())
+      .then(({ data }) => resolve(data.result))
       .catch(reject);
   });
 };
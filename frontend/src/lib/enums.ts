@@ -51,6 +51,7 @@ import { archiveFormat, compressionLevel, fingerprintAlgorithm } from '@/lib/sch
 import {
   serverScheduleComparator,
   serverScheduleConditionSchema,
+  serverScheduleOverlapBehavior,
   serverSchedulePreConditionSchema,
   serverScheduleStepActionSchema,
   serverScheduleTriggerSchema,
@@ -166,6 +167,11 @@ export const schedulePreConditionLabelMapping: Record<
   file_exists: 'File Exists',
 };
 
+export const scheduleOverlapBehaviorLabelMapping: Record<z.infer<typeof serverScheduleOverlapBehavior>, string> = {
+  skip: 'Skip the new run',
+  queue: 'Queue the new run',
+};
+
 export const scheduleConditionLabelMapping: Record<z.infer<typeof serverScheduleConditionSchema>['type'], string> = {
   none: 'None',
   and: 'AND (All must be true)',
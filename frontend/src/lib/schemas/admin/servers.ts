@@ -23,6 +23,7 @@ export const adminServerFeatureLimitsSchema = z.object({
   allocations: z.number().min(0),
   databases: z.number().min(0),
   backups: z.number().min(0),
+  backupSize: z.preprocess(nullableNumber, z.number().min(1).nullable()),
   schedules: z.number().min(0),
 });
 
@@ -46,11 +47,13 @@ export const adminServerSchema = z.object({
   featureLimits: z.lazy(() => adminServerFeatureLimitsSchema),
   startup: z.string().min(1).max(8192),
   image: z.string().min(2).max(255),
+  allowCustomImage: z.boolean(),
   autoKill: z.object({
     enabled: z.boolean(),
     seconds: z.number(),
   }),
   autoStartBehavior: z.lazy(() => serverAutostartBehavior),
+  autoStartOnReconnect: z.boolean(),
   timezone: z.preprocess(nullableString, z.string().nullable()),
   hugepagesPassthroughEnabled: z.boolean(),
   kvmPassthroughEnabled: z.boolean(),
@@ -69,8 +72,10 @@ const adminServerBaseOmit = adminServerSchema.omit({
   status: true,
   isSuspended: true,
   isTransferring: true,
+  allowCustomImage: true,
   autoKill: true,
   autoStartBehavior: true,
+  autoStartOnReconnect: true,
   created: true,
 });
 
@@ -92,6 +97,7 @@ export const adminServerCreateSchema = adminServerBaseOmit.extend({
 });
 
 export const adminServerUpdateSchema = adminServerBaseOmit.extend({
+  allowCustomImage: z.boolean(),
   ownerUuid: z.uuid(),
   eggUuid: z.uuid(),
   backupConfigurationUuid: z.uuid().nullable(),
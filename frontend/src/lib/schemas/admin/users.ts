@@ -1,7 +1,7 @@
 import { z } from 'zod';
 import { oAuthProviderSchema } from '@/lib/schemas/generic.ts';
 import { roleSchema, userToastPosition } from '@/lib/schemas/user.ts';
-import { nullableString } from '@/lib/transformers.ts';
+import { nullableNumber, nullableString } from '@/lib/transformers.ts';
 
 export const adminUserSchema = z.object({
   uuid: z.string(),
@@ -23,6 +23,7 @@ export const adminFullUserSchema = adminUserSchema.extend({
   requireTwoFactor: z.boolean(),
   toastPosition: z.lazy(() => userToastPosition),
   startOnGroupedServers: z.boolean(),
+  backupStorageLimit: z.number().nullable(),
   hasPassword: z.boolean(),
 });
 
@@ -34,6 +35,7 @@ export const adminUserUpdateSchema = adminUserSchema
   })
   .extend({
     roleUuid: z.string().nullable(),
+    backupStorageLimit: z.preprocess(nullableNumber, z.number().min(0).nullable()),
   });
 
 export const adminUserOAuthLinkSchema = z.object({
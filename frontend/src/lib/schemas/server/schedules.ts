@@ -205,14 +205,21 @@ export const serverScheduleConditionSchema: ZodType<ServerScheduleCondition> = z
   ]),
 );
 
+export const serverScheduleOverlapBehavior = z.enum(['skip', 'queue']);
+
+export const serverScheduleTriggerResult = z.enum(['triggered', 'skipped', 'queued']);
+
 export const serverScheduleSchema = z.object({
   uuid: z.string(),
   name: z.string().min(1).max(255),
   enabled: z.boolean(),
   triggers: z.array(serverScheduleTriggerSchema),
   condition: serverSchedulePreConditionSchema,
+  overlapBehavior: serverScheduleOverlapBehavior,
   lastRun: z.date().nullable(),
   lastFailure: z.date().nullable(),
+  skippedRuns: z.number(),
+  lastSkipped: z.date().nullable(),
   created: z.date(),
 });
 
@@ -220,6 +227,8 @@ export const serverScheduleUpdateSchema = serverScheduleSchema.omit({
   uuid: true,
   lastRun: true,
   lastFailure: true,
+  skippedRuns: true,
+  lastSkipped: true,
   created: true,
 });
 
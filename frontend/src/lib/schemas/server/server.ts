@@ -29,6 +29,7 @@ export const serverSchema = z.object({
   allocation: z.lazy(() => serverAllocationSchema).nullable(),
   egg: z.lazy(() => serverEggSchema),
   status: serverStatus.nullable(),
+  powerState: z.lazy(() => serverPowerState).nullable(),
   isSuspended: z.boolean(),
   isOwner: z.boolean(),
   isTransferring: z.boolean(),
@@ -42,6 +43,7 @@ export const serverSchema = z.object({
   sftpPort: z.number().int().min(1).max(65535),
   name: z.string(),
   description: z.string().nullable(),
+  descriptionHtml: z.string().nullable(),
   limits: z.lazy(() => adminServerLimitsSchema),
   featureLimits: z.lazy(() => adminServerFeatureLimitsSchema),
   startup: z.string(),
@@ -51,6 +53,7 @@ export const serverSchema = z.object({
     seconds: z.number().int().nonnegative(),
   }),
   autoStartBehavior: z.lazy(() => serverAutostartBehavior),
+  autoStartOnReconnect: z.boolean(),
   timezone: z.string().nullable(),
   created: z.coerce.date(),
 });
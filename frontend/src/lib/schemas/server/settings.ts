@@ -11,6 +11,7 @@ export const serverSettingsAutokillSchema = z.object({
 
 export const serverSettingsAutostartSchema = z.object({
   behavior: z.enum(['always', 'unless_stopped', 'never']),
+  onReconnect: z.boolean(),
 });
 
 export const serverSettingsRenameSchema = z.object({
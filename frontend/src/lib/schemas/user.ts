@@ -39,6 +39,7 @@ export const fullUserSchema = userSchema.extend({
   requireTwoFactor: z.boolean(),
   toastPosition: userToastPosition,
   startOnGroupedServers: z.boolean(),
+  backupStorageLimit: z.number().nullable(),
   hasPassword: z.boolean(),
   admin: z.boolean(),
   language: z.string(),
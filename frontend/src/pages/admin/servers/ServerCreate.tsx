@@ -97,6 +97,7 @@ export default function ServerCreate() {
         allocations: 5,
         databases: 5,
         backups: 5,
+        backupSize: null,
         schedules: 5,
       },
       nodeUuid: '',
@@ -526,6 +527,14 @@ export default function ServerCreate() {
                     key={form.key('featureLimits.backups')}
                     {...form.getInputProps('featureLimits.backups')}
                   />
+                  <NumberInput
+                    label='Backup Size (MiB)'
+                    description='The maximum size of a single backup, leave empty for no limit. Checked once the backup is created, oversized backups are discarded'
+                    placeholder='Unlimited'
+                    min={1}
+                    key={form.key('featureLimits.backupSize')}
+                    {...form.getInputProps('featureLimits.backupSize')}
+                  />
                   <NumberInput
                     withAsterisk
                     label='Schedules'
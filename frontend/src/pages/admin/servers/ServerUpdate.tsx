@@ -76,6 +76,7 @@ export default function ServerUpdate({ contextServer }: { contextServer: z.infer
       pinnedCpus: [],
       startup: '',
       image: '',
+      allowCustomImage: false,
       timezone: Intl.DateTimeFormat().resolvedOptions().timeZone,
       hugepagesPassthroughEnabled: false,
       kvmPassthroughEnabled: false,
@@ -83,6 +84,7 @@ export default function ServerUpdate({ contextServer }: { contextServer: z.infer
         allocations: 5,
         databases: 5,
         backups: 5,
+        backupSize: null,
         schedules: 5,
       },
     },
@@ -114,6 +116,7 @@ export default function ServerUpdate({ contextServer }: { contextServer: z.infer
         pinnedCpus: contextServer.pinnedCpus,
         startup: contextServer.startup,
         image: contextServer.image,
+        allowCustomImage: contextServer.allowCustomImage,
         timezone: contextServer.timezone,
         hugepagesPassthroughEnabled: contextServer.hugepagesPassthroughEnabled,
         kvmPassthroughEnabled: contextServer.kvmPassthroughEnabled,
@@ -368,6 +371,15 @@ export default function ServerUpdate({ contextServer }: { contextServer: z.infer
                   />
                 </Group>
 
+                <Switch
+                  label='Allow Custom Docker Image'
+                  description='Allow setting a docker image that is not one of the egg docker images'
+                  key={form.key('allowCustomImage')}
+                  {...form.getInputProps('allowCustomImage', {
+                    type: 'checkbox',
+                  })}
+                />
+
                 <Select
                   withAsterisk
                   label='Timezone'
@@ -457,6 +469,14 @@ export default function ServerUpdate({ contextServer }: { contextServer: z.infer
                     key={form.key('featureLimits.backups')}
                     {...form.getInputProps('featureLimits.backups')}
                   />
+                  <NumberInput
+                    label='Backup Size (MiB)'
+                    description='The maximum size of a single backup, leave empty for no limit. Checked once the backup is created, oversized backups are discarded'
+                    placeholder='Unlimited'
+                    min={1}
+                    key={form.key('featureLimits.backupSize')}
+                    {...form.getInputProps('featureLimits.backupSize')}
+                  />
                   <NumberInput
                     withAsterisk
                     label='Schedules'
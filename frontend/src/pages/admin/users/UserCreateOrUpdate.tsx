@@ -12,6 +12,7 @@ import Button from '@/elements/Button.tsx';
 import { AdminCan } from '@/elements/Can.tsx';
 import Code from '@/elements/Code.tsx';
 import AdminContentContainer from '@/elements/containers/AdminContentContainer.tsx';
+import NumberInput from '@/elements/input/NumberInput.tsx';
 import Select from '@/elements/input/Select.tsx';
 import Switch from '@/elements/input/Switch.tsx';
 import TextInput from '@/elements/input/TextInput.tsx';
@@ -44,6 +45,7 @@ export default function UserCreateOrUpdate({ contextUser }: { contextUser?: z.in
       admin: false,
       language: settings.app.language,
       roleUuid: null,
+      backupStorageLimit: null,
     },
   });
 
@@ -73,6 +75,7 @@ export default function UserCreateOrUpdate({ contextUser }: { contextUser?: z.in
         admin: contextUser.admin,
         language: contextUser.language,
         roleUuid: contextUser.role?.uuid ?? null,
+        backupStorageLimit: contextUser.backupStorageLimit,
       });
     }
   }, [contextUser]);
@@ -203,6 +206,15 @@ export default function UserCreateOrUpdate({ contextUser }: { contextUser?: z.in
             {...form.getInputProps('password')}
           />
 
+          <NumberInput
+            label='Backup Storage Limit (MiB)'
+            description='The maximum combined size of backups across all servers owned by this user, leave empty for no limit'
+            placeholder='Unlimited'
+            min={0}
+            key={form.key('backupStorageLimit')}
+            {...form.getInputProps('backupStorageLimit')}
+          />
+
           <Switch label='Admin' key={form.key('admin')} {...form.getInputProps('admin', { type: 'checkbox' })} />
         </Stack>
 
@@ -1,6 +1,9 @@
 import { faServer } from '@fortawesome/free-solid-svg-icons';
-import { Title } from '@mantine/core';
+import { Group, Text, Title } from '@mantine/core';
+import { useQuery } from '@tanstack/react-query';
+import getBackupStorage from '@/api/me/account/getBackupStorage.ts';
 import SubNavigation from '@/elements/SubNavigation.tsx';
+import { bytesToString } from '@/lib/size.ts';
 import { useAuth } from '@/providers/AuthProvider.tsx';
 import { useTranslations } from '@/providers/TranslationProvider.tsx';
 
@@ -8,11 +11,28 @@ export default function DashboardHomeTitle() {
   const { t } = useTranslations();
   const { user } = useAuth();
 
+  const { data: backupStorage } = useQuery({
+    queryKey: ['account', 'backup-storage'],
+    queryFn: getBackupStorage,
+    enabled: user?.backupStorageLimit != null,
+  });
+
   return (
     <>
-      <Title order={1} c='white' mb='md'>
-        {t('pages.account.home.title', {})}
-      </Title>
+      <Group justify='space-between' align='center' mb='md'>
+        <Title order={1} c='white'>
+          {t('pages.account.home.title', {})}
+        </Title>
+
+        {backupStorage?.limitBytes != null && (
+          <Text size='sm' c='gray.5'>
+            {t('pages.account.home.backupStorage', {
+              used: bytesToString(backupStorage.usedBytes),
+              limit: bytesToString(backupStorage.limitBytes),
+            })}
+          </Text>
+        )}
+      </Group>
 
       <SubNavigation
         baseUrl='/'
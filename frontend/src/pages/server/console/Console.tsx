@@ -25,6 +25,7 @@ import Progress from '@/elements/Progress.tsx';
 import Spinner from '@/elements/Spinner.tsx';
 import Tooltip from '@/elements/Tooltip.tsx';
 import { useKeyboardShortcut } from '@/plugins/useKeyboardShortcuts.ts';
+import { useServerCan } from '@/plugins/usePermissions.ts';
 import { SocketEvent, SocketRequest } from '@/plugins/useWebsocketEvent.ts';
 import { useTranslations } from '@/providers/TranslationProvider.tsx';
 import { useServerStore } from '@/stores/server.ts';
@@ -53,6 +54,7 @@ const commandSnippetFilter: OptionsFilter = ({ options, search }) => {
 export default function Terminal() {
   const { t } = useTranslations();
   const { server, commandSnippets, imagePulls, socketConnected, socketInstance, state } = useServerStore();
+  const canSendCommands = useServerCan('control.console');
 
   const [history, setHistory] = useState<string[]>([]);
   const [historyIndex, setHistoryIndex] = useState(-1);
@@ -564,7 +566,7 @@ export default function Terminal() {
             }}
             placeholder={t('pages.server.console.input.placeholder', {})}
             aria-label={t('pages.server.console.input.ariaLabel', {})}
-            disabled={!socketConnected || state === 'offline'}
+            disabled={!socketConnected || state === 'offline' || !canSendCommands}
             onKeyDown={handleKeyDown}
             autoCorrect='off'
             autoCapitalize='none'
@@ -45,7 +45,11 @@ export default function ServerConsole() {
           <Title order={1} c='white'>
             {server.name}
           </Title>
-          <p className='text-sm text-gray-300!'>{server.description}</p>
+          {server.descriptionHtml ? (
+            <div className='text-sm text-gray-300!' dangerouslySetInnerHTML={{ __html: server.descriptionHtml }} />
+          ) : (
+            <p className='text-sm text-gray-300!'>{server.description}</p>
+          )}
         </div>
         <ServerCan action={['control.start', 'control.stop', 'control.restart']} matchAny>
           <ServerPowerControls />
@@ -30,7 +30,7 @@ export default function EulaModal() {
     try {
       let content: string;
       try {
-        content = await getFileContent(server.uuid, '/eula.txt').then((c) => c.text());
+        content = await getFileContent(server.uuid, '/eula.txt').then(({ content }) => content.text());
       } catch {
         content = 'eula=false';
       }
@@ -4,6 +4,7 @@ import { join } from 'pathe';
 import { startTransition, useEffect, useRef, useState } from 'react';
 import { createSearchParams, useNavigate, useParams, useSearchParams } from 'react-router';
 import { TransformComponent, TransformWrapper } from 'react-zoom-pan-pinch';
+import { httpErrorToHuman } from '@/api/axios.ts';
 import getFileContent from '@/api/server/files/getFileContent.ts';
 import saveFileContent from '@/api/server/files/saveFileContent.ts';
 import Button from '@/elements/Button.tsx';
@@ -50,6 +51,7 @@ function FileEditorComponent() {
 
   const editorRef = useRef<Parameters<OnMount>[0]>(null);
   const contentRef = useRef(content);
+  const fingerprintRef = useRef<string | null>(null);
 
   const blocker = useBlocker(dirty);
 
@@ -64,7 +66,11 @@ function FileEditorComponent() {
 
     setLoading(true);
     getFileContent(server.uuid, join(browsingDirectory, fileName))
-      .then((content) => (params.action === 'image' ? URL.createObjectURL(content) : content.text()))
+      .then(({ content, fingerprint }) => {
+        fingerprintRef.current = fingerprint;
+
+        return params.action === 'image' ? URL.createObjectURL(content) : content.text();
+      })
       .then((content) => {
         startTransition(() => {
           setContent(content);
@@ -85,23 +91,36 @@ function FileEditorComponent() {
     const currentContent = editorRef.current.getValue();
     setSaving(true);
 
-    saveFileContent(server.uuid, join(browsingDirectory, name ?? fileName), currentContent).then(() => {
-      startTransition(() => {
+    saveFileContent(
+      server.uuid,
+      join(browsingDirectory, name ?? fileName),
+      currentContent,
+      name ? null : fingerprintRef.current,
+    )
+      .then((fingerprint) => {
+        fingerprintRef.current = fingerprint;
+
+        startTransition(() => {
+          setSaving(false);
+          setNameModalOpen(false);
+        });
+
+        addToast(t('pages.server.files.toast.fileSaved', {}), 'success');
+
+        if (name) {
+          navigate(
+            `/server/${server.uuidShort}/files/edit?${createSearchParams({
+              directory: browsingDirectory,
+              file: name,
+            })}`,
+          );
+        }
+      })
+      .catch((msg) => {
         setSaving(false);
-        setNameModalOpen(false);
+        setDirty(true);
+        addToast(httpErrorToHuman(msg), 'error');
       });
-
-      addToast(t('pages.server.files.toast.fileSaved', {}), 'success');
-
-      if (name) {
-        navigate(
-          `/server/${server.uuidShort}/files/edit?${createSearchParams({
-            directory: browsingDirectory,
-            file: name,
-          })}`,
-        );
-      }
-    });
   };
 
   if (!['new', 'edit', 'image'].includes(params.action!)) {
@@ -2,6 +2,7 @@ import {
   faChevronDown,
   faClockRotateLeft,
   faExclamationTriangle,
+  faForward,
   faPencil,
   faPlay,
   faPlayCircle,
@@ -60,8 +61,18 @@ export default function ScheduleView() {
       setLoading(true);
 
       triggerSchedule(server.uuid, params.id, skipCondition)
-        .then(() => {
-          addToast('Schedule triggered.', 'success');
+        .then((result) => {
+          switch (result) {
+            case 'triggered':
+              addToast('Schedule triggered.', 'success');
+              break;
+            case 'queued':
+              addToast('Schedule is still running, the run has been queued.', 'success');
+              break;
+            case 'skipped':
+              addToast('Schedule is still running, the run has been skipped.', 'warning');
+              break;
+          }
         })
         .finally(() => setLoading(false));
     }
@@ -187,6 +198,20 @@ export default function ScheduleView() {
             }
             color={schedule.lastFailure ? 'red' : 'green'}
           />
+          <DetailCard
+            icon={<FontAwesomeIcon icon={faForward} />}
+            label='Skipped Runs'
+            value={
+              schedule.lastSkipped ? (
+                <>
+                  {schedule.skippedRuns} (<FormattedTimestamp timestamp={schedule.lastSkipped} />)
+                </>
+              ) : (
+                schedule.skippedRuns
+              )
+            }
+            color={schedule.skippedRuns > 0 ? 'yellow' : 'green'}
+          />
         </div>
 
         <Tabs defaultValue='actions'>
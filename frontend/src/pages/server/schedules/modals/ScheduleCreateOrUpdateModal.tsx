@@ -14,7 +14,11 @@ import Select from '@/elements/input/Select.tsx';
 import Switch from '@/elements/input/Switch.tsx';
 import TextInput from '@/elements/input/TextInput.tsx';
 import { Modal, ModalFooter } from '@/elements/modals/Modal.tsx';
-import { scheduleTriggerDefaultMapping, scheduleTriggerLabelMapping } from '@/lib/enums.ts';
+import {
+  scheduleOverlapBehaviorLabelMapping,
+  scheduleTriggerDefaultMapping,
+  scheduleTriggerLabelMapping,
+} from '@/lib/enums.ts';
 import { serverScheduleSchema, serverScheduleUpdateSchema } from '@/lib/schemas/server/schedules.ts';
 import { useToast } from '@/providers/ToastProvider.tsx';
 import { useServerStore } from '@/stores/server.ts';
@@ -44,6 +48,7 @@ export default function ScheduleCreateOrUpdateModal({ propSchedule, onScheduleUp
       condition: {
         type: 'none',
       },
+      overlapBehavior: 'skip',
     },
     validateInputOnBlur: true,
     validate: zod4Resolver(serverScheduleUpdateSchema),
@@ -55,6 +60,7 @@ export default function ScheduleCreateOrUpdateModal({ propSchedule, onScheduleUp
         name: propSchedule.name,
         enabled: propSchedule.enabled,
         triggers: propSchedule.triggers,
+        overlapBehavior: propSchedule.overlapBehavior,
       });
     }
   }, [propSchedule]);
@@ -103,6 +109,16 @@ export default function ScheduleCreateOrUpdateModal({ propSchedule, onScheduleUp
 
         <Switch label='Enabled' name='enabled' {...form.getInputProps('enabled', { type: 'checkbox' })} />
 
+        <Select
+          label='When Already Running'
+          description='What happens when the schedule is triggered from the panel while a previous run is still executing, runs started by the node itself are not affected'
+          data={Object.entries(scheduleOverlapBehaviorLabelMapping).map(([value, label]) => ({
+            value,
+            label,
+          }))}
+          {...form.getInputProps('overlapBehavior')}
+        />
+
         <div>
           <Title order={4} mb='sm'>
             Triggers
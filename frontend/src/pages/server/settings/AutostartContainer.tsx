@@ -9,6 +9,7 @@ import { httpErrorToHuman } from '@/api/axios.ts';
 import updateAutostart from '@/api/server/settings/updateAutostart.ts';
 import Button from '@/elements/Button.tsx';
 import Select from '@/elements/input/Select.tsx';
+import Switch from '@/elements/input/Switch.tsx';
 import TitleCard from '@/elements/TitleCard.tsx';
 import { serverSettingsAutostartSchema } from '@/lib/schemas/server/settings.ts';
 import { useToast } from '@/providers/ToastProvider.tsx';
@@ -25,6 +26,7 @@ export default function AutostartContainer() {
   const form = useForm<z.infer<typeof serverSettingsAutostartSchema>>({
     initialValues: {
       behavior: server.autoStartBehavior,
+      onReconnect: server.autoStartOnReconnect,
     },
     validateInputOnBlur: true,
     validate: zod4Resolver(serverSettingsAutostartSchema),
@@ -71,6 +73,11 @@ export default function AutostartContainer() {
             {...form.getInputProps('behavior')}
           />
 
+          <Switch
+            label={t('pages.server.settings.autostart.form.onReconnect', {})}
+            {...form.getInputProps('onReconnect', { type: 'checkbox' })}
+          />
+
           <Group mt='auto'>
             <Button type='submit' loading={loading} disabled={!form.isValid()}>
               {t('common.button.save', {})}
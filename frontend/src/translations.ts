@@ -372,6 +372,7 @@ const baseTranslations = defineTranslations({
       account: {
         home: {
           title: 'Servers',
+          backupStorage: 'Backup storage: {used} of {limit} used',
           tooltip: {
             removeFromGroup: 'Remove from Group',
             addToGroup: 'Add to Group',
@@ -1390,6 +1391,7 @@ const baseTranslations = defineTranslations({
             title: 'Auto-Start',
             form: {
               behavior: 'Behavior',
+              onReconnect: 'Start the server again when its node comes back online after an outage',
             },
             toast: {
               updated: 'Server auto-start behavior updated.',
@@ -1,7 +1,6 @@
 use crate::{env::RedisMode, response::ApiResponse};
 use axum::http::StatusCode;
 use colored::Colorize;
-use compact_str::ToCompactString;
 use rustis::{
     client::Client,
     commands::{
@@ -46,6 +45,7 @@ impl moka::Expiry<compact_str::CompactString, DataEntry> for DataExpiry {
 pub struct Cache {
     pub client: Arc<Client>,
     use_internal_cache: bool,
+    key_prefix: compact_str::CompactString,
     local: moka::future::Cache<compact_str::CompactString, DataEntry>,
     local_task: tokio::task::JoinHandle<()>,
     local_locks: moka::future::Cache<compact_str::CompactString, LockEntry>,
@@ -109,6 +109,7 @@ impl Cache {
         let instance = Arc::new(Self {
             client,
             use_internal_cache: env.app_use_internal_cache,
+            key_prefix: env.app_cache_key_prefix.as_str().into(),
             local,
             local_task,
             local_locks,
@@ -139,6 +140,16 @@ impl Cache {
         instance
     }
 
+    /// Returns the key as stored in redis, prefixed with `APP_CACHE_KEY_PREFIX` if configured.
+    /// Has to be used for any key accessed through `client` directly.
+    pub fn key(&self, key: impl AsRef<str>) -> compact_str::CompactString {
+        if self.key_prefix.is_empty() {
+            key.as_ref().into()
+        } else {
+            compact_str::format_compact!("{}::{}", self.key_prefix, key.as_ref())
+        }
+    }
+
     pub async fn version(&self) -> Result<compact_str::CompactString, rustis::Error> {
         let version: String = self.client.info([InfoSection::Server]).await?;
         let version = version
@@ -160,11 +171,11 @@ impl Cache {
         limit_window: u64,
         client: impl AsRef<str>,
     ) -> Result<(), ApiResponse> {
-        let key = compact_str::format_compact!(
+        let key = self.key(compact_str::format_compact!(
             "ratelimit::{}::{}",
             limit_identifier.as_ref(),
             client.as_ref()
-        );
+        ));
 
         let now = chrono::Utc::now().timestamp();
         let expiry = self.client.expiretime(&key).await.unwrap_or_default();
@@ -198,7 +209,7 @@ impl Cache {
         timeout: Option<u64>,
     ) -> Result<CacheLock, anyhow::Error> {
         let lock_id = lock_id.into();
-        let redis_key = compact_str::format_compact!("lock::{}", lock_id);
+        let redis_key = self.key(compact_str::format_compact!("lock::{}", lock_id));
         let ttl_secs = ttl.unwrap_or(30);
         let deadline = timeout.map(|ms| Instant::now() + Duration::from_secs(ms));
 
@@ -236,7 +247,13 @@ impl Cache {
         {
             true => {
                 tracing::debug!("acquired cache lock");
-                Ok(CacheLock::new(lock_id, self.client.clone(), permit, ttl))
+                Ok(CacheLock::new(
+                    lock_id,
+                    redis_key,
+                    self.client.clone(),
+                    permit,
+                    ttl,
+                ))
             }
             false => anyhow::bail!("timed out acquiring redis lock `{}`", lock_id),
         }
@@ -288,6 +305,7 @@ impl Cache {
         ttl: u64,
         fn_compute: F,
     ) -> Result<T, anyhow::Error> {
+        let key = self.key(key);
         let effective_moka_ttl = if self.use_internal_cache {
             Duration::from_secs(ttl)
         } else {
@@ -301,10 +319,10 @@ impl Cache {
 
         let entry = self
             .local
-            .try_get_with(key.to_compact_string(), async move {
+            .try_get_with(key.clone(), async move {
                 tracing::debug!("checking redis cache");
                 let cached_value: Option<BulkString> = client
-                    .get(key)
+                    .get(key.as_str())
                     .await
                     .map_err(|err| {
                         tracing::error!("redis get error: {:?}", err);
@@ -331,7 +349,12 @@ impl Cache {
                 let serialized_arc = Arc::new(serialized);
 
                 let _ = client
-                    .set_with_options(key, serialized_arc.as_slice(), None, SetExpiration::Ex(ttl))
+                    .set_with_options(
+                        key.as_str(),
+                        serialized_arc.as_slice(),
+                        None,
+                        SetExpiration::Ex(ttl),
+                    )
                     .await;
 
                 Ok::<_, anyhow::Error>(DataEntry {
@@ -363,9 +386,67 @@ impl Cache {
         }
     }
 
+    /// Returns a previously cached value without computing it on a miss.
+    pub async fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, anyhow::Error> {
+        let key = self.key(key);
+        self.cache_calls.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(entry) = self.local.get(&key).await {
+            return Ok(Some(rmp_serde::from_slice::<T>(&entry.data)?));
+        }
+
+        let cached_value: Option<BulkString> = self.client.get(key.as_str()).await?;
+
+        match cached_value {
+            Some(value) => Ok(Some(rmp_serde::from_slice::<T>(&value)?)),
+            None => {
+                self.cache_misses.fetch_add(1, Ordering::Relaxed);
+
+                Ok(None)
+            }
+        }
+    }
+
+    pub async fn set<T: Serialize>(
+        &self,
+        key: &str,
+        ttl: u64,
+        value: &T,
+    ) -> Result<(), anyhow::Error> {
+        let key = self.key(key);
+        let effective_moka_ttl = if self.use_internal_cache {
+            Duration::from_secs(ttl)
+        } else {
+            Duration::from_millis(50)
+        };
+
+        let serialized = Arc::new(rmp_serde::to_vec(value)?);
+
+        self.client
+            .set_with_options(
+                key.as_str(),
+                serialized.as_slice(),
+                None,
+                SetExpiration::Ex(ttl),
+            )
+            .await?;
+        self.local
+            .insert(
+                key,
+                DataEntry {
+                    data: serialized,
+                    intended_ttl: effective_moka_ttl,
+                },
+            )
+            .await;
+
+        Ok(())
+    }
+
     pub async fn invalidate(&self, key: &str) -> Result<(), anyhow::Error> {
-        self.local.invalidate(key).await;
-        self.client.del(key).await?;
+        let key = self.key(key);
+        self.local.invalidate(&key).await;
+        self.client.del(&key).await?;
 
         Ok(())
     }
@@ -400,6 +481,7 @@ impl Drop for Cache {
 
 pub struct CacheLock {
     lock_id: Option<compact_str::CompactString>,
+    redis_key: compact_str::CompactString,
     redis_client: Arc<Client>,
     permit: Option<tokio::sync::OwnedSemaphorePermit>,
     ttl_guard: Option<tokio::task::JoinHandle<()>>,
@@ -408,24 +490,26 @@ pub struct CacheLock {
 impl CacheLock {
     fn new(
         lock_id: compact_str::CompactString,
+        redis_key: compact_str::CompactString,
         redis_client: Arc<Client>,
         permit: tokio::sync::OwnedSemaphorePermit,
         ttl: Option<u64>,
     ) -> Self {
         let ttl_guard = ttl.map(|secs| {
             let lock_id = lock_id.clone();
+            let redis_key = redis_key.clone();
             let redis_client = redis_client.clone();
 
             tokio::spawn(async move {
                 tokio::time::sleep(Duration::from_secs(secs)).await;
                 tracing::warn!(%lock_id, "cache lock TTL expired; force-releasing");
-                let redis_key = compact_str::format_compact!("lock::{}", lock_id);
                 let _ = redis_client.del(&redis_key).await;
             })
         });
 
         Self {
             lock_id: Some(lock_id),
+            redis_key,
             redis_client,
             permit: Some(permit),
             ttl_guard,
@@ -446,11 +530,11 @@ impl Drop for CacheLock {
 
         self.permit.take();
 
-        if let Some(lock_id) = self.lock_id.take() {
+        if self.lock_id.take().is_some() {
+            let redis_key = std::mem::take(&mut self.redis_key);
             let redis_client = self.redis_client.clone();
 
             tokio::spawn(async move {
-                let redis_key = compact_str::format_compact!("lock::{}", lock_id);
                 let _ = redis_client.del(&redis_key).await;
             });
         }
@@ -5,6 +5,7 @@ use tokio::sync::Mutex;
 
 type BatchFuture = Pin<Box<dyn Future<Output = Result<(), anyhow::Error>> + Send>>;
 
+#[derive(Clone)]
 pub struct Database {
     pub cache: Arc<crate::cache::Cache>,
 
@@ -13,6 +14,8 @@ pub struct Database {
 
     encryption_key: Arc<str>,
     use_decryption_cache: bool,
+    wings_max_concurrent_requests: usize,
+    wings_semaphores: Arc<Mutex<HashMap<uuid::Uuid, Arc<tokio::sync::Semaphore>>>>,
     batch_actions: Arc<Mutex<HashMap<(&'static str, uuid::Uuid), BatchFuture>>>,
 }
 
@@ -56,6 +59,8 @@ impl Database {
 
             encryption_key: env.app_encryption_key.clone().into(),
             use_decryption_cache: env.app_use_decryption_cache,
+            wings_max_concurrent_requests: env.app_wings_max_concurrent_requests,
+            wings_semaphores: Arc::new(Mutex::new(HashMap::new())),
             batch_actions: Arc::new(Mutex::new(HashMap::new())),
         };
 
@@ -143,6 +148,50 @@ impl Database {
         Ok(size.0 as u64)
     }
 
+    /// Recomputes all denormalized counters (server and backup counts) from their source tables.
+    pub async fn reconcile_counters(&self) -> Result<(), sqlx::Error> {
+        let mut transaction = self.write().begin().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE nodes
+            SET server_count = (SELECT COUNT(*) FROM servers WHERE servers.node_uuid = nodes.uuid)
+            "#,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE locations
+            SET server_count = (
+                SELECT COALESCE(SUM(nodes.server_count), 0)
+                FROM nodes
+                WHERE nodes.location_uuid = locations.uuid
+            )
+            "#,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE servers
+            SET backup_count = (
+                SELECT COUNT(*)
+                FROM server_backups
+                WHERE server_backups.server_uuid = servers.uuid AND server_backups.deleted IS NULL
+            )
+            "#,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
     #[inline]
     pub fn write(&self) -> &sqlx::PgPool {
         &self.write
@@ -153,6 +202,17 @@ impl Database {
         self.read.as_ref().unwrap_or(&self.write)
     }
 
+    /// Returns a handle that serves reads from the primary as well.
+    /// Use this for reads that have to observe a write that just happened,
+    /// as replicas may not have caught up yet.
+    #[inline]
+    pub fn primary(&self) -> Self {
+        Self {
+            read: None,
+            ..self.clone()
+        }
+    }
+
     pub async fn encrypt(
         &self,
         data: impl AsRef<[u8]> + Send + 'static,
@@ -224,6 +284,37 @@ impl Database {
         let mut actions = self.batch_actions.lock().await;
         actions.insert((key, uuid), Box::pin(action));
     }
+
+    /// Returns the semaphore limiting concurrent wings requests to the given node,
+    /// `None` if the limit is disabled.
+    pub async fn wings_semaphore(
+        &self,
+        node_uuid: uuid::Uuid,
+    ) -> Option<Arc<tokio::sync::Semaphore>> {
+        if self.wings_max_concurrent_requests == 0 {
+            return None;
+        }
+
+        let mut semaphores = self.wings_semaphores.lock().await;
+        Some(
+            semaphores
+                .entry(node_uuid)
+                .or_insert_with(|| {
+                    Arc::new(tokio::sync::Semaphore::new(
+                        self.wings_max_concurrent_requests,
+                    ))
+                })
+                .clone(),
+        )
+    }
+
+    /// Removes a pending batch action without executing it, returns whether one was queued.
+    /// Useful when the caller is about to perform the same action immediately.
+    #[inline]
+    pub async fn cancel_batch_action(&self, key: &'static str, uuid: uuid::Uuid) -> bool {
+        let mut actions = self.batch_actions.lock().await;
+        actions.remove(&(key, uuid)).is_some()
+    }
 }
 
 #[derive(Debug)]
@@ -3,7 +3,7 @@ use axum::{extract::ConnectInfo, http::HeaderMap};
 use colored::Colorize;
 use dotenvy::dotenv;
 use std::sync::{Arc, atomic::AtomicBool};
-use tracing_subscriber::fmt::writer::MakeWriterExt;
+use tracing_subscriber::{fmt::writer::MakeWriterExt, layer::SubscriberExt};
 
 #[derive(Clone)]
 pub enum RedisMode {
@@ -45,6 +45,8 @@ pub struct Env {
     pub app_debug: AtomicBool,
     pub app_use_decryption_cache: bool,
     pub app_use_internal_cache: bool,
+    pub app_cache_key_prefix: String,
+    pub app_wings_max_concurrent_requests: usize,
     pub app_trusted_proxies: Vec<cidr::IpCidr>,
     pub app_log_directory: Option<String>,
     pub app_encryption_key: String,
@@ -132,6 +134,15 @@ impl Env {
                 .trim_matches('"')
                 .parse()
                 .context("Invalid APP_USE_INTERNAL_CACHE value")?,
+            app_cache_key_prefix: std::env::var("APP_CACHE_KEY_PREFIX")
+                .unwrap_or("".to_string())
+                .trim_matches('"')
+                .to_string(),
+            app_wings_max_concurrent_requests: std::env::var("APP_WINGS_MAX_CONCURRENT_REQUESTS")
+                .unwrap_or("32".to_string())
+                .trim_matches('"')
+                .parse()
+                .context("Invalid APP_WINGS_MAX_CONCURRENT_REQUESTS value")?,
             app_trusted_proxies: std::env::var("APP_TRUSTED_PROXIES")
                 .unwrap_or("".to_string())
                 .trim_matches('"')
@@ -189,39 +200,34 @@ impl Env {
             (None, None)
         };
 
+        let log_filter =
+            crate::telemetry::log_filter_layer(if env.is_debug() { "debug" } else { "info" })?;
+
         if let Some(file_appender) = appender {
             tracing::subscriber::set_global_default(
-                tracing_subscriber::fmt()
-                    .with_timer(tracing_subscriber::fmt::time::ChronoLocal::rfc_3339())
-                    .with_writer(stdout_writer.and(file_appender))
-                    .with_target(false)
-                    .with_level(true)
-                    .with_file(true)
-                    .with_line_number(true)
-                    .with_max_level(if env.is_debug() {
-                        tracing::Level::DEBUG
-                    } else {
-                        tracing::Level::INFO
-                    })
-                    .finish(),
+                tracing_subscriber::registry().with(log_filter).with(
+                    tracing_subscriber::fmt::layer()
+                        .with_timer(tracing_subscriber::fmt::time::ChronoLocal::rfc_3339())
+                        .with_writer(stdout_writer.and(file_appender))
+                        .with_target(false)
+                        .with_level(true)
+                        .with_file(true)
+                        .with_line_number(true),
+                ),
             )?;
         } else {
             tracing::subscriber::set_global_default(
-                tracing_subscriber::fmt()
-                    .with_timer(tracing_subscriber::fmt::time::ChronoLocal::new(
-                        "%Y-%m-%d %H:%M:%S %z".to_string(),
-                    ))
-                    .with_writer(stdout_writer)
-                    .with_target(false)
-                    .with_level(true)
-                    .with_file(true)
-                    .with_line_number(true)
-                    .with_max_level(if env.is_debug() {
-                        tracing::Level::DEBUG
-                    } else {
-                        tracing::Level::INFO
-                    })
-                    .finish(),
+                tracing_subscriber::registry().with(log_filter).with(
+                    tracing_subscriber::fmt::layer()
+                        .with_timer(tracing_subscriber::fmt::time::ChronoLocal::new(
+                            "%Y-%m-%d %H:%M:%S %z".to_string(),
+                        ))
+                        .with_writer(stdout_writer)
+                        .with_target(false)
+                        .with_level(true)
+                        .with_file(true)
+                        .with_line_number(true),
+                ),
             )?;
         }
 
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+pub struct Features {
+    settings: Arc<super::settings::Settings>,
+}
+
+impl Features {
+    pub fn new(settings: Arc<super::settings::Settings>) -> Self {
+        Self { settings }
+    }
+
+    /// Returns whether a feature flag is enabled, either globally or for the user's role or account.
+    /// Unknown flags are always disabled.
+    pub async fn enabled(
+        &self,
+        flag: &str,
+        user: Option<&crate::models::user::User>,
+    ) -> Result<bool, anyhow::Error> {
+        Ok(self.settings.feature_flags().await?.is_enabled(flag, user))
+    }
+
+    /// Returns the names of all feature flags enabled for the user.
+    pub async fn enabled_for(
+        &self,
+        user: Option<&crate::models::user::User>,
+    ) -> Result<Vec<compact_str::CompactString>, anyhow::Error> {
+        Ok(self.settings.feature_flags().await?.enabled_for(user))
+    }
+}
@@ -24,6 +24,7 @@ pub mod env;
 pub mod events;
 pub mod extensions;
 pub mod extract;
+pub mod features;
 pub mod jwt;
 pub mod mail;
 pub mod models;
@@ -109,6 +110,7 @@ pub struct AppState {
     pub ntp: Arc<ntp::Ntp>,
     pub storage: Arc<storage::Storage>,
     pub captcha: Arc<captcha::Captcha>,
+    pub features: Arc<features::Features>,
     pub mail: Arc<mail::Mail>,
     pub database: Arc<database::Database>,
     pub cache: Arc<cache::Cache>,
@@ -144,6 +146,7 @@ impl AppState {
         );
         let storage = Arc::new(storage::Storage::new(settings.clone()));
         let captcha = Arc::new(captcha::Captcha::new(settings.clone()));
+        let features = Arc::new(features::Features::new(settings.clone()));
         let mail = Arc::new(mail::Mail::new(settings.clone()));
 
         let state = Arc::new(AppState {
@@ -169,6 +172,7 @@ impl AppState {
             ntp,
             storage,
             captcha,
+            features,
             mail,
             database: database.clone(),
             cache: cache.clone(),
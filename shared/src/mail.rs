@@ -1,12 +1,17 @@
 use crate::settings::SettingsReadGuard;
 use lettre::AsyncTransport;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{Mutex, OnceCell, Semaphore, mpsc};
 
 pub const MAIL_CONNECTION_TEST: &str = include_str!("../mails/connection_test.html");
 pub const MAIL_PASSWORD_RESET: &str = include_str!("../mails/password_reset.html");
 pub const MAIL_ACCOUNT_CREATED: &str = include_str!("../mails/account_created.html");
+pub const MAIL_RESOURCE_ALERT: &str = include_str!("../mails/resource_alert.html");
+pub const MAIL_BACKUP_COMPLETED: &str = include_str!("../mails/backup_completed.html");
+pub const MAIL_SERVER_SUSPENDED: &str = include_str!("../mails/server_suspended.html");
+pub const MAIL_SECURITY_ALERT: &str = include_str!("../mails/security_alert.html");
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Transport {
     None,
     Smtp {
@@ -26,13 +31,83 @@ enum Transport {
     },
 }
 
+impl Transport {
+    fn sender(
+        &self,
+    ) -> Option<(
+        &compact_str::CompactString,
+        &Option<compact_str::CompactString>,
+    )> {
+        match self {
+            Transport::None => None,
+            Transport::Smtp {
+                from_address,
+                from_name,
+                ..
+            }
+            | Transport::Sendmail {
+                from_address,
+                from_name,
+                ..
+            }
+            | Transport::Filesystem {
+                from_address,
+                from_name,
+                ..
+            } => Some((from_address, from_name)),
+        }
+    }
+
+    async fn send(&self, message: lettre::message::Message) -> Result<(), anyhow::Error> {
+        match self {
+            Transport::None => {}
+            Transport::Smtp { transport, .. } => {
+                transport.send(message).await?;
+            }
+            Transport::Sendmail { transport, .. } => {
+                transport.send(message).await?;
+            }
+            Transport::Filesystem { transport, .. } => {
+                transport.send(message).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Identifies the SMTP configuration a pooled transport was built for,
+/// the pool is rebuilt whenever any of these change.
+#[derive(PartialEq)]
+struct SmtpTransportKey {
+    host: compact_str::CompactString,
+    port: u16,
+    username: Option<compact_str::CompactString>,
+    password: Option<compact_str::CompactString>,
+    use_tls: bool,
+    pool_size: u32,
+}
+
+struct QueuedMail {
+    transport: Transport,
+    message: lettre::message::Message,
+    max_retries: u32,
+    retry_backoff: Duration,
+}
+
 pub struct Mail {
     settings: Arc<super::settings::Settings>,
+    smtp_transport: Mutex<Option<(SmtpTransportKey, Transport)>>,
+    queue: OnceCell<mpsc::Sender<QueuedMail>>,
 }
 
 impl Mail {
     pub fn new(settings: Arc<super::settings::Settings>) -> Self {
-        Self { settings }
+        Self {
+            settings,
+            smtp_transport: Mutex::new(None),
+            queue: OnceCell::new(),
+        }
     }
 
     async fn get_transport(&self) -> Result<(SettingsReadGuard<'_>, Transport), anyhow::Error> {
@@ -49,36 +124,63 @@ impl Mail {
                 from_address,
                 from_name,
             } => {
-                let mut transport =
-                    lettre::AsyncSmtpTransport::<lettre::Tokio1Executor>::builder_dangerous(
-                        host.as_str(),
-                    )
-                    .port(*port)
-                    .tls(if *use_tls {
-                        lettre::transport::smtp::client::Tls::Required(
-                            lettre::transport::smtp::client::TlsParametersBuilder::new(
-                                host.to_string(),
-                            )
-                            .build_native()
-                            .unwrap(),
+                let key = SmtpTransportKey {
+                    host: host.clone(),
+                    port: *port,
+                    username: username.clone(),
+                    password: password.clone(),
+                    use_tls: *use_tls,
+                    pool_size: settings.mail.smtp_pool_size,
+                };
+
+                let mut smtp_transport = self.smtp_transport.lock().await;
+                if let Some((cached_key, Transport::Smtp { transport, .. })) = &*smtp_transport
+                    && *cached_key == key
+                {
+                    Transport::Smtp {
+                        transport: transport.clone(),
+                        from_address: from_address.clone(),
+                        from_name: from_name.clone(),
+                    }
+                } else {
+                    let mut transport =
+                        lettre::AsyncSmtpTransport::<lettre::Tokio1Executor>::builder_dangerous(
+                            host.as_str(),
                         )
-                    } else {
-                        lettre::transport::smtp::client::Tls::None
-                    });
-
-                if let Some(username) = username {
-                    transport = transport.credentials(
-                        lettre::transport::smtp::authentication::Credentials::new(
-                            username.to_string(),
-                            password.clone().unwrap_or_default().into(),
-                        ),
-                    );
-                }
+                        .port(*port)
+                        .pool_config(
+                            lettre::transport::smtp::PoolConfig::new()
+                                .max_size(settings.mail.smtp_pool_size),
+                        )
+                        .tls(if *use_tls {
+                            lettre::transport::smtp::client::Tls::Required(
+                                lettre::transport::smtp::client::TlsParametersBuilder::new(
+                                    host.to_string(),
+                                )
+                                .build_native()
+                                .unwrap(),
+                            )
+                        } else {
+                            lettre::transport::smtp::client::Tls::None
+                        });
 
-                Transport::Smtp {
-                    transport: transport.build(),
-                    from_address: from_address.clone(),
-                    from_name: from_name.clone(),
+                    if let Some(username) = username {
+                        transport = transport.credentials(
+                            lettre::transport::smtp::authentication::Credentials::new(
+                                username.to_string(),
+                                password.clone().unwrap_or_default().into(),
+                            ),
+                        );
+                    }
+
+                    let transport = Transport::Smtp {
+                        transport: transport.build(),
+                        from_address: from_address.clone(),
+                        from_name: from_name.clone(),
+                    };
+                    *smtp_transport = Some((key, transport.clone()));
+
+                    transport
                 }
             }
             super::settings::MailMode::Sendmail {
@@ -115,6 +217,105 @@ impl Mail {
         Ok((settings, transport))
     }
 
+    /// Returns the sender of the delivery queue, spawning its worker on first use.
+    /// The queue size and delivery concurrency are read once, changing them requires a restart.
+    async fn get_queue(&self) -> Result<&mpsc::Sender<QueuedMail>, anyhow::Error> {
+        self.queue
+            .get_or_try_init(|| async {
+                let settings = self.settings.get().await?;
+                let (sender, mut receiver) =
+                    mpsc::channel::<QueuedMail>(settings.mail.queue_size as usize);
+                let semaphore = Arc::new(Semaphore::new(settings.mail.smtp_pool_size as usize));
+                drop(settings);
+
+                tokio::spawn(async move {
+                    while let Some(queued_mail) = receiver.recv().await {
+                        let permit = match semaphore.clone().acquire_owned().await {
+                            Ok(permit) => permit,
+                            Err(_) => break,
+                        };
+
+                        tokio::spawn(async move {
+                            Self::deliver(queued_mail).await;
+                            drop(permit);
+                        });
+                    }
+                });
+
+                Ok::<_, anyhow::Error>(sender)
+            })
+            .await
+    }
+
+    async fn deliver(queued_mail: QueuedMail) {
+        let mut attempt = 0;
+
+        loop {
+            match queued_mail
+                .transport
+                .send(queued_mail.message.clone())
+                .await
+            {
+                Ok(()) => {
+                    tracing::debug!("email sent successfully");
+                    return;
+                }
+                Err(err) => {
+                    // only smtp failures can be transient, a permanent rejection will never succeed
+                    let retryable = err
+                        .downcast_ref::<lettre::transport::smtp::Error>()
+                        .is_some_and(|err| !err.is_permanent());
+
+                    if !retryable || attempt >= queued_mail.max_retries {
+                        tracing::error!(attempts = attempt + 1, "failed to send email: {:?}", err);
+                        return;
+                    }
+
+                    let backoff = queued_mail.retry_backoff * 2u32.pow(attempt.min(10));
+                    tracing::warn!(
+                        attempt = attempt + 1,
+                        "failed to send email, retrying in {}ms: {:?}",
+                        backoff.as_millis(),
+                        err
+                    );
+
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Sends a notification email, unless the user opted out of its category.
+    pub async fn send_notification(
+        &self,
+        database: &crate::database::Database,
+        user_uuid: uuid::Uuid,
+        category: crate::models::user_notification_preferences::NotificationCategory,
+        destination: compact_str::CompactString,
+        subject: compact_str::CompactString,
+        body: impl AsRef<str>,
+        context: minijinja::Value,
+    ) {
+        match crate::models::user_notification_preferences::UserNotificationPreferences::by_user_uuid(
+            database, user_uuid,
+        )
+        .await
+        {
+            Ok(preferences) if !preferences.is_enabled(category) => return,
+            Ok(_) => {}
+            Err(err) => {
+                tracing::error!(
+                    user = %user_uuid,
+                    "failed to get notification preferences, sending anyway: {:?}",
+                    err
+                );
+            }
+        }
+
+        self.send(destination, subject, body, context).await;
+    }
+
     pub async fn send(
         &self,
         destination: compact_str::CompactString,
@@ -130,9 +331,16 @@ impl Mail {
             }
         };
 
+        let (from_address, from_name) = match transport.sender() {
+            Some((from_address, from_name)) => (from_address.clone(), from_name.clone()),
+            None => return,
+        };
+
         let mut environment = minijinja::Environment::new();
         environment.set_auto_escape_callback(|_| minijinja::AutoEscape::Html);
         environment.add_global("settings", minijinja::Value::from_serialize(&*settings));
+        let max_retries = settings.mail.max_retries;
+        let retry_backoff = Duration::from_millis(settings.mail.retry_backoff_ms);
         drop(settings);
 
         let rendered_body = match environment.render_str(body.as_ref(), context) {
@@ -150,83 +358,59 @@ impl Mail {
             }
         };
 
+        let build_message = || -> Result<lettre::message::Message, anyhow::Error> {
+            Ok(lettre::message::Message::builder()
+                .subject(subject.as_str())
+                .to(lettre::message::Mailbox::new(None, destination.parse()?))
+                .from(lettre::message::Mailbox::new(
+                    from_name.map(String::from),
+                    from_address.parse()?,
+                ))
+                .header(lettre::message::header::ContentType::TEXT_HTML)
+                .body(rendered_body)?)
+        };
+
+        let message = match build_message() {
+            Ok(message) => message,
+            Err(err) => {
+                tracing::error!(
+                    destination = ?destination,
+                    subject = ?subject,
+                    "error while building email: {:?}",
+                    err
+                );
+
+                return;
+            }
+        };
+
         tracing::debug!(
             transport = ?transport,
             destination = ?destination,
             subject = ?subject,
-            "sending email"
+            "queueing email"
         );
 
-        tokio::spawn(async move {
-            let run = async || -> Result<(), anyhow::Error> {
-                match transport {
-                    Transport::None => {}
-                    Transport::Smtp {
-                        transport,
-                        from_address,
-                        from_name,
-                    } => {
-                        transport
-                            .send(
-                                lettre::message::Message::builder()
-                                    .subject(subject)
-                                    .to(lettre::message::Mailbox::new(None, destination.parse()?))
-                                    .from(lettre::message::Mailbox::new(
-                                        from_name.map(String::from),
-                                        from_address.parse()?,
-                                    ))
-                                    .header(lettre::message::header::ContentType::TEXT_HTML)
-                                    .body(rendered_body)?,
-                            )
-                            .await?;
-                    }
-                    Transport::Sendmail {
-                        transport,
-                        from_address,
-                        from_name,
-                    } => {
-                        transport
-                            .send(
-                                lettre::message::Message::builder()
-                                    .subject(subject)
-                                    .to(lettre::message::Mailbox::new(None, destination.parse()?))
-                                    .from(lettre::message::Mailbox::new(
-                                        from_name.map(String::from),
-                                        from_address.parse()?,
-                                    ))
-                                    .header(lettre::message::header::ContentType::TEXT_HTML)
-                                    .body(rendered_body)?,
-                            )
-                            .await?;
-                    }
-                    Transport::Filesystem {
-                        transport,
-                        from_address,
-                        from_name,
-                    } => {
-                        transport
-                            .send(
-                                lettre::message::Message::builder()
-                                    .subject(subject)
-                                    .to(lettre::message::Mailbox::new(None, destination.parse()?))
-                                    .from(lettre::message::Mailbox::new(
-                                        from_name.map(String::from),
-                                        from_address.parse()?,
-                                    ))
-                                    .header(lettre::message::header::ContentType::TEXT_HTML)
-                                    .body(rendered_body)?,
-                            )
-                            .await?;
-                    }
-                }
-
-                Ok(())
-            };
-
-            match run().await {
-                Ok(_) => tracing::debug!("email sent successfully"),
-                Err(err) => tracing::error!("failed to send email: {:?}", err),
+        let queue = match self.get_queue().await {
+            Ok(queue) => queue,
+            Err(err) => {
+                tracing::error!("failed to get mail queue: {:#?}", err);
+                return;
             }
-        });
+        };
+
+        if let Err(err) = queue.try_send(QueuedMail {
+            transport,
+            message,
+            max_retries,
+            retry_backoff,
+        }) {
+            tracing::error!(
+                destination = ?destination,
+                subject = ?subject,
+                "failed to queue email, dropping it: {}",
+                err
+            );
+        }
     }
 }
@@ -162,28 +162,47 @@ impl AdminActivity {
         })
     }
 
+    /// Deletes all activity older than `cutoff` in batches of `batch_size` rows,
+    /// returning the total amount of deleted rows.
     pub async fn delete_older_than(
         database: &crate::database::Database,
         cutoff: chrono::DateTime<chrono::Utc>,
+        batch_size: i64,
     ) -> Result<u64, crate::database::DatabaseError> {
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM admin_activities
-            WHERE created < $1
-            "#,
-            cutoff.naive_utc()
-        )
-        .execute(database.write())
-        .await?;
+        let mut deleted = 0;
+
+        loop {
+            let result = sqlx::query(
+                r#"
+                DELETE FROM admin_activities
+                WHERE ctid IN (
+                    SELECT ctid
+                    FROM admin_activities
+                    WHERE created < $1
+                    LIMIT $2
+                )
+                "#,
+            )
+            .bind(cutoff.naive_utc())
+            .bind(batch_size)
+            .execute(database.write())
+            .await?;
+
+            deleted += result.rows_affected();
+
+            if result.rows_affected() < batch_size as u64 {
+                break;
+            }
+        }
 
-        Ok(result.rows_affected())
+        Ok(deleted)
     }
 
     #[inline]
     pub async fn into_admin_api_object(
         self,
         database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
+        storage_url_retriever: &StorageUrlRetriever,
     ) -> Result<AdminApiAdminActivity, anyhow::Error> {
         Ok(AdminApiAdminActivity {
             user: self
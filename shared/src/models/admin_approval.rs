@@ -0,0 +1,403 @@
+use crate::{prelude::*, response::DisplayError, settings::approvals::ApprovalAction};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, LazyLock},
+};
+use utoipa::ToSchema;
+
+pub enum AdminApprovalCheck {
+    /// The action does not require an approval.
+    Allowed,
+    /// The action was approved, the approval is held until it is consumed or dropped.
+    Approved(AdminApproval, crate::cache::CacheLock),
+    /// The action is waiting for another admin to approve it.
+    Pending(AdminApproval),
+}
+
+impl AdminApprovalCheck {
+    /// Consumes the approval, has to be called once the approved action succeeded.
+    /// Dropping the check instead keeps the approval for a retry.
+    pub async fn consume(
+        self,
+        database: &crate::database::Database,
+    ) -> Result<(), crate::database::DatabaseError> {
+        let Self::Approved(approval, _lock) = self else {
+            return Ok(());
+        };
+
+        sqlx::query(
+            r#"
+            DELETE FROM admin_approvals
+            WHERE admin_approvals.uuid = $1
+            "#,
+        )
+        .bind(approval.uuid)
+        .execute(database.write())
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AdminApproval {
+    pub uuid: uuid::Uuid,
+    pub action: compact_str::CompactString,
+    pub target_uuid: uuid::Uuid,
+    pub payload: serde_json::Value,
+
+    pub requester: Fetchable<super::user::User>,
+    pub approver: Option<Fetchable<super::user::User>>,
+
+    pub approved: Option<chrono::NaiveDateTime>,
+    pub expires: chrono::NaiveDateTime,
+    pub created: chrono::NaiveDateTime,
+}
+
+impl BaseModel for AdminApproval {
+    const NAME: &'static str = "admin_approval";
+
+    #[inline]
+    fn columns(prefix: Option<&str>) -> BTreeMap<&'static str, compact_str::CompactString> {
+        let prefix = prefix.unwrap_or_default();
+
+        BTreeMap::from([
+            (
+                "admin_approvals.uuid",
+                compact_str::format_compact!("{prefix}uuid"),
+            ),
+            (
+                "admin_approvals.action",
+                compact_str::format_compact!("{prefix}action"),
+            ),
+            (
+                "admin_approvals.target_uuid",
+                compact_str::format_compact!("{prefix}target_uuid"),
+            ),
+            (
+                "admin_approvals.payload",
+                compact_str::format_compact!("{prefix}payload"),
+            ),
+            (
+                "admin_approvals.requester_uuid",
+                compact_str::format_compact!("{prefix}requester_uuid"),
+            ),
+            (
+                "admin_approvals.approver_uuid",
+                compact_str::format_compact!("{prefix}approver_uuid"),
+            ),
+            (
+                "admin_approvals.approved",
+                compact_str::format_compact!("{prefix}approved"),
+            ),
+            (
+                "admin_approvals.expires",
+                compact_str::format_compact!("{prefix}expires"),
+            ),
+            (
+                "admin_approvals.created",
+                compact_str::format_compact!("{prefix}created"),
+            ),
+        ])
+    }
+
+    #[inline]
+    fn map(prefix: Option<&str>, row: &PgRow) -> Result<Self, crate::database::DatabaseError> {
+        let prefix = prefix.unwrap_or_default();
+
+        Ok(Self {
+            uuid: row.try_get(compact_str::format_compact!("{prefix}uuid").as_str())?,
+            action: row.try_get(compact_str::format_compact!("{prefix}action").as_str())?,
+            target_uuid: row
+                .try_get(compact_str::format_compact!("{prefix}target_uuid").as_str())?,
+            payload: row.try_get(compact_str::format_compact!("{prefix}payload").as_str())?,
+            requester: super::user::User::get_fetchable(
+                row.try_get(compact_str::format_compact!("{prefix}requester_uuid").as_str())?,
+            ),
+            approver: super::user::User::get_fetchable_from_row(
+                row,
+                compact_str::format_compact!("{prefix}approver_uuid"),
+            ),
+            approved: row.try_get(compact_str::format_compact!("{prefix}approved").as_str())?,
+            expires: row.try_get(compact_str::format_compact!("{prefix}expires").as_str())?,
+            created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
+        })
+    }
+}
+
+impl AdminApproval {
+    /// Checks whether an admin action may be executed.
+    /// If the action requires an approval, an approved request for the target is locked and returned,
+    /// otherwise a pending request is created (or the existing one returned).
+    /// The payload holds the options of the action, an approval is only valid for the exact payload it was requested with.
+    pub async fn check(
+        state: &crate::State,
+        action: ApprovalAction,
+        target_uuid: uuid::Uuid,
+        payload: serde_json::Value,
+        user_uuid: uuid::Uuid,
+    ) -> Result<AdminApprovalCheck, anyhow::Error> {
+        let settings = state.settings.get_as(|s| s.approvals.clone()).await?;
+        if !settings.requires_approval(action) {
+            return Ok(AdminApprovalCheck::Allowed);
+        }
+
+        let approved_uuid: Option<uuid::Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT admin_approvals.uuid
+            FROM admin_approvals
+            WHERE admin_approvals.action = $1 AND admin_approvals.target_uuid = $2
+                AND admin_approvals.payload = $3 AND admin_approvals.approved IS NOT NULL
+                AND admin_approvals.expires > NOW()
+            LIMIT 1
+            "#,
+        )
+        .bind(action.as_str())
+        .bind(target_uuid)
+        .bind(&payload)
+        .fetch_optional(state.database.write())
+        .await?;
+
+        if let Some(approved_uuid) = approved_uuid {
+            let lock = state
+                .cache
+                .lock(
+                    compact_str::format_compact!("admin_approvals::{approved_uuid}"),
+                    Some(300),
+                    Some(30),
+                )
+                .await?;
+
+            // a concurrent request may have consumed the approval while we waited for the lock
+            let row = sqlx::query(&format!(
+                r#"
+                SELECT {}
+                FROM admin_approvals
+                WHERE admin_approvals.uuid = $1 AND admin_approvals.approved IS NOT NULL
+                    AND admin_approvals.expires > NOW()
+                "#,
+                Self::columns_sql(None)
+            ))
+            .bind(approved_uuid)
+            .fetch_optional(state.database.write())
+            .await?;
+
+            if let Some(row) = row {
+                return Ok(AdminApprovalCheck::Approved(Self::map(None, &row)?, lock));
+            }
+        }
+
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM admin_approvals
+            WHERE admin_approvals.action = $1 AND admin_approvals.target_uuid = $2
+                AND admin_approvals.payload = $3 AND admin_approvals.approved IS NULL
+                AND admin_approvals.expires > NOW()
+            LIMIT 1
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(action.as_str())
+        .bind(target_uuid)
+        .bind(&payload)
+        .fetch_optional(state.database.read())
+        .await?;
+
+        if let Some(row) = row {
+            return Ok(AdminApprovalCheck::Pending(Self::map(None, &row)?));
+        }
+
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO admin_approvals (action, target_uuid, payload, requester_uuid, expires)
+            VALUES ($1, $2, $3, $4, NOW() + make_interval(mins => $5))
+            RETURNING {}
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(action.as_str())
+        .bind(target_uuid)
+        .bind(&payload)
+        .bind(user_uuid)
+        .bind(settings.expiry_minutes as i32)
+        .fetch_one(state.database.write())
+        .await?;
+
+        Ok(AdminApprovalCheck::Pending(Self::map(None, &row)?))
+    }
+
+    pub async fn all_pending(
+        database: &crate::database::Database,
+    ) -> Result<Vec<Self>, crate::database::DatabaseError> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM admin_approvals
+            WHERE admin_approvals.expires > NOW()
+            ORDER BY admin_approvals.created
+            "#,
+            Self::columns_sql(None)
+        ))
+        .fetch_all(database.read())
+        .await?;
+
+        rows.into_iter()
+            .map(|row| Self::map(None, &row))
+            .try_collect_vec()
+    }
+
+    /// Approves a pending request, the approving admin has to be different from the requester.
+    pub async fn approve(
+        &mut self,
+        database: &crate::database::Database,
+        user_uuid: uuid::Uuid,
+    ) -> Result<(), anyhow::Error> {
+        if self.requester.uuid == user_uuid {
+            return Err(
+                DisplayError::new("requests cannot be approved by their requester")
+                    .with_status(axum::http::StatusCode::CONFLICT)
+                    .into(),
+            );
+        }
+
+        let approved: Option<chrono::NaiveDateTime> = sqlx::query_scalar(
+            r#"
+            UPDATE admin_approvals
+            SET approver_uuid = $2, approved = NOW()
+            WHERE admin_approvals.uuid = $1 AND admin_approvals.approved IS NULL
+                AND admin_approvals.expires > NOW()
+            RETURNING admin_approvals.approved
+            "#,
+        )
+        .bind(self.uuid)
+        .bind(user_uuid)
+        .fetch_optional(database.write())
+        .await?;
+
+        let Some(approved) = approved else {
+            return Err(
+                DisplayError::new("request has already been approved or expired")
+                    .with_status(axum::http::StatusCode::CONFLICT)
+                    .into(),
+            );
+        };
+
+        self.approver = Some(super::user::User::get_fetchable(user_uuid));
+        self.approved = Some(approved);
+
+        Ok(())
+    }
+
+    pub async fn delete_expired(database: &crate::database::Database) -> Result<u64, sqlx::Error> {
+        Ok(sqlx::query(
+            r#"
+            DELETE FROM admin_approvals
+            WHERE admin_approvals.expires < NOW()
+            "#,
+        )
+        .execute(database.write())
+        .await?
+        .rows_affected())
+    }
+
+    #[inline]
+    pub fn action(&self) -> Option<ApprovalAction> {
+        ApprovalAction::parse(&self.action)
+    }
+
+    #[inline]
+    pub fn into_admin_api_object(self) -> AdminApiAdminApproval {
+        AdminApiAdminApproval {
+            uuid: self.uuid,
+            action: self.action,
+            target_uuid: self.target_uuid,
+            payload: self.payload,
+            requester_uuid: self.requester.uuid,
+            approver_uuid: self.approver.map(|approver| approver.uuid),
+            approved: self.approved.map(|dt| dt.and_utc()),
+            expires: self.expires.and_utc(),
+            created: self.created.and_utc(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ByUuid for AdminApproval {
+    const TABLE: &'static str = "admin_approvals";
+
+    async fn by_uuid(
+        database: &crate::database::Database,
+        uuid: uuid::Uuid,
+    ) -> Result<Self, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM admin_approvals
+            WHERE admin_approvals.uuid = $1
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(uuid)
+        .fetch_one(database.read())
+        .await?;
+
+        Self::map(None, &row)
+    }
+}
+
+#[async_trait::async_trait]
+impl DeletableModel for AdminApproval {
+    type DeleteOptions = ();
+
+    fn get_delete_handlers() -> &'static LazyLock<DeleteListenerList<Self>> {
+        static DELETE_LISTENERS: LazyLock<DeleteListenerList<AdminApproval>> =
+            LazyLock::new(|| Arc::new(ModelHandlerList::default()));
+
+        &DELETE_LISTENERS
+    }
+
+    async fn delete(
+        &self,
+        state: &crate::State,
+        options: Self::DeleteOptions,
+    ) -> Result<(), anyhow::Error> {
+        let mut transaction = state.database.write().begin().await?;
+
+        self.run_delete_handlers(&options, state, &mut transaction)
+            .await?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM admin_approvals
+            WHERE admin_approvals.uuid = $1
+            "#,
+        )
+        .bind(self.uuid)
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+#[schema(title = "AdminApproval")]
+pub struct AdminApiAdminApproval {
+    pub uuid: uuid::Uuid,
+    pub action: compact_str::CompactString,
+    pub target_uuid: uuid::Uuid,
+    /// The options the action is executed with once approved.
+    pub payload: serde_json::Value,
+
+    pub requester_uuid: uuid::Uuid,
+    pub approver_uuid: Option<uuid::Uuid>,
+
+    pub approved: Option<chrono::DateTime<chrono::Utc>>,
+    pub expires: chrono::DateTime<chrono::Utc>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
@@ -318,6 +318,8 @@ impl BackupConfiguration {
 
 #[async_trait::async_trait]
 impl ByUuid for BackupConfiguration {
+    const TABLE: &'static str = "backup_configurations";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
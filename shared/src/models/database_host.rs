@@ -302,6 +302,8 @@ impl DatabaseHost {
 
 #[async_trait::async_trait]
 impl ByUuid for DatabaseHost {
+    const TABLE: &'static str = "database_hosts";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
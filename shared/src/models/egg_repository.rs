@@ -369,6 +369,8 @@ impl UpdatableModel for EggRepository {
 
 #[async_trait::async_trait]
 impl ByUuid for EggRepository {
+    const TABLE: &'static str = "egg_repositories";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
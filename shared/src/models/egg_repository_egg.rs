@@ -232,6 +232,8 @@ impl EggRepositoryEgg {
 
 #[async_trait::async_trait]
 impl ByUuid for EggRepositoryEgg {
+    const TABLE: &'static str = "egg_repository_eggs";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
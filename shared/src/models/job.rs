@@ -0,0 +1,632 @@
+use crate::{models::node::Node, prelude::*, response::DisplayError};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow, prelude::Type};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, LazyLock},
+};
+use tokio::sync::Semaphore;
+use utoipa::ToSchema;
+
+/// Limits how many jobs are processed at the same time by this instance.
+static JOB_SEMAPHORE: LazyLock<Arc<Semaphore>> = LazyLock::new(|| Arc::new(Semaphore::new(8)));
+
+#[derive(ToSchema, Serialize, Deserialize, Type, PartialEq, Eq, Hash, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+#[schema(rename_all = "snake_case")]
+#[sqlx(type_name = "job_status", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServerTransferJob {
+    pub server_uuid: uuid::Uuid,
+    pub destination_node_uuid: uuid::Uuid,
+
+    pub allocation_uuid: Option<uuid::Uuid>,
+    pub allocation_uuids: Vec<uuid::Uuid>,
+
+    pub backups: Vec<uuid::Uuid>,
+    pub delete_source_backups: bool,
+    pub archive_format: wings_api::TransferArchiveFormat,
+    pub compression_level: Option<wings_api::CompressionLevel>,
+    pub multiplex_channels: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServerReinstallJob {
+    pub server_uuid: uuid::Uuid,
+    pub user_uuid: Option<uuid::Uuid>,
+    pub truncate_directory: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServerBackupJob {
+    pub server_uuid: uuid::Uuid,
+    pub backup_uuid: uuid::Uuid,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JobPayload {
+    ServerTransfer(ServerTransferJob),
+    ServerReinstall(ServerReinstallJob),
+    ServerBackup(ServerBackupJob),
+}
+
+impl JobPayload {
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ServerTransfer(_) => "server_transfer",
+            Self::ServerReinstall(_) => "server_reinstall",
+            Self::ServerBackup(_) => "server_backup",
+        }
+    }
+
+    /// The admin permission required to view a job of this type.
+    #[inline]
+    pub fn admin_permission(&self) -> &'static str {
+        match self {
+            Self::ServerTransfer(_) => "servers.transfer",
+            Self::ServerReinstall(_) | Self::ServerBackup(_) => "servers.read",
+        }
+    }
+
+    /// The server permission required to see a queued job of the given type on a server.
+    /// Returns `None` for job types that are visible to everyone with access to the server.
+    #[inline]
+    pub fn server_permission(r#type: &str) -> Option<&'static str> {
+        match r#type {
+            "server_reinstall" => Some("settings.install"),
+            "server_backup" => Some("backups.read"),
+            _ => None,
+        }
+    }
+
+    /// Runs the job, returning `None` if it is finished later on by an external callback.
+    async fn run(
+        self,
+        state: &crate::State,
+        job: &Job,
+    ) -> Result<Option<serde_json::Value>, anyhow::Error> {
+        match self {
+            Self::ServerTransfer(transfer) => {
+                let server =
+                    super::server::Server::by_uuid_optional(&state.database, transfer.server_uuid)
+                        .await?
+                        .ok_or_else(|| DisplayError::new("server not found"))?;
+                let destination_node =
+                    Node::by_uuid_optional(&state.database, transfer.destination_node_uuid)
+                        .await?
+                        .ok_or_else(|| DisplayError::new("destination node not found"))?;
+
+                job.update_progress(&state.database, 10).await?;
+
+                server
+                    .transfer(
+                        state,
+                        super::server::ServerTransferOptions {
+                            destination_node,
+                            allocation_uuid: transfer.allocation_uuid,
+                            allocation_uuids: transfer.allocation_uuids,
+                            backups: transfer.backups,
+                            delete_source_backups: transfer.delete_source_backups,
+                            archive_format: transfer.archive_format,
+                            compression_level: transfer.compression_level,
+                            multiplex_channels: transfer.multiplex_channels,
+                        },
+                    )
+                    .await?;
+
+                // wings accepted the transfer, the remote transfer callbacks finish the job
+                job.update_progress(&state.database, 50).await?;
+
+                Ok(None)
+            }
+            Self::ServerReinstall(reinstall) => {
+                let server =
+                    super::server::Server::by_uuid_optional(&state.database, reinstall.server_uuid)
+                        .await?
+                        .ok_or_else(|| DisplayError::new("server not found"))?;
+
+                server
+                    .install(
+                        state,
+                        reinstall.user_uuid,
+                        reinstall.truncate_directory,
+                        None,
+                    )
+                    .await?;
+
+                Ok(Some(serde_json::json!({
+                    "server_uuid": reinstall.server_uuid,
+                })))
+            }
+            Self::ServerBackup(backup) => {
+                let server =
+                    super::server::Server::by_uuid_optional(&state.database, backup.server_uuid)
+                        .await?
+                        .ok_or_else(|| DisplayError::new("server not found"))?;
+                let server_backup = super::server_backup::ServerBackup::by_server_uuid_uuid(
+                    &state.database,
+                    backup.server_uuid,
+                    backup.backup_uuid,
+                )
+                .await?
+                .ok_or_else(|| DisplayError::new("backup not found"))?;
+
+                server_backup.start(&state.database, &server).await?;
+
+                Ok(Some(serde_json::json!({
+                    "server_uuid": backup.server_uuid,
+                    "backup_uuid": backup.backup_uuid,
+                })))
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Job {
+    pub uuid: uuid::Uuid,
+    pub user: Option<Fetchable<super::user::User>>,
+
+    pub r#type: compact_str::CompactString,
+    pub status: JobStatus,
+    pub progress: i16,
+
+    pub payload: serde_json::Value,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<String>,
+
+    pub started: Option<chrono::NaiveDateTime>,
+    pub completed: Option<chrono::NaiveDateTime>,
+    pub created: chrono::NaiveDateTime,
+}
+
+impl BaseModel for Job {
+    const NAME: &'static str = "job";
+
+    #[inline]
+    fn columns(prefix: Option<&str>) -> BTreeMap<&'static str, compact_str::CompactString> {
+        let prefix = prefix.unwrap_or_default();
+
+        BTreeMap::from([
+            ("jobs.uuid", compact_str::format_compact!("{prefix}uuid")),
+            (
+                "jobs.user_uuid",
+                compact_str::format_compact!("{prefix}user_uuid"),
+            ),
+            ("jobs.type", compact_str::format_compact!("{prefix}type")),
+            (
+                "jobs.status",
+                compact_str::format_compact!("{prefix}status"),
+            ),
+            (
+                "jobs.progress",
+                compact_str::format_compact!("{prefix}progress"),
+            ),
+            (
+                "jobs.payload",
+                compact_str::format_compact!("{prefix}payload"),
+            ),
+            (
+                "jobs.result",
+                compact_str::format_compact!("{prefix}result"),
+            ),
+            ("jobs.error", compact_str::format_compact!("{prefix}error")),
+            (
+                "jobs.started",
+                compact_str::format_compact!("{prefix}started"),
+            ),
+            (
+                "jobs.completed",
+                compact_str::format_compact!("{prefix}completed"),
+            ),
+            (
+                "jobs.created",
+                compact_str::format_compact!("{prefix}created"),
+            ),
+        ])
+    }
+
+    #[inline]
+    fn map(prefix: Option<&str>, row: &PgRow) -> Result<Self, crate::database::DatabaseError> {
+        let prefix = prefix.unwrap_or_default();
+
+        Ok(Self {
+            uuid: row.try_get(compact_str::format_compact!("{prefix}uuid").as_str())?,
+            user: super::user::User::get_fetchable_from_row(
+                row,
+                compact_str::format_compact!("{prefix}user_uuid"),
+            ),
+            r#type: row.try_get(compact_str::format_compact!("{prefix}type").as_str())?,
+            status: row.try_get(compact_str::format_compact!("{prefix}status").as_str())?,
+            progress: row.try_get(compact_str::format_compact!("{prefix}progress").as_str())?,
+            payload: row.try_get(compact_str::format_compact!("{prefix}payload").as_str())?,
+            result: row.try_get(compact_str::format_compact!("{prefix}result").as_str())?,
+            error: row.try_get(compact_str::format_compact!("{prefix}error").as_str())?,
+            started: row.try_get(compact_str::format_compact!("{prefix}started").as_str())?,
+            completed: row.try_get(compact_str::format_compact!("{prefix}completed").as_str())?,
+            created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
+        })
+    }
+}
+
+impl Job {
+    /// Queues a new job, it will be picked up by the job worker of a primary instance.
+    pub async fn create(
+        database: &crate::database::Database,
+        user_uuid: Option<uuid::Uuid>,
+        payload: &JobPayload,
+    ) -> Result<Self, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO jobs (user_uuid, type, payload)
+            VALUES ($1, $2, $3)
+            RETURNING {}
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(user_uuid)
+        .bind(payload.name())
+        .bind(serde_json::to_value(payload)?)
+        .fetch_one(database.write())
+        .await?;
+
+        Self::map(None, &row)
+    }
+
+    /// Claims the oldest pending job, marking it as running.
+    /// Safe to call from multiple instances, a job is only ever claimed once.
+    pub async fn claim_next(
+        database: &crate::database::Database,
+    ) -> Result<Option<Self>, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE jobs
+            SET status = 'RUNNING', started = NOW()
+            WHERE jobs.uuid = (
+                SELECT pending_jobs.uuid
+                FROM jobs pending_jobs
+                WHERE pending_jobs.status = 'PENDING'
+                ORDER BY pending_jobs.created
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING {}
+            "#,
+            Self::columns_sql(None)
+        ))
+        .fetch_optional(database.write())
+        .await?;
+
+        row.try_map(|row| Self::map(None, &row))
+    }
+
+    pub async fn update_progress(
+        &self,
+        database: &crate::database::Database,
+        progress: u8,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE jobs
+            SET progress = $2
+            WHERE jobs.uuid = $1
+            "#,
+        )
+        .bind(self.uuid)
+        .bind(progress.min(100) as i16)
+        .execute(database.write())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn finish(
+        &self,
+        database: &crate::database::Database,
+        result: Result<serde_json::Value, anyhow::Error>,
+    ) -> Result<(), sqlx::Error> {
+        let (status, result, error) = match result {
+            Ok(result) => (JobStatus::Completed, Some(result), None),
+            Err(err) => {
+                let error = match err.downcast_ref::<DisplayError>() {
+                    Some(error) => error.message().to_string(),
+                    None => {
+                        tracing::error!(job = %self.uuid, "job failed: {:?}", err);
+                        sentry_anyhow::capture_anyhow(&err);
+
+                        "internal server error".to_string()
+                    }
+                };
+
+                (JobStatus::Failed, None, Some(error))
+            }
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE jobs
+            SET status = $2, progress = CASE WHEN $2 = 'COMPLETED'::job_status THEN 100 ELSE jobs.progress END,
+                result = $3, error = $4, completed = NOW()
+            WHERE jobs.uuid = $1
+            "#,
+        )
+        .bind(self.uuid)
+        .bind(status)
+        .bind(result)
+        .bind(error)
+        .execute(database.write())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Runs a claimed job, storing its result or error unless it is finished by an external callback.
+    pub async fn run(self, state: &crate::State) -> Result<(), anyhow::Error> {
+        let result = match serde_json::from_value::<JobPayload>(self.payload.clone()) {
+            Ok(payload) => payload.run(state, &self).await,
+            Err(err) => Err(err.into()),
+        };
+
+        match result {
+            Ok(None) => {}
+            Ok(Some(result)) => self.finish(&state.database, Ok(result)).await?,
+            Err(err) => self.finish(&state.database, Err(err)).await?,
+        }
+
+        Ok(())
+    }
+
+    /// Finishes the running transfer job of a server once wings reported the outcome of the transfer.
+    pub async fn finish_server_transfer(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+        destination_node_uuid: uuid::Uuid,
+        successful: bool,
+    ) -> Result<(), sqlx::Error> {
+        let (status, result, error) = if successful {
+            (
+                JobStatus::Completed,
+                Some(serde_json::json!({
+                    "server_uuid": server_uuid,
+                    "destination_node_uuid": destination_node_uuid,
+                })),
+                None,
+            )
+        } else {
+            (JobStatus::Failed, None, Some("server transfer failed"))
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE jobs
+            SET status = $2, progress = CASE WHEN $2 = 'COMPLETED'::job_status THEN 100 ELSE jobs.progress END,
+                result = $3, error = $4, completed = NOW()
+            WHERE jobs.type = 'server_transfer' AND jobs.status = 'RUNNING'
+                AND jobs.payload->>'server_uuid' = $1::text
+            "#,
+        )
+        .bind(server_uuid)
+        .bind(status)
+        .bind(result)
+        .bind(error)
+        .execute(database.write())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Claims and spawns pending jobs until either none are left or the concurrency limit is reached.
+    /// Returns the amount of jobs that were started.
+    pub async fn process_pending(state: &crate::State) -> Result<usize, anyhow::Error> {
+        let mut started = 0;
+
+        while let Ok(permit) = JOB_SEMAPHORE.clone().try_acquire_owned() {
+            let job = match Self::claim_next(&state.database).await? {
+                Some(job) => job,
+                None => break,
+            };
+
+            let state = state.clone();
+            tokio::spawn(async move {
+                let uuid = job.uuid;
+                if let Err(err) = job.run(&state).await {
+                    tracing::error!(job = %uuid, "failed to store job result: {:?}", err);
+                }
+
+                drop(permit);
+            });
+
+            started += 1;
+        }
+
+        Ok(started)
+    }
+
+    /// Marks jobs that were running when the panel stopped as failed, they cannot be resumed.
+    /// Transfers that were already handed to wings are left running, their callbacks still finish them.
+    pub async fn fail_interrupted(
+        database: &crate::database::Database,
+    ) -> Result<u64, sqlx::Error> {
+        Ok(sqlx::query(
+            r#"
+            UPDATE jobs
+            SET status = 'FAILED', error = 'job was interrupted', completed = NOW()
+            WHERE jobs.status = 'RUNNING' AND NOT (
+                jobs.type = 'server_transfer' AND EXISTS (
+                    SELECT 1 FROM servers
+                    WHERE servers.uuid::text = jobs.payload->>'server_uuid'
+                        AND servers.destination_node_uuid IS NOT NULL
+                )
+            )
+            "#,
+        )
+        .execute(database.write())
+        .await?
+        .rows_affected())
+    }
+
+    pub async fn delete_finished_older_than(
+        database: &crate::database::Database,
+        before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, sqlx::Error> {
+        Ok(sqlx::query(
+            r#"
+            DELETE FROM jobs
+            WHERE jobs.status IN ('COMPLETED', 'FAILED') AND jobs.completed < $1
+            "#,
+        )
+        .bind(before.naive_utc())
+        .execute(database.write())
+        .await?
+        .rows_affected())
+    }
+
+    /// Returns the 1-based position of a job among all pending jobs, `None` if it is no longer pending.
+    pub async fn queue_position(
+        database: &crate::database::Database,
+        uuid: uuid::Uuid,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        let position: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*)
+            FROM jobs
+            JOIN jobs pending_jobs ON pending_jobs.status = 'PENDING'
+                AND pending_jobs.created <= jobs.created
+            WHERE jobs.uuid = $1 AND jobs.status = 'PENDING'
+            "#,
+        )
+        .bind(uuid)
+        .fetch_one(database.read())
+        .await?;
+
+        Ok((position > 0).then_some(position))
+    }
+
+    /// Lists the pending jobs operating on a server, along with their position in the queue.
+    pub async fn queued_by_server_uuid(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+    ) -> Result<Vec<ApiQueuedJob>, sqlx::Error> {
+        let rows = sqlx::query(
+            r#"
+            SELECT pending_jobs.type, pending_jobs.position, pending_jobs.created
+            FROM (
+                SELECT jobs.type, jobs.payload, jobs.created,
+                    ROW_NUMBER() OVER (ORDER BY jobs.created) AS position
+                FROM jobs
+                WHERE jobs.status = 'PENDING'
+            ) pending_jobs
+            WHERE pending_jobs.payload->>'server_uuid' = $1::text
+            ORDER BY pending_jobs.position
+            "#,
+        )
+        .bind(server_uuid)
+        .fetch_all(database.read())
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(ApiQueuedJob {
+                    r#type: row.try_get("type")?,
+                    position: row.try_get("position")?,
+                    created: row
+                        .try_get::<chrono::NaiveDateTime, _>("created")?
+                        .and_utc(),
+                })
+            })
+            .try_collect_vec()
+    }
+
+    /// The admin permission required to view this job, `None` if its payload cannot be parsed.
+    #[inline]
+    pub fn admin_permission(&self) -> Option<&'static str> {
+        serde_json::from_value::<JobPayload>(self.payload.clone())
+            .ok()
+            .map(|payload| payload.admin_permission())
+    }
+
+    #[inline]
+    pub async fn into_admin_api_object(
+        self,
+        database: &crate::database::Database,
+    ) -> Result<AdminApiJob, anyhow::Error> {
+        let queue_position = if self.status == JobStatus::Pending {
+            Self::queue_position(database, self.uuid).await?
+        } else {
+            None
+        };
+
+        Ok(AdminApiJob {
+            uuid: self.uuid,
+            r#type: self.r#type,
+            status: self.status,
+            progress: self.progress,
+            queue_position,
+            result: self.result,
+            error: self.error,
+            started: self.started.map(|dt| dt.and_utc()),
+            completed: self.completed.map(|dt| dt.and_utc()),
+            created: self.created.and_utc(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ByUuid for Job {
+    const TABLE: &'static str = "jobs";
+
+    async fn by_uuid(
+        database: &crate::database::Database,
+        uuid: uuid::Uuid,
+    ) -> Result<Self, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM jobs
+            WHERE jobs.uuid = $1
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(uuid)
+        .fetch_one(database.read())
+        .await?;
+
+        Self::map(None, &row)
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+#[schema(title = "Job")]
+pub struct AdminApiJob {
+    pub uuid: uuid::Uuid,
+
+    pub r#type: compact_str::CompactString,
+    pub status: JobStatus,
+    pub progress: i16,
+    pub queue_position: Option<i64>,
+
+    pub result: Option<serde_json::Value>,
+    pub error: Option<String>,
+
+    pub started: Option<chrono::DateTime<chrono::Utc>>,
+    pub completed: Option<chrono::DateTime<chrono::Utc>>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(ToSchema, Serialize)]
+#[schema(title = "QueuedJob")]
+pub struct ApiQueuedJob {
+    pub r#type: compact_str::CompactString,
+    pub position: i64,
+
+    pub created: chrono::DateTime<chrono::Utc>,
+}
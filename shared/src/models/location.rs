@@ -19,6 +19,8 @@ pub struct Location {
     pub name: compact_str::CompactString,
     pub description: Option<compact_str::CompactString>,
 
+    pub server_count: i32,
+
     pub created: chrono::NaiveDateTime,
 }
 
@@ -46,6 +48,10 @@ impl BaseModel for Location {
                 "locations.description",
                 compact_str::format_compact!("{prefix}description"),
             ),
+            (
+                "locations.server_count",
+                compact_str::format_compact!("{prefix}server_count"),
+            ),
             (
                 "locations.created",
                 compact_str::format_compact!("{prefix}created"),
@@ -67,6 +73,8 @@ impl BaseModel for Location {
             name: row.try_get(compact_str::format_compact!("{prefix}name").as_str())?,
             description: row
                 .try_get(compact_str::format_compact!("{prefix}description").as_str())?,
+            server_count: row
+                .try_get(compact_str::format_compact!("{prefix}server_count").as_str())?,
             created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
         })
     }
@@ -171,6 +179,7 @@ impl Location {
             },
             name: self.name,
             description: self.description,
+            server_count: self.server_count,
             created: self.created.and_utc(),
         }
     }
@@ -178,6 +187,8 @@ impl Location {
 
 #[async_trait::async_trait]
 impl ByUuid for Location {
+    const TABLE: &'static str = "locations";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -421,5 +432,7 @@ pub struct AdminApiLocation {
     pub name: compact_str::CompactString,
     pub description: Option<compact_str::CompactString>,
 
+    pub server_count: i32,
+
     pub created: chrono::DateTime<chrono::Utc>,
 }
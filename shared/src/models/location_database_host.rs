@@ -199,7 +199,7 @@ impl CreatableModel for LocationDatabaseHost {
         transaction.commit().await?;
 
         match Self::by_location_uuid_database_host_uuid(
-            &state.database,
+            &state.database.primary(),
             options.location_uuid,
             options.database_host_uuid,
         )
@@ -8,29 +8,36 @@ use sqlx::{
     postgres::{PgArguments, PgRow},
 };
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     marker::PhantomData,
     pin::Pin,
-    sync::{Arc, LazyLock},
+    sync::{
+        Arc, LazyLock,
+        atomic::{AtomicI64, Ordering},
+    },
 };
 use tokio::sync::RwLock;
 use utoipa::ToSchema;
 
 pub mod admin_activity;
+pub mod admin_approval;
 pub mod backup_configuration;
 pub mod database_host;
 pub mod egg_repository;
 pub mod egg_repository_egg;
+pub mod job;
 pub mod location;
 pub mod location_database_host;
 pub mod mount;
 pub mod nest;
 pub mod nest_egg;
 pub mod nest_egg_mount;
+pub mod nest_egg_resource_alert;
 pub mod nest_egg_variable;
 pub mod node;
 pub mod node_allocation;
 pub mod node_mount;
+pub mod node_tag;
 pub mod oauth_provider;
 pub mod role;
 pub mod server;
@@ -39,6 +46,8 @@ pub mod server_allocation;
 pub mod server_backup;
 pub mod server_database;
 pub mod server_mount;
+pub mod server_reinstall_history;
+pub mod server_resource_alert;
 pub mod server_schedule;
 pub mod server_schedule_step;
 pub mod server_subuser;
@@ -47,6 +56,7 @@ pub mod user;
 pub mod user_activity;
 pub mod user_api_key;
 pub mod user_command_snippet;
+pub mod user_notification_preferences;
 pub mod user_oauth_link;
 pub mod user_password_reset;
 pub mod user_recovery_code;
@@ -61,8 +71,8 @@ pub struct PaginationParams {
     #[schema(minimum = 1)]
     #[serde(default = "Pagination::default_page")]
     pub page: i64,
-    #[garde(range(min = 1, max = 100))]
-    #[schema(minimum = 1, maximum = 100)]
+    #[garde(custom(validate_per_page))]
+    #[schema(minimum = 1)]
     #[serde(default = "Pagination::default_per_page")]
     pub per_page: i64,
 }
@@ -73,8 +83,8 @@ pub struct PaginationParamsWithSearch {
     #[schema(minimum = 1)]
     #[serde(default = "Pagination::default_page")]
     pub page: i64,
-    #[garde(range(min = 1, max = 100))]
-    #[schema(minimum = 1, maximum = 100)]
+    #[garde(custom(validate_per_page))]
+    #[schema(minimum = 1)]
     #[serde(default = "Pagination::default_per_page")]
     pub per_page: i64,
     #[garde(length(chars, min = 1, max = 128))]
@@ -95,6 +105,20 @@ pub struct Pagination<T: Serialize = serde_json::Value> {
     pub data: Vec<T>,
 }
 
+static DEFAULT_PER_PAGE: AtomicI64 = AtomicI64::new(25);
+static MAX_PER_PAGE: AtomicI64 = AtomicI64::new(100);
+
+pub fn validate_per_page(per_page: &i64, _context: &()) -> Result<(), garde::Error> {
+    let max_per_page = Pagination::max_per_page();
+    if !(1..=max_per_page).contains(per_page) {
+        return Err(garde::Error::new(compact_str::format_compact!(
+            "per_page must be between 1 and {max_per_page}"
+        )));
+    }
+
+    Ok(())
+}
+
 impl Pagination {
     #[inline]
     pub const fn default_page() -> i64 {
@@ -102,8 +126,22 @@ impl Pagination {
     }
 
     #[inline]
-    pub const fn default_per_page() -> i64 {
-        25
+    pub fn default_per_page() -> i64 {
+        DEFAULT_PER_PAGE.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub fn max_per_page() -> i64 {
+        MAX_PER_PAGE.load(Ordering::Relaxed)
+    }
+
+    /// Updates the per_page limits used when deserializing and validating pagination params,
+    /// called whenever the pagination settings are (re)loaded.
+    pub fn set_per_page_limits(default_per_page: i64, max_per_page: i64) {
+        let max_per_page = max_per_page.max(1);
+
+        MAX_PER_PAGE.store(max_per_page, Ordering::Relaxed);
+        DEFAULT_PER_PAGE.store(default_per_page.clamp(1, max_per_page), Ordering::Relaxed);
     }
 }
 
@@ -454,6 +492,11 @@ pub trait DeletableModel: BaseModel + Send + Sync + 'static {
 
 #[async_trait::async_trait]
 pub trait ByUuid: BaseModel {
+    /// The table models are loaded from by [`ByUuid::by_uuids`].
+    const TABLE: &'static str;
+    /// The joins required by [`BaseModel::columns`] when loading from [`ByUuid::TABLE`].
+    const JOINS: &'static str = "";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -498,6 +541,81 @@ pub trait ByUuid: BaseModel {
         }
     }
 
+    /// Loads multiple models at once, uuids that do not exist are left out of the map.
+    async fn by_uuids(
+        database: &crate::database::Database,
+        uuids: &[uuid::Uuid],
+    ) -> Result<HashMap<uuid::Uuid, Self>, DatabaseError>
+    where
+        Self: Send,
+    {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM {table}
+            {}
+            WHERE {table}.uuid = ANY($1)
+            "#,
+            Self::columns_sql(None),
+            Self::JOINS,
+            table = Self::TABLE,
+        ))
+        .bind(uuids)
+        .fetch_all(database.read())
+        .await?;
+
+        rows.into_iter()
+            .map(|row| -> Result<_, DatabaseError> {
+                Ok((
+                    row.try_get::<uuid::Uuid, _>("uuid")?,
+                    Self::map(None, &row)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Cached variant of [`ByUuid::by_uuids`], sharing its cache entries with [`ByUuid::by_uuid_cached`].
+    /// Only the uuids missing from the cache are loaded from the database.
+    async fn by_uuids_cached(
+        database: &crate::database::Database,
+        uuids: &[uuid::Uuid],
+    ) -> Result<HashMap<uuid::Uuid, Self>, anyhow::Error>
+    where
+        Self: Send,
+    {
+        let mut models = HashMap::with_capacity(uuids.len());
+        let mut missing = Vec::new();
+
+        for uuid in uuids {
+            if models.contains_key(uuid) || missing.contains(uuid) {
+                continue;
+            }
+
+            match database
+                .cache
+                .get::<Self>(&format!("{}::{uuid}", Self::NAME))
+                .await?
+            {
+                Some(model) => {
+                    models.insert(*uuid, model);
+                }
+                None => missing.push(*uuid),
+            }
+        }
+
+        if !missing.is_empty() {
+            for (uuid, model) in Self::by_uuids(database, &missing).await? {
+                database
+                    .cache
+                    .set(&format!("{}::{uuid}", Self::NAME), 10, &model)
+                    .await?;
+                models.insert(uuid, model);
+            }
+        }
+
+        Ok(models)
+    }
+
     #[inline]
     fn get_fetchable(uuid: uuid::Uuid) -> Fetchable<Self> {
         Fetchable {
@@ -694,6 +812,35 @@ impl<M: ByUuid + Send> Fetchable<M> {
     ) -> Result<Option<M>, anyhow::Error> {
         M::by_uuid_optional_cached(database, self.uuid).await
     }
+
+    /// Loads all given fetchables at once, see [`ByUuid::by_uuids_cached`].
+    pub async fn fetch_many_cached<'a>(
+        database: &crate::database::Database,
+        fetchables: impl IntoIterator<Item = &'a Self>,
+    ) -> Result<HashMap<uuid::Uuid, M>, anyhow::Error>
+    where
+        M: 'a,
+    {
+        let uuids = fetchables
+            .into_iter()
+            .map(|fetchable| fetchable.uuid)
+            .collect::<Vec<_>>();
+        if uuids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        M::by_uuids_cached(database, &uuids).await
+    }
+}
+
+pub enum OnConflictAction<'a> {
+    /// `DO NOTHING`, a conflicting insert affects and returns no rows.
+    DoNothing,
+    /// `DO UPDATE SET`, overwrites the given columns with the values of the conflicting insert.
+    /// An empty list updates every inserted column that is not part of the conflict target,
+    /// if only conflict target columns were inserted the existing row is left as is.
+    /// Unlike `DoNothing`, a conflicting insert always returns the existing row.
+    DoUpdate(Vec<&'a str>),
 }
 
 pub struct InsertQueryBuilder<'a> {
@@ -701,6 +848,7 @@ pub struct InsertQueryBuilder<'a> {
     columns: Vec<&'a str>,
     expressions: Vec<String>,
     arguments: PgArguments,
+    on_conflict_clause: Option<(Vec<&'a str>, OnConflictAction<'a>)>,
     returning_clause: Option<&'a str>,
 }
 
@@ -711,6 +859,7 @@ impl<'a> InsertQueryBuilder<'a> {
             columns: Vec::new(),
             expressions: Vec::new(),
             arguments: PgArguments::default(),
+            on_conflict_clause: None,
             returning_clause: None,
         }
     }
@@ -765,6 +914,12 @@ impl<'a> InsertQueryBuilder<'a> {
         self
     }
 
+    /// Adds an `ON CONFLICT` clause on the given columns, which have to match a unique index of the table.
+    pub fn on_conflict(mut self, columns: &[&'a str], action: OnConflictAction<'a>) -> Self {
+        self.on_conflict_clause = Some((columns.to_vec(), action));
+        self
+    }
+
     pub fn returning(mut self, clause: &'a str) -> Self {
         self.returning_clause = Some(clause);
         self
@@ -779,6 +934,46 @@ impl<'a> InsertQueryBuilder<'a> {
             self.table, columns_sql, values_sql
         );
 
+        if let Some((conflict_columns, action)) = &self.on_conflict_clause {
+            sql.push_str(" ON CONFLICT (");
+            sql.push_str(&conflict_columns.join(", "));
+            sql.push(')');
+
+            let update_columns = match action {
+                OnConflictAction::DoNothing => Vec::new(),
+                OnConflictAction::DoUpdate(columns) if columns.is_empty() => {
+                    let columns: Vec<_> = self
+                        .columns
+                        .iter()
+                        .filter(|column| !conflict_columns.contains(column))
+                        .copied()
+                        .collect();
+
+                    // `DO NOTHING` would not return the conflicting row, so the
+                    // conflict target is "updated" to its own value instead
+                    if columns.is_empty() {
+                        conflict_columns.iter().take(1).copied().collect()
+                    } else {
+                        columns
+                    }
+                }
+                OnConflictAction::DoUpdate(columns) => columns.clone(),
+            };
+
+            if update_columns.is_empty() {
+                sql.push_str(" DO NOTHING");
+            } else {
+                sql.push_str(" DO UPDATE SET ");
+                sql.push_str(
+                    &update_columns
+                        .into_iter()
+                        .map(|column| format!("{column} = EXCLUDED.{column}"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                );
+            }
+        }
+
         if let Some(clause) = self.returning_clause {
             sql.push_str(" RETURNING ");
             sql.push_str(clause);
@@ -806,6 +1001,17 @@ impl<'a> InsertQueryBuilder<'a> {
             .fetch_one(executor)
             .await
     }
+
+    /// Like `fetch_one`, but returns `None` if a `DO NOTHING` conflict clause skipped the insert.
+    pub async fn fetch_optional(
+        self,
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+    ) -> Result<Option<sqlx::postgres::PgRow>, sqlx::Error> {
+        let sql = self.build_sql();
+        sqlx::query_with(&sql, self.arguments)
+            .fetch_optional(executor)
+            .await
+    }
 }
 
 pub struct UpdateQueryBuilder<'a> {
@@ -878,3 +1084,85 @@ impl<'a> UpdateQueryBuilder<'a> {
         query.execute(executor).await.map(|r| r.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_on_conflict_do_nothing() {
+        let mut builder = InsertQueryBuilder::new("server_mounts");
+        builder
+            .set("server_uuid", uuid::Uuid::nil())
+            .set("mount_uuid", uuid::Uuid::nil());
+        let builder = builder
+            .on_conflict(&["server_uuid", "mount_uuid"], OnConflictAction::DoNothing)
+            .returning("created");
+
+        assert_eq!(
+            builder.build_sql(),
+            "INSERT INTO server_mounts (server_uuid, mount_uuid) VALUES ($1, $2) \
+            ON CONFLICT (server_uuid, mount_uuid) DO NOTHING RETURNING created"
+        );
+    }
+
+    #[test]
+    fn insert_on_conflict_do_update() {
+        let mut builder = InsertQueryBuilder::new("node_allocations");
+        builder
+            .set("node_uuid", uuid::Uuid::nil())
+            .set("ip_alias", "alias")
+            .set("port", 25565i32)
+            .set("notes", "notes");
+        let builder = builder
+            .on_conflict(
+                &["node_uuid", "port"],
+                OnConflictAction::DoUpdate(vec!["ip_alias", "notes"]),
+            )
+            .returning("uuid");
+
+        assert_eq!(
+            builder.build_sql(),
+            "INSERT INTO node_allocations (node_uuid, ip_alias, port, notes) VALUES ($1, $2, $3, $4) \
+            ON CONFLICT (node_uuid, port) DO UPDATE SET ip_alias = EXCLUDED.ip_alias, notes = EXCLUDED.notes \
+            RETURNING uuid"
+        );
+    }
+
+    #[test]
+    fn insert_on_conflict_do_update_defaults_to_inserted_columns() {
+        let mut builder = InsertQueryBuilder::new("node_allocations");
+        builder
+            .set("node_uuid", uuid::Uuid::nil())
+            .set("ip_alias", "alias")
+            .set("port", 25565i32);
+        let builder = builder.on_conflict(
+            &["node_uuid", "port"],
+            OnConflictAction::DoUpdate(Vec::new()),
+        );
+
+        assert_eq!(
+            builder.build_sql(),
+            "INSERT INTO node_allocations (node_uuid, ip_alias, port) VALUES ($1, $2, $3) \
+            ON CONFLICT (node_uuid, port) DO UPDATE SET ip_alias = EXCLUDED.ip_alias"
+        );
+    }
+
+    #[test]
+    fn insert_on_conflict_do_update_without_columns_returns_existing_row() {
+        let mut builder = InsertQueryBuilder::new("server_mounts");
+        builder
+            .set("server_uuid", uuid::Uuid::nil())
+            .set("mount_uuid", uuid::Uuid::nil());
+        let builder = builder.on_conflict(
+            &["server_uuid", "mount_uuid"],
+            OnConflictAction::DoUpdate(Vec::new()),
+        );
+
+        assert_eq!(
+            builder.build_sql(),
+            "INSERT INTO server_mounts (server_uuid, mount_uuid) VALUES ($1, $2) \
+            ON CONFLICT (server_uuid, mount_uuid) DO UPDATE SET server_uuid = EXCLUDED.server_uuid"
+        );
+    }
+}
@@ -109,6 +109,31 @@ impl Mount {
         row.try_map(|row| Self::map(None, &row))
     }
 
+    pub async fn by_node_uuid_egg_uuid_name(
+        database: &crate::database::Database,
+        node_uuid: uuid::Uuid,
+        egg_uuid: uuid::Uuid,
+        name: &str,
+    ) -> Result<Option<Self>, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM mounts
+            JOIN node_mounts ON mounts.uuid = node_mounts.mount_uuid
+            JOIN nest_egg_mounts ON mounts.uuid = nest_egg_mounts.mount_uuid
+            WHERE node_mounts.node_uuid = $1 AND nest_egg_mounts.egg_uuid = $2 AND mounts.name = $3
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(node_uuid)
+        .bind(egg_uuid)
+        .bind(name)
+        .fetch_optional(database.read())
+        .await?;
+
+        row.try_map(|row| Self::map(None, &row))
+    }
+
     pub async fn all_with_pagination(
         database: &crate::database::Database,
         page: i64,
@@ -163,6 +188,8 @@ impl Mount {
 
 #[async_trait::async_trait]
 impl ByUuid for Mount {
+    const TABLE: &'static str = "mounts";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
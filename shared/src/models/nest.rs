@@ -133,6 +133,8 @@ impl Nest {
 
 #[async_trait::async_trait]
 impl ByUuid for Nest {
+    const TABLE: &'static str = "nests";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -44,6 +44,70 @@ pub fn validate_config_allocations(
     Ok(())
 }
 
+/// Environment variables wings passes to every server, these can be used in startup commands
+/// without being defined as egg variables.
+const STARTUP_BUILTIN_VARIABLES: &[&str] = &[
+    "STARTUP",
+    "SERVER_MEMORY",
+    "SERVER_IP",
+    "SERVER_PORT",
+    "TZ",
+    "P_SERVER_LOCATION",
+    "P_SERVER_UUID",
+    "P_SERVER_ALLOCATION_LIMIT",
+];
+
+/// Validates the `{{...}}` placeholders of a startup command, placeholders have to be balanced
+/// and reference either one of the given variables or a variable provided by wings.
+/// Dotted placeholders like `{{server.build.default.port}}` are left to wings.
+pub fn validate_startup_placeholders(
+    startup: &str,
+    env_variables: &[&str],
+) -> Result<(), garde::Error> {
+    let mut rest = startup;
+
+    while let Some(start) = rest.find("{{") {
+        if rest[..start].contains("}}") {
+            return Err(garde::Error::new(
+                "unbalanced placeholder, found `}}` without `{{`",
+            ));
+        }
+
+        let placeholder = &rest[start + 2..];
+        let Some(end) = placeholder.find("}}") else {
+            return Err(garde::Error::new(
+                "unbalanced placeholder, found `{{` without `}}`",
+            ));
+        };
+
+        let name = placeholder[..end].trim();
+        if name.is_empty() || name.contains("{{") {
+            return Err(garde::Error::new(
+                "invalid placeholder, expected a variable name",
+            ));
+        }
+
+        if !name.contains('.')
+            && !STARTUP_BUILTIN_VARIABLES.contains(&name)
+            && !env_variables.contains(&name)
+        {
+            return Err(garde::Error::new(compact_str::format_compact!(
+                "placeholder references undefined variable: {name}"
+            )));
+        }
+
+        rest = &placeholder[end + 2..];
+    }
+
+    if rest.contains("}}") {
+        return Err(garde::Error::new(
+            "unbalanced placeholder, found `}}` without `{{`",
+        ));
+    }
+
+    Ok(())
+}
+
 fn true_fn() -> bool {
     true
 }
@@ -150,6 +214,25 @@ pub struct NestEggConfigAllocations {
     #[schema(inline)]
     #[serde(default)]
     pub user_self_assign: NestEggConfigAllocationsUserSelfAssign,
+    #[serde(default)]
+    pub minimum: u16,
+}
+
+impl NestEggConfigAllocations {
+    /// Ensures a server holding `count` allocations still meets the egg's minimum.
+    /// Returns a `DisplayError` if it does not.
+    pub fn ensure_minimum(&self, count: usize) -> Result<(), anyhow::Error> {
+        if count < self.minimum as usize {
+            return Err(crate::response::DisplayError::new(format!(
+                "egg requires at least {} allocation(s), server would have {}",
+                self.minimum, count
+            ))
+            .with_status(reqwest::StatusCode::BAD_REQUEST)
+            .into());
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(ToSchema, Serialize, Deserialize, Clone)]
@@ -251,6 +334,79 @@ pub struct ExportedNestEgg {
     pub variables: Vec<super::nest_egg_variable::ExportedNestEggVariable>,
 }
 
+#[derive(ToSchema, Serialize)]
+pub struct NestEggUpdateFieldChange {
+    pub field: compact_str::CompactString,
+    pub current: serde_json::Value,
+    pub updated: serde_json::Value,
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct NestEggUpdateVariableChange {
+    pub env_variable: compact_str::CompactString,
+    #[schema(inline)]
+    pub changes: Vec<NestEggUpdateFieldChange>,
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct NestEggUpdateDiff {
+    #[schema(inline)]
+    pub changes: Vec<NestEggUpdateFieldChange>,
+
+    pub variables_added: Vec<super::nest_egg_variable::ExportedNestEggVariable>,
+    /// Variables missing from the update, these are kept but moved behind all updated variables.
+    pub variables_removed: Vec<super::nest_egg_variable::ExportedNestEggVariable>,
+    #[schema(inline)]
+    pub variables_changed: Vec<NestEggUpdateVariableChange>,
+}
+
+impl NestEggUpdateDiff {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+            && self.variables_added.is_empty()
+            && self.variables_removed.is_empty()
+            && self.variables_changed.is_empty()
+    }
+}
+
+fn diff_json_fields(
+    prefix: &str,
+    current: serde_json::Value,
+    updated: serde_json::Value,
+    skip: &[&str],
+    nested: &[&str],
+    changes: &mut Vec<NestEggUpdateFieldChange>,
+) {
+    let (serde_json::Value::Object(mut current), serde_json::Value::Object(updated)) =
+        (current, updated)
+    else {
+        return;
+    };
+
+    for (key, updated) in updated {
+        if skip.contains(&key.as_str()) {
+            continue;
+        }
+
+        let current = current.remove(&key).unwrap_or_default();
+        if current == updated {
+            continue;
+        }
+
+        let field = compact_str::format_compact!("{prefix}{key}");
+        if nested.contains(&key.as_str()) {
+            diff_json_fields(&format!("{field}."), current, updated, &[], &[], changes);
+        } else {
+            changes.push(NestEggUpdateFieldChange {
+                field,
+                current,
+                updated,
+            });
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct NestEgg {
     pub uuid: uuid::Uuid,
@@ -416,6 +572,58 @@ impl BaseModel for NestEgg {
 }
 
 impl NestEgg {
+    /// Validates a startup command against the variables of an egg, see [`validate_startup_placeholders`].
+    pub async fn validate_startup(
+        database: &crate::database::Database,
+        egg_uuid: uuid::Uuid,
+        startup: &str,
+    ) -> Result<(), crate::database::DatabaseError> {
+        let variables =
+            super::nest_egg_variable::NestEggVariable::all_by_egg_uuid(database, egg_uuid).await?;
+        let env_variables = variables
+            .iter()
+            .map(|variable| variable.env_variable.as_str())
+            .collect::<Vec<_>>();
+
+        if let Err(error) = validate_startup_placeholders(startup, &env_variables) {
+            let mut report = garde::Report::new();
+            report.append(garde::Path::new("startup"), error);
+
+            return Err(report.into());
+        }
+
+        Ok(())
+    }
+
+    /// Rejects docker images pulled from registries that are not in the configured allowlist.
+    pub async fn validate_docker_image_registries(
+        state: &crate::State,
+        docker_images: &IndexMap<compact_str::CompactString, compact_str::CompactString>,
+    ) -> Result<(), crate::database::DatabaseError> {
+        let settings = state.settings.server().await?;
+
+        for image in docker_images.values() {
+            if let Err(registry) = settings.validate_image_registry(image) {
+                let mut report = garde::Report::new();
+                report.append(
+                    garde::Path::new("docker_images"),
+                    garde::Error::new(compact_str::format_compact!(
+                        "images from registry {registry} are not allowed"
+                    )),
+                );
+
+                return Err(report.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn has_docker_image(&self, image: &str) -> bool {
+        self.docker_images.values().any(|i| i == image)
+    }
+
     pub async fn import(
         state: &crate::State,
         nest_uuid: uuid::Uuid,
@@ -472,6 +680,7 @@ impl NestEgg {
                     user_viewable: variable.user_viewable,
                     user_editable: variable.user_editable,
                     secret: variable.secret,
+                    resolve_references: variable.resolve_references,
                     rules: variable.rules,
                 },
             )
@@ -486,9 +695,13 @@ impl NestEgg {
 
     pub async fn import_update(
         &self,
-        database: &crate::database::Database,
+        state: &crate::State,
         exported_egg: ExportedNestEgg,
     ) -> Result<(), crate::database::DatabaseError> {
+        Self::validate_docker_image_registries(state, &exported_egg.docker_images).await?;
+
+        let database = &state.database;
+
         sqlx::query!(
             "UPDATE nest_eggs
             SET
@@ -559,9 +772,9 @@ impl NestEgg {
             if let Err(err) = sqlx::query!(
                 "INSERT INTO nest_egg_variables (  
                     egg_uuid, name, description, order_, env_variable,  
-                    default_value, user_viewable, user_editable, rules  
+                    default_value, user_viewable, user_editable, rules, resolve_references  
                 )  
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
                 ON CONFLICT (egg_uuid, env_variable) DO UPDATE SET
                     name = EXCLUDED.name,
                     description = EXCLUDED.description,
@@ -569,7 +782,8 @@ impl NestEgg {
                     default_value = EXCLUDED.default_value,
                     user_viewable = EXCLUDED.user_viewable,
                     user_editable = EXCLUDED.user_editable,
-                    rules = EXCLUDED.rules",
+                    rules = EXCLUDED.rules,
+                    resolve_references = EXCLUDED.resolve_references",
                 self.uuid,
                 &variable.name,
                 variable.description.as_deref(),
@@ -586,7 +800,8 @@ impl NestEgg {
                     .rules
                     .iter()
                     .map(|r| r.as_str())
-                    .collect::<Vec<_>>() as &[&str]
+                    .collect::<Vec<_>>() as &[&str],
+                variable.resolve_references
             )
             .execute(database.read())
             .await
@@ -620,6 +835,74 @@ impl NestEgg {
         Ok(())
     }
 
+    /// Computes what `import_update` would change on this egg, without applying anything.
+    pub async fn diff_update(
+        &self,
+        database: &crate::database::Database,
+        exported_egg: &ExportedNestEgg,
+    ) -> Result<NestEggUpdateDiff, crate::database::DatabaseError> {
+        let current_egg = self.clone().into_exported(database).await?;
+
+        let mut changes = Vec::new();
+        diff_json_fields(
+            "",
+            serde_json::to_value(&current_egg)?,
+            serde_json::to_value(exported_egg)?,
+            &["uuid", "variables"],
+            &["config", "scripts"],
+            &mut changes,
+        );
+
+        let mut current_variables = current_egg.variables;
+        let mut variables_added = Vec::new();
+        let mut variables_changed = Vec::new();
+
+        for (i, variable) in exported_egg.variables.iter().enumerate() {
+            if rule_validator::validate_rules(&variable.rules, &()).is_err() {
+                continue;
+            }
+
+            let mut variable = variable.clone();
+            if variable.order == 0 {
+                variable.order = i as i16 + 1;
+            }
+
+            let Some(position) = current_variables
+                .iter()
+                .position(|v| v.env_variable == variable.env_variable)
+            else {
+                variables_added.push(variable);
+                continue;
+            };
+
+            let current_variable = current_variables.remove(position);
+
+            let mut variable_changes = Vec::new();
+            diff_json_fields(
+                "",
+                serde_json::to_value(&current_variable)?,
+                serde_json::to_value(&variable)?,
+                &["env_variable", "secret"],
+                &[],
+                &mut variable_changes,
+            );
+
+            if !variable_changes.is_empty() {
+                variables_changed.push(NestEggUpdateVariableChange {
+                    env_variable: variable.env_variable,
+                    changes: variable_changes,
+                });
+            }
+        }
+
+        Ok(NestEggUpdateDiff {
+            changes,
+            variables_added,
+            variables_removed: current_variables,
+            variables_changed,
+        })
+    }
+
     pub async fn by_nest_uuid_with_pagination(
         database: &crate::database::Database,
         nest_uuid: uuid::Uuid,
@@ -866,6 +1149,8 @@ impl NestEgg {
 
 #[async_trait::async_trait]
 impl ByUuid for NestEgg {
+    const TABLE: &'static str = "nest_eggs";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -949,6 +1234,8 @@ impl CreatableModel for NestEgg {
     ) -> Result<Self, crate::database::DatabaseError> {
         options.validate()?;
 
+        Self::validate_docker_image_registries(state, &options.docker_images).await?;
+
         if let Some(egg_repository_egg_uuid) = options.egg_repository_egg_uuid {
             super::egg_repository_egg::EggRepositoryEgg::by_uuid_optional_cached(
                 &state.database,
@@ -1078,6 +1365,14 @@ impl UpdatableModel for NestEgg {
     ) -> Result<(), crate::database::DatabaseError> {
         options.validate()?;
 
+        if let Some(startup) = &options.startup {
+            Self::validate_startup(&state.database, self.uuid, startup).await?;
+        }
+
+        if let Some(docker_images) = &options.docker_images {
+            Self::validate_docker_image_registries(state, docker_images).await?;
+        }
+
         let egg_repository_egg =
             if let Some(egg_repository_egg_uuid) = &options.egg_repository_egg_uuid {
                 match egg_repository_egg_uuid {
@@ -1316,3 +1611,50 @@ pub struct ApiNestEgg {
 
     pub created: chrono::DateTime<chrono::Utc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_defined_startup_placeholders() {
+        for startup in [
+            "java -jar server.jar",
+            "java -Xmx{{SERVER_MEMORY}}M -jar {{SERVER_JARFILE}}",
+            "./server --port {{ SERVER_PORT }} --jar {{SERVER_JARFILE}}",
+            "./server --port {{server.build.default.port}}",
+        ] {
+            assert!(
+                validate_startup_placeholders(startup, &["SERVER_JARFILE"]).is_ok(),
+                "{startup} should be valid"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_undefined_startup_placeholders() {
+        let error =
+            validate_startup_placeholders("./server {{MISSING}}", &["SERVER_JARFILE"]).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("placeholder references undefined variable: MISSING")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_startup_placeholders() {
+        for startup in [
+            "./server {{SERVER_PORT",
+            "./server SERVER_PORT}}",
+            "./server }}{{SERVER_PORT}}",
+            "./server {{}}",
+            "./server {{ {{SERVER_PORT}}",
+        ] {
+            assert!(
+                validate_startup_placeholders(startup, &[]).is_err(),
+                "{startup} should be invalid"
+            );
+        }
+    }
+}
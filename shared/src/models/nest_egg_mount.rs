@@ -237,8 +237,12 @@ impl CreatableModel for NestEggMount {
 
         transaction.commit().await?;
 
-        match Self::by_egg_uuid_mount_uuid(&state.database, options.egg_uuid, options.mount_uuid)
-            .await?
+        match Self::by_egg_uuid_mount_uuid(
+            &state.database.primary(),
+            options.egg_uuid,
+            options.mount_uuid,
+        )
+        .await?
         {
             Some(nest_egg_mount) => Ok(nest_egg_mount),
             None => Err(sqlx::Error::RowNotFound.into()),
@@ -0,0 +1,154 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// Resource alert thresholds applying to every server of an egg,
+/// a server's own [`super::server_resource_alert::ServerResourceAlert`] takes precedence.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NestEggResourceAlert {
+    pub egg_uuid: uuid::Uuid,
+
+    pub cpu_threshold: Option<i16>,
+    pub memory_threshold: Option<i16>,
+    pub window_seconds: i32,
+
+    pub created: chrono::NaiveDateTime,
+}
+
+impl BaseModel for NestEggResourceAlert {
+    const NAME: &'static str = "nest_egg_resource_alert";
+
+    #[inline]
+    fn columns(prefix: Option<&str>) -> BTreeMap<&'static str, compact_str::CompactString> {
+        let prefix = prefix.unwrap_or_default();
+
+        BTreeMap::from([
+            (
+                "nest_egg_resource_alerts.egg_uuid",
+                compact_str::format_compact!("{prefix}egg_uuid"),
+            ),
+            (
+                "nest_egg_resource_alerts.cpu_threshold",
+                compact_str::format_compact!("{prefix}cpu_threshold"),
+            ),
+            (
+                "nest_egg_resource_alerts.memory_threshold",
+                compact_str::format_compact!("{prefix}memory_threshold"),
+            ),
+            (
+                "nest_egg_resource_alerts.window_seconds",
+                compact_str::format_compact!("{prefix}window_seconds"),
+            ),
+            (
+                "nest_egg_resource_alerts.created",
+                compact_str::format_compact!("{prefix}created"),
+            ),
+        ])
+    }
+
+    #[inline]
+    fn map(prefix: Option<&str>, row: &PgRow) -> Result<Self, crate::database::DatabaseError> {
+        let prefix = prefix.unwrap_or_default();
+
+        Ok(Self {
+            egg_uuid: row.try_get(compact_str::format_compact!("{prefix}egg_uuid").as_str())?,
+            cpu_threshold: row
+                .try_get(compact_str::format_compact!("{prefix}cpu_threshold").as_str())?,
+            memory_threshold: row
+                .try_get(compact_str::format_compact!("{prefix}memory_threshold").as_str())?,
+            window_seconds: row
+                .try_get(compact_str::format_compact!("{prefix}window_seconds").as_str())?,
+            created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
+        })
+    }
+}
+
+impl NestEggResourceAlert {
+    pub async fn by_egg_uuid(
+        database: &crate::database::Database,
+        egg_uuid: uuid::Uuid,
+    ) -> Result<Option<Self>, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM nest_egg_resource_alerts
+            WHERE nest_egg_resource_alerts.egg_uuid = $1
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(egg_uuid)
+        .fetch_optional(database.read())
+        .await?;
+
+        row.try_map(|row| Self::map(None, &row))
+    }
+
+    pub async fn upsert(
+        database: &crate::database::Database,
+        egg_uuid: uuid::Uuid,
+        cpu_threshold: Option<i16>,
+        memory_threshold: Option<i16>,
+        window_seconds: i32,
+    ) -> Result<Self, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO nest_egg_resource_alerts (egg_uuid, cpu_threshold, memory_threshold, window_seconds)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (egg_uuid) DO UPDATE SET
+                cpu_threshold = EXCLUDED.cpu_threshold,
+                memory_threshold = EXCLUDED.memory_threshold,
+                window_seconds = EXCLUDED.window_seconds
+            RETURNING {}
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(egg_uuid)
+        .bind(cpu_threshold)
+        .bind(memory_threshold)
+        .bind(window_seconds)
+        .fetch_one(database.write())
+        .await?;
+
+        Self::map(None, &row)
+    }
+
+    pub async fn delete_by_egg_uuid(
+        database: &crate::database::Database,
+        egg_uuid: uuid::Uuid,
+    ) -> Result<bool, crate::database::DatabaseError> {
+        let deleted = sqlx::query(
+            r#"
+            DELETE FROM nest_egg_resource_alerts
+            WHERE nest_egg_resource_alerts.egg_uuid = $1
+            "#,
+        )
+        .bind(egg_uuid)
+        .execute(database.write())
+        .await?
+        .rows_affected();
+
+        Ok(deleted > 0)
+    }
+
+    #[inline]
+    pub fn into_admin_api_object(self) -> AdminApiNestEggResourceAlert {
+        AdminApiNestEggResourceAlert {
+            cpu_threshold: self.cpu_threshold,
+            memory_threshold: self.memory_threshold,
+            window_seconds: self.window_seconds,
+            created: self.created.and_utc(),
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+#[schema(title = "NestEggResourceAlert")]
+pub struct AdminApiNestEggResourceAlert {
+    pub cpu_threshold: Option<i16>,
+    pub memory_threshold: Option<i16>,
+    pub window_seconds: i32,
+
+    pub created: chrono::DateTime<chrono::Utc>,
+}
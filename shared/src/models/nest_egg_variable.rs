@@ -6,7 +6,7 @@ use garde::Validate;
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, postgres::PgRow};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashMap},
     sync::{Arc, LazyLock},
 };
 use utoipa::ToSchema;
@@ -42,6 +42,9 @@ pub struct ExportedNestEggVariable {
     #[serde(default)]
     pub secret: bool,
     #[garde(skip)]
+    #[serde(default)]
+    pub resolve_references: bool,
+    #[garde(skip)]
     #[serde(
         default,
         deserialize_with = "crate::deserialize::deserialize_nest_egg_variable_rules"
@@ -62,6 +65,7 @@ pub struct NestEggVariable {
     pub user_viewable: bool,
     pub user_editable: bool,
     pub secret: bool,
+    pub resolve_references: bool,
     pub rules: Vec<compact_str::CompactString>,
 
     pub created: chrono::NaiveDateTime,
@@ -111,6 +115,10 @@ impl BaseModel for NestEggVariable {
                 "nest_egg_variables.secret",
                 compact_str::format_compact!("{prefix}secret"),
             ),
+            (
+                "nest_egg_variables.resolve_references",
+                compact_str::format_compact!("{prefix}resolve_references"),
+            ),
             (
                 "nest_egg_variables.rules",
                 compact_str::format_compact!("{prefix}rules"),
@@ -141,6 +149,8 @@ impl BaseModel for NestEggVariable {
             user_editable: row
                 .try_get(compact_str::format_compact!("{prefix}user_editable").as_str())?,
             secret: row.try_get(compact_str::format_compact!("{prefix}secret").as_str())?,
+            resolve_references: row
+                .try_get(compact_str::format_compact!("{prefix}resolve_references").as_str())?,
             rules: row.try_get(compact_str::format_compact!("{prefix}rules").as_str())?,
             created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
         })
@@ -169,6 +179,52 @@ impl NestEggVariable {
         row.try_map(|row| Self::map(None, &row))
     }
 
+    /// Validates the variable values of a new server against the rules of the egg's variables,
+    /// variables without a value are validated with their default value.
+    /// Returns the values by variable uuid, values of unknown variables are ignored.
+    pub fn validate_server_values(
+        variables: &[Self],
+        values: &[(&str, &str)],
+    ) -> Result<HashMap<uuid::Uuid, compact_str::CompactString>, String> {
+        let mut validator_variables = HashMap::new();
+        validator_variables.reserve(variables.len());
+
+        for variable in variables {
+            validator_variables.insert(
+                variable.env_variable.as_str(),
+                (
+                    variable.rules.as_slice(),
+                    if let Some((_, value)) = values
+                        .iter()
+                        .find(|(env_variable, _)| variable.env_variable == *env_variable)
+                    {
+                        *value
+                    } else {
+                        variable.default_value.as_ref().map_or("", |v| v.as_str())
+                    },
+                ),
+            );
+        }
+
+        let validator = rule_validator::Validator::new(validator_variables)
+            .map_err(|error| error.to_string())?;
+        validator.validate()?;
+
+        let mut server_variables = HashMap::new();
+        server_variables.reserve(variables.len());
+
+        for (env_variable, value) in values {
+            let variable_uuid = match variables.iter().find(|v| v.env_variable == *env_variable) {
+                Some(variable) => variable.uuid,
+                None => continue,
+            };
+
+            server_variables.insert(variable_uuid, (*value).into());
+        }
+
+        Ok(server_variables)
+    }
+
     pub async fn all_by_egg_uuid(
         database: &crate::database::Database,
         egg_uuid: uuid::Uuid,
@@ -191,6 +247,222 @@ impl NestEggVariable {
             .try_collect_vec()
     }
 
+    /// Imports exported variables onto an egg within a single transaction.
+    /// Variables sharing an `env_variable` with an existing one update it in place (keeping server values),
+    /// all others are created. With `replace`, existing variables missing from the import are deleted.
+    pub async fn import_all(
+        state: &crate::State,
+        egg_uuid: uuid::Uuid,
+        variables: Vec<ExportedNestEggVariable>,
+        replace: bool,
+    ) -> Result<Vec<Self>, anyhow::Error> {
+        let mut seen_env_variables = std::collections::HashSet::new();
+        let mut create_options = Vec::with_capacity(variables.len());
+
+        for variable in variables {
+            if !seen_env_variables.insert(variable.env_variable.clone()) {
+                return Err(crate::response::DisplayError::new(format!(
+                    "duplicate variable {} in import",
+                    variable.env_variable
+                ))
+                .into());
+            }
+
+            let options = CreateNestEggVariableOptions {
+                egg_uuid,
+                name: variable.name,
+                description: variable.description,
+                order: variable.order,
+                env_variable: variable.env_variable,
+                default_value: variable.default_value,
+                user_viewable: variable.user_viewable,
+                user_editable: variable.user_editable,
+                secret: variable.secret,
+                resolve_references: variable.resolve_references,
+                rules: variable.rules,
+            };
+            options
+                .validate()
+                .map_err(crate::database::DatabaseError::Validation)?;
+
+            create_options.push(options);
+        }
+
+        let mut existing_variables = Self::all_by_egg_uuid(&state.database, egg_uuid).await?;
+
+        let mut transaction = state.database.write().begin().await?;
+
+        for mut options in create_options {
+            match existing_variables
+                .iter_mut()
+                .find(|v| v.env_variable == options.env_variable)
+            {
+                Some(existing_variable) => {
+                    let mut update_options = UpdateNestEggVariableOptions {
+                        name: Some(options.name),
+                        description: Some(options.description),
+                        order: Some(options.order),
+                        env_variable: None,
+                        default_value: Some(options.default_value),
+                        user_viewable: Some(options.user_viewable),
+                        user_editable: Some(options.user_editable),
+                        secret: Some(options.secret),
+                        resolve_references: Some(options.resolve_references),
+                        rules: Some(options.rules),
+                    };
+
+                    let mut query_builder = UpdateQueryBuilder::new("nest_egg_variables");
+
+                    Self::run_update_handlers(
+                        existing_variable,
+                        &mut update_options,
+                        &mut query_builder,
+                        state,
+                        &mut transaction,
+                    )
+                    .await?;
+
+                    query_builder
+                        .set("name", update_options.name.as_ref())
+                        .set(
+                            "description",
+                            update_options.description.as_ref().map(|d| d.as_ref()),
+                        )
+                        .set("order_", update_options.order)
+                        .set(
+                            "default_value",
+                            update_options.default_value.as_ref().map(|d| d.as_ref()),
+                        )
+                        .set("user_viewable", update_options.user_viewable)
+                        .set("user_editable", update_options.user_editable)
+                        .set("secret", update_options.secret)
+                        .set("resolve_references", update_options.resolve_references)
+                        .set("rules", update_options.rules.as_ref())
+                        .where_eq("uuid", existing_variable.uuid);
+
+                    query_builder.execute(&mut *transaction).await?;
+                }
+                None => {
+                    Self::insert_in_transaction(state, &mut options, &mut transaction).await?;
+                }
+            }
+        }
+
+        if replace {
+            for existing_variable in existing_variables
+                .iter()
+                .filter(|v| !seen_env_variables.contains(&v.env_variable))
+            {
+                existing_variable
+                    .run_delete_handlers(&(), state, &mut transaction)
+                    .await?;
+
+                sqlx::query(
+                    r#"
+                    DELETE FROM nest_egg_variables
+                    WHERE nest_egg_variables.uuid = $1
+                    "#,
+                )
+                .bind(existing_variable.uuid)
+                .execute(&mut *transaction)
+                .await?;
+            }
+        }
+
+        transaction.commit().await?;
+
+        Ok(Self::all_by_egg_uuid(&state.database.primary(), egg_uuid).await?)
+    }
+
+    /// Creates multiple variables in a single transaction, every item either results
+    /// in the created variable or the reason it could not be created.
+    pub async fn create_bulk(
+        state: &crate::State,
+        variables: Vec<CreateNestEggVariableOptions>,
+    ) -> Result<Vec<Result<Self, compact_str::CompactString>>, anyhow::Error> {
+        let mut seen_names = std::collections::HashSet::new();
+        let mut seen_env_variables = std::collections::HashSet::new();
+        let mut results = Vec::with_capacity(variables.len());
+
+        let mut transaction = state.database.write().begin().await?;
+
+        for mut options in variables {
+            if let Err(errors) = options.validate() {
+                results.push(Err(crate::utils::flatten_validation_errors(&errors)
+                    .join(", ")
+                    .into()));
+                continue;
+            }
+
+            if !seen_names.insert(options.name.clone())
+                || !seen_env_variables.insert(options.env_variable.clone())
+            {
+                results.push(Err(compact_str::format_compact!(
+                    "duplicate variable {} in request",
+                    options.env_variable
+                )));
+                continue;
+            }
+
+            // every item runs in its own savepoint so a failing item does not abort the others
+            let mut savepoint = transaction.begin().await?;
+
+            match Self::insert_in_transaction(state, &mut options, &mut savepoint).await {
+                Ok(variable) => {
+                    savepoint.commit().await?;
+                    results.push(Ok(variable));
+                }
+                Err(err) if err.is_unique_violation() => {
+                    savepoint.rollback().await?;
+                    results.push(Err(compact_str::format_compact!(
+                        "variable with name or env_variable {} already exists",
+                        options.env_variable
+                    )));
+                }
+                Err(crate::database::DatabaseError::Sqlx(err)) => return Err(err.into()),
+                Err(err) => {
+                    savepoint.rollback().await?;
+                    results.push(Err(err.to_string().into()));
+                }
+            }
+        }
+
+        transaction.commit().await?;
+
+        Ok(results)
+    }
+
+    /// Runs the create handlers and inserts the variable within the given transaction.
+    async fn insert_in_transaction(
+        state: &crate::State,
+        options: &mut CreateNestEggVariableOptions,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Self, crate::database::DatabaseError> {
+        let mut query_builder = InsertQueryBuilder::new("nest_egg_variables");
+
+        Self::run_create_handlers(options, &mut query_builder, state, transaction).await?;
+
+        query_builder
+            .set("egg_uuid", options.egg_uuid)
+            .set("name", &options.name)
+            .set("description", &options.description)
+            .set("order_", options.order)
+            .set("env_variable", &options.env_variable)
+            .set("default_value", &options.default_value)
+            .set("user_viewable", options.user_viewable)
+            .set("user_editable", options.user_editable)
+            .set("secret", options.secret)
+            .set("resolve_references", options.resolve_references)
+            .set("rules", &options.rules);
+
+        let row = query_builder
+            .returning(&Self::columns_sql(None))
+            .fetch_one(&mut **transaction)
+            .await?;
+
+        Self::map(None, &row)
+    }
+
     #[inline]
     pub fn into_exported(self) -> ExportedNestEggVariable {
         ExportedNestEggVariable {
@@ -202,6 +474,7 @@ impl NestEggVariable {
             user_viewable: self.user_viewable,
             user_editable: self.user_editable,
             secret: self.secret,
+            resolve_references: self.resolve_references,
             rules: self.rules,
         }
     }
@@ -218,6 +491,7 @@ impl NestEggVariable {
             user_viewable: self.user_viewable,
             user_editable: self.user_editable,
             is_secret: self.secret,
+            resolve_references: self.resolve_references,
             rules: self.rules,
             created: self.created.and_utc(),
         }
@@ -240,8 +514,8 @@ pub struct CreateNestEggVariableOptions {
     #[garde(skip)]
     pub order: i16,
 
-    #[garde(length(chars, min = 1, max = 255))]
-    #[schema(min_length = 1, max_length = 255)]
+    #[garde(length(chars, min = 1, max = 255), pattern("^[A-Za-z_][A-Za-z0-9_]*$"))]
+    #[schema(min_length = 1, max_length = 255, pattern = "^[A-Za-z_][A-Za-z0-9_]*$")]
     pub env_variable: compact_str::CompactString,
 
     #[garde(length(max = 1024))]
@@ -254,6 +528,8 @@ pub struct CreateNestEggVariableOptions {
     pub user_editable: bool,
     #[garde(skip)]
     pub secret: bool,
+    #[garde(skip)]
+    pub resolve_references: bool,
 
     #[garde(custom(rule_validator::validate_rules))]
     pub rules: Vec<compact_str::CompactString>,
@@ -279,28 +555,8 @@ impl CreatableModel for NestEggVariable {
 
         let mut transaction = state.database.write().begin().await?;
 
-        let mut query_builder = InsertQueryBuilder::new("nest_egg_variables");
-
-        Self::run_create_handlers(&mut options, &mut query_builder, state, &mut transaction)
-            .await?;
-
-        query_builder
-            .set("egg_uuid", options.egg_uuid)
-            .set("name", &options.name)
-            .set("description", &options.description)
-            .set("order_", options.order)
-            .set("env_variable", &options.env_variable)
-            .set("default_value", &options.default_value)
-            .set("user_viewable", options.user_viewable)
-            .set("user_editable", options.user_editable)
-            .set("secret", options.secret)
-            .set("rules", &options.rules);
-
-        let row = query_builder
-            .returning(&Self::columns_sql(None))
-            .fetch_one(&mut *transaction)
-            .await?;
-        let nest_egg_variable = Self::map(None, &row)?;
+        let nest_egg_variable =
+            Self::insert_in_transaction(state, &mut options, &mut transaction).await?;
 
         transaction.commit().await?;
 
@@ -326,8 +582,8 @@ pub struct UpdateNestEggVariableOptions {
     #[garde(skip)]
     pub order: Option<i16>,
 
-    #[garde(length(chars, min = 1, max = 255))]
-    #[schema(min_length = 1, max_length = 255)]
+    #[garde(length(chars, min = 1, max = 255), pattern("^[A-Za-z_][A-Za-z0-9_]*$"))]
+    #[schema(min_length = 1, max_length = 255, pattern = "^[A-Za-z_][A-Za-z0-9_]*$")]
     pub env_variable: Option<compact_str::CompactString>,
 
     #[garde(length(max = 1024))]
@@ -345,6 +601,8 @@ pub struct UpdateNestEggVariableOptions {
     pub user_editable: Option<bool>,
     #[garde(skip)]
     pub secret: Option<bool>,
+    #[garde(skip)]
+    pub resolve_references: Option<bool>,
 
     #[garde(inner(custom(rule_validator::validate_rules)))]
     pub rules: Option<Vec<compact_str::CompactString>>,
@@ -396,6 +654,7 @@ impl UpdatableModel for NestEggVariable {
             .set("user_viewable", options.user_viewable)
             .set("user_editable", options.user_editable)
             .set("secret", options.secret)
+            .set("resolve_references", options.resolve_references)
             .set("rules", options.rules.as_ref())
             .where_eq("uuid", self.uuid);
 
@@ -425,6 +684,9 @@ impl UpdatableModel for NestEggVariable {
         if let Some(secret) = options.secret {
             self.secret = secret;
         }
+        if let Some(resolve_references) = options.resolve_references {
+            self.resolve_references = resolve_references;
+        }
         if let Some(rules) = options.rules {
             self.rules = rules;
         }
@@ -486,6 +748,7 @@ pub struct AdminApiNestEggVariable {
     pub user_viewable: bool,
     pub user_editable: bool,
     pub is_secret: bool,
+    pub resolve_references: bool,
     pub rules: Vec<compact_str::CompactString>,
 
     pub created: chrono::DateTime<chrono::Utc>,
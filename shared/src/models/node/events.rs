@@ -12,6 +12,14 @@ pub enum NodeEvent {
     /// Emitted when wings restarts, the base panel uses this to fail backups and transfers in progress.
     /// Importantly, the base panel does not use this for failing server installations, wings tries hard to resume those on restart.
     StateReset { node: Box<super::Node> },
+    /// Emitted when a node failed several consecutive health checks.
+    Disconnected { node: Box<super::Node> },
+    /// Emitted when an unreachable node passed several consecutive health checks again.
+    /// `started_servers` contains the servers that were started again because of `auto_start_on_reconnect`.
+    Reconnected {
+        node: Box<super::Node>,
+        started_servers: Vec<uuid::Uuid>,
+    },
 }
 
 #[async_trait::async_trait]
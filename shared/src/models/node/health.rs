@@ -0,0 +1,324 @@
+use crate::{
+    models::{CreatableModel, EventEmittingModel},
+    prelude::*,
+};
+use futures_util::StreamExt;
+use rustis::commands::{SetExpiration, StringCommands};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The amount of consecutive failed checks before a node is considered offline.
+const OFFLINE_THRESHOLD: u32 = 2;
+/// The amount of consecutive successful checks before an offline node is considered reconnected.
+const RECONNECT_THRESHOLD: u32 = 2;
+/// How long the health of a node is kept in redis, entries of deleted nodes simply expire.
+const HEALTH_CACHE_SECONDS: u64 = 60 * 60;
+
+/// The reachability of a node, along with the servers that were running on it when it was last reachable.
+/// Stored in redis directly, bypassing the local cache, so every panel instance shares the same view of a node.
+#[derive(Default, Serialize, Deserialize)]
+struct NodeHealth {
+    offline: bool,
+    failures: u32,
+    successes: u32,
+    running_servers: HashSet<uuid::Uuid>,
+}
+
+impl NodeHealth {
+    async fn load(cache: &crate::cache::Cache, key: &str) -> Result<Self, anyhow::Error> {
+        let health: Option<String> = cache.client.get(cache.key(key)).await?;
+
+        Ok(match health {
+            Some(health) => serde_json::from_str(&health)?,
+            None => Self::default(),
+        })
+    }
+
+    async fn store(&self, cache: &crate::cache::Cache, key: &str) -> Result<(), anyhow::Error> {
+        cache
+            .client
+            .set_with_options(
+                cache.key(key),
+                serde_json::to_string(self)?,
+                None,
+                SetExpiration::Ex(HEALTH_CACHE_SECONDS),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[inline]
+fn is_running(state: &wings_api::ServerState) -> bool {
+    matches!(
+        state,
+        wings_api::ServerState::Running | wings_api::ServerState::Starting
+    )
+}
+
+impl super::Node {
+    /// Checks the reachability of all nodes concurrently, see [`Self::check_health`].
+    /// A failing check is logged and does not affect the checks of other nodes.
+    pub async fn check_health_all(state: &crate::State) -> Result<(), anyhow::Error> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM nodes
+            JOIN locations ON locations.uuid = nodes.location_uuid
+            "#,
+            Self::columns_sql(None)
+        ))
+        .fetch_all(state.database.read())
+        .await?;
+
+        let nodes = rows
+            .into_iter()
+            .map(|row| Self::map(None, &row))
+            .try_collect_vec()?;
+
+        let mut results_stream =
+            futures_util::stream::iter(nodes.into_iter().map(|node| async move {
+                let node_uuid = node.uuid;
+
+                (node_uuid, node.check_health(state).await)
+            }))
+            .buffer_unordered(16);
+        while let Some((node_uuid, result)) = results_stream.next().await {
+            if let Err(err) = result {
+                tracing::error!(node = %node_uuid, "failed to check node health: {:?}", err);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the reachability of a node. A node is only marked offline or reconnected after
+    /// several consecutive checks agree, so a flapping node does not repeatedly start servers.
+    /// Once a node reconnects, servers with `auto_start_on_reconnect` that were running before the
+    /// outage and are no longer running are started again.
+    async fn check_health(self, state: &crate::State) -> Result<(), anyhow::Error> {
+        let health_key = format!("node::{}::health", self.uuid);
+
+        // another panel instance is already checking this node
+        let Ok(_lock) = state
+            .cache
+            .lock(health_key.clone(), Some(30), Some(1))
+            .await
+        else {
+            return Ok(());
+        };
+
+        let mut health = NodeHealth::load(&state.cache, &health_key).await?;
+
+        let resources = match self.fetch_server_resources(&state.database).await {
+            Ok(resources) => resources,
+            Err(err) => {
+                health.successes = 0;
+                health.failures += 1;
+
+                let disconnected = !health.offline && health.failures >= OFFLINE_THRESHOLD;
+                if disconnected {
+                    health.offline = true;
+                }
+
+                health.store(&state.cache, &health_key).await?;
+
+                if disconnected {
+                    tracing::warn!(node = %self.uuid, "node is unreachable: {:?}", err);
+
+                    Self::get_event_emitter().emit(
+                        state.clone(),
+                        super::NodeEvent::Disconnected {
+                            node: Box::new(self),
+                        },
+                    );
+                }
+
+                return Ok(());
+            }
+        };
+
+        let running_servers = resources
+            .iter()
+            .filter(|(_, usage)| is_running(&usage.state))
+            .map(|(uuid, _)| *uuid)
+            .collect::<HashSet<_>>();
+
+        health.failures = 0;
+
+        let mut previously_running = None;
+        if !health.offline {
+            health.running_servers = running_servers;
+        } else {
+            health.successes += 1;
+
+            if health.successes >= RECONNECT_THRESHOLD {
+                health.offline = false;
+                health.successes = 0;
+
+                previously_running = Some(std::mem::replace(
+                    &mut health.running_servers,
+                    running_servers,
+                ));
+            }
+        }
+
+        health.store(&state.cache, &health_key).await?;
+
+        let Some(previously_running) = previously_running else {
+            return Ok(());
+        };
+
+        tracing::info!(node = %self.uuid, "node reconnected");
+
+        let started_servers = if self.maintenance_enabled {
+            Vec::new()
+        } else {
+            match self
+                .auto_start_servers(
+                    state,
+                    previously_running
+                        .into_iter()
+                        .filter(|uuid| {
+                            !resources
+                                .get(uuid)
+                                .is_some_and(|usage| is_running(&usage.state))
+                        })
+                        .collect(),
+                )
+                .await
+            {
+                Ok(started_servers) => started_servers,
+                Err(err) => {
+                    tracing::error!(
+                        node = %self.uuid,
+                        "failed to auto-start servers after node reconnect: {:?}",
+                        err
+                    );
+
+                    Vec::new()
+                }
+            }
+        };
+
+        Self::get_event_emitter().emit(
+            state.clone(),
+            super::NodeEvent::Reconnected {
+                node: Box::new(self),
+                started_servers,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Starts the given servers that have `auto_start_on_reconnect` enabled, returns the started servers.
+    async fn auto_start_servers(
+        &self,
+        state: &crate::State,
+        server_uuids: Vec<uuid::Uuid>,
+    ) -> Result<Vec<uuid::Uuid>, anyhow::Error> {
+        if server_uuids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let server_uuids: Vec<uuid::Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT servers.uuid
+            FROM servers
+            WHERE servers.node_uuid = $1
+                AND servers.uuid = ANY($2)
+                AND servers.auto_start_on_reconnect
+                AND NOT servers.suspended
+                AND servers.status IS NULL
+            "#,
+        )
+        .bind(self.uuid)
+        .bind(&server_uuids)
+        .fetch_all(state.database.read())
+        .await?;
+
+        let mut started_servers = Vec::new();
+        started_servers.reserve_exact(server_uuids.len());
+
+        let api_client = self.api_client(&state.database).await?;
+
+        for server_uuid in server_uuids {
+            let server =
+                match crate::models::server::Server::by_uuid_optional(&state.database, server_uuid)
+                    .await
+                {
+                    Ok(Some(server)) => server,
+                    Ok(None) => continue,
+                    Err(err) => {
+                        tracing::warn!(
+                            node = %self.uuid,
+                            server = %server_uuid,
+                            "failed to fetch server for auto-start: {:?}",
+                            err
+                        );
+
+                        continue;
+                    }
+                };
+
+            if let Err(err) = api_client
+                .post_servers_server_power(
+                    server.uuid,
+                    &wings_api::servers_server_power::post::RequestBody {
+                        action: wings_api::ServerPowerAction::Start,
+                        wait_seconds: None,
+                    },
+                )
+                .await
+            {
+                tracing::warn!(
+                    node = %self.uuid,
+                    server = %server.uuid,
+                    "failed to auto-start server after node reconnect: {:?}",
+                    err
+                );
+
+                continue;
+            }
+
+            tracing::info!(
+                node = %self.uuid,
+                server = %server.uuid,
+                "auto-started server after node reconnect"
+            );
+
+            crate::models::server::Server::invalidate_power_state(&state.database, server.uuid)
+                .await;
+            server.relay_power_state_in_background(state).await;
+
+            let options = crate::models::server_activity::CreateServerActivityOptions {
+                server_uuid: server.uuid,
+                user_uuid: None,
+                impersonator_uuid: None,
+                api_key_uuid: None,
+                schedule_uuid: None,
+                event: "server:power.auto-start".into(),
+                ip: None,
+                data: serde_json::json!({
+                    "reason": "node_reconnect",
+                }),
+                created: None,
+            };
+            if let Err(err) =
+                crate::models::server_activity::ServerActivity::create(state, options).await
+            {
+                tracing::warn!(
+                    server = %server.uuid,
+                    "failed to log server auto-start activity: {:?}",
+                    err
+                );
+            }
+
+            started_servers.push(server.uuid);
+        }
+
+        Ok(started_servers)
+    }
+}
@@ -16,6 +16,7 @@ use std::{
 use utoipa::ToSchema;
 
 mod events;
+mod health;
 pub use events::NodeEvent;
 
 pub type GetNode = crate::extract::ConsumingExtension<Node>;
@@ -31,6 +32,7 @@ pub struct Node {
 
     pub deployment_enabled: bool,
     pub maintenance_enabled: bool,
+    pub deployment_weight: i16,
 
     pub public_url: Option<reqwest::Url>,
     pub url: reqwest::Url,
@@ -40,6 +42,8 @@ pub struct Node {
     pub memory: i64,
     pub disk: i64,
 
+    pub server_count: i32,
+
     pub token_id: compact_str::CompactString,
     pub token: Vec<u8>,
 
@@ -72,6 +76,10 @@ impl BaseModel for Node {
                 "nodes.maintenance_enabled",
                 compact_str::format_compact!("{prefix}maintenance_enabled"),
             ),
+            (
+                "nodes.deployment_weight",
+                compact_str::format_compact!("{prefix}deployment_weight"),
+            ),
             (
                 "nodes.public_url",
                 compact_str::format_compact!("{prefix}public_url"),
@@ -90,6 +98,10 @@ impl BaseModel for Node {
                 compact_str::format_compact!("{prefix}memory"),
             ),
             ("nodes.disk", compact_str::format_compact!("{prefix}disk")),
+            (
+                "nodes.server_count",
+                compact_str::format_compact!("{prefix}server_count"),
+            ),
             (
                 "nodes.token_id",
                 compact_str::format_compact!("{prefix}token_id"),
@@ -125,6 +137,8 @@ impl BaseModel for Node {
                 .try_get(compact_str::format_compact!("{prefix}deployment_enabled").as_str())?,
             maintenance_enabled: row
                 .try_get(compact_str::format_compact!("{prefix}maintenance_enabled").as_str())?,
+            deployment_weight: row
+                .try_get(compact_str::format_compact!("{prefix}deployment_weight").as_str())?,
             public_url: row
                 .try_get::<Option<String>, _>(
                     compact_str::format_compact!("{prefix}public_url").as_str(),
@@ -139,6 +153,8 @@ impl BaseModel for Node {
             sftp_port: row.try_get(compact_str::format_compact!("{prefix}sftp_port").as_str())?,
             memory: row.try_get(compact_str::format_compact!("{prefix}memory").as_str())?,
             disk: row.try_get(compact_str::format_compact!("{prefix}disk").as_str())?,
+            server_count: row
+                .try_get(compact_str::format_compact!("{prefix}server_count").as_str())?,
             token_id: row.try_get(compact_str::format_compact!("{prefix}token_id").as_str())?,
             token: row.try_get(compact_str::format_compact!("{prefix}token").as_str())?,
             created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
@@ -304,6 +320,58 @@ impl Node {
         })
     }
 
+    /// Returns all nodes that can receive a new server with the given resources,
+    /// ordered from least to most loaded, with the load divided by each node's
+    /// `deployment_weight` so heavier nodes are preferred. When `tags` is not empty,
+    /// only nodes carrying every one of the given tags are considered.
+    pub async fn all_deployable(
+        database: &crate::database::Database,
+        location_uuids: &[uuid::Uuid],
+        tags: &[compact_str::CompactString],
+        memory: i64,
+        disk: i64,
+    ) -> Result<Vec<Self>, crate::database::DatabaseError> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM nodes
+            JOIN locations ON locations.uuid = nodes.location_uuid
+            LEFT JOIN (
+                SELECT servers.node_uuid, SUM(servers.memory) AS memory, SUM(servers.disk) AS disk
+                FROM servers
+                GROUP BY servers.node_uuid
+            ) usage ON usage.node_uuid = nodes.uuid
+            WHERE nodes.deployment_enabled AND NOT nodes.maintenance_enabled
+                AND (cardinality($1::uuid[]) = 0 OR nodes.location_uuid = ANY($1))
+                AND (
+                    cardinality($2::text[]) = 0
+                    OR (
+                        SELECT COUNT(*)
+                        FROM node_tags
+                        WHERE node_tags.node_uuid = nodes.uuid AND node_tags.name = ANY($2)
+                    ) = cardinality($2)
+                )
+                AND COALESCE(usage.memory, 0) + $3 <= nodes.memory
+                AND COALESCE(usage.disk, 0) + $4 <= nodes.disk
+            ORDER BY GREATEST(
+                (COALESCE(usage.memory, 0) + $3)::float8 / GREATEST(nodes.memory, 1),
+                (COALESCE(usage.disk, 0) + $4)::float8 / GREATEST(nodes.disk, 1)
+            ) / GREATEST(nodes.deployment_weight, 1), nodes.created
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(location_uuids)
+        .bind(tags)
+        .bind(memory)
+        .bind(disk)
+        .fetch_all(database.read())
+        .await?;
+
+        rows.into_iter()
+            .map(|row| Self::map(None, &row))
+            .try_collect_vec()
+    }
+
     pub async fn count_by_location_uuid(
         database: &crate::database::Database,
         location_uuid: uuid::Uuid,
@@ -342,6 +410,31 @@ impl Node {
             .await
     }
 
+    /// Fetch the current system resource usage of this node.
+    ///
+    /// Cached for 15 seconds.
+    pub async fn fetch_system_stats(
+        &self,
+        database: &crate::database::Database,
+    ) -> Result<wings_api::SystemStats, anyhow::Error> {
+        database
+            .cache
+            .cached(
+                &format!("node::{}::system_stats", self.uuid),
+                15,
+                || async {
+                    Ok::<_, anyhow::Error>(
+                        self.api_client(database)
+                            .await?
+                            .get_system_stats()
+                            .await?
+                            .stats,
+                    )
+                },
+            )
+            .await
+    }
+
     /// Fetch the current resource usages of all servers on this node.
     ///
     /// Cached for 15 seconds.
@@ -367,6 +460,48 @@ impl Node {
             .await
     }
 
+    /// Sums the resource limits of all servers on this node,
+    /// servers without a limit (0) are counted separately.
+    ///
+    /// Cached for 15 seconds.
+    pub async fn fetch_allocated_resources(
+        &self,
+        database: &crate::database::Database,
+    ) -> Result<NodeAllocatedResources, anyhow::Error> {
+        database
+            .cache
+            .cached(
+                &format!("node::{}::allocated_resources", self.uuid),
+                15,
+                || async {
+                    let row = sqlx::query(
+                        r#"
+                        SELECT
+                            COUNT(*) AS servers,
+                            COALESCE(SUM(servers.memory), 0)::int8 AS memory,
+                            COUNT(*) FILTER (WHERE servers.memory = 0) AS unlimited_memory_servers,
+                            COALESCE(SUM(servers.disk), 0)::int8 AS disk,
+                            COUNT(*) FILTER (WHERE servers.disk = 0) AS unlimited_disk_servers
+                        FROM servers
+                        WHERE servers.node_uuid = $1
+                        "#,
+                    )
+                    .bind(self.uuid)
+                    .fetch_one(database.read())
+                    .await?;
+
+                    Ok::<_, anyhow::Error>(NodeAllocatedResources {
+                        servers: row.try_get("servers")?,
+                        memory: row.try_get("memory")?,
+                        unlimited_memory_servers: row.try_get("unlimited_memory_servers")?,
+                        disk: row.try_get("disk")?,
+                        unlimited_disk_servers: row.try_get("unlimited_disk_servers")?,
+                    })
+                },
+            )
+            .await
+    }
+
     pub async fn reset_token(
         &self,
         state: &crate::State,
@@ -409,10 +544,15 @@ impl Node {
         &self,
         database: &crate::database::Database,
     ) -> Result<wings_api::client::WingsClient, anyhow::Error> {
-        Ok(wings_api::client::WingsClient::new(
+        let client = wings_api::client::WingsClient::new(
             self.url.to_string(),
             database.decrypt(self.token.to_vec()).await?.into(),
-        ))
+        );
+
+        Ok(match database.wings_semaphore(self.uuid).await {
+            Some(semaphore) => client.with_semaphore(semaphore),
+            None => client,
+        })
     }
 
     #[inline]
@@ -459,12 +599,14 @@ impl Node {
             description: self.description,
             deployment_enabled: self.deployment_enabled,
             maintenance_enabled: self.maintenance_enabled,
+            deployment_weight: self.deployment_weight,
             public_url: self.public_url.map(|url| url.to_string()),
             url: self.url.to_string(),
             sftp_host: self.sftp_host,
             sftp_port: self.sftp_port,
             memory: self.memory,
             disk: self.disk,
+            server_count: self.server_count,
             token_id: self.token_id,
             token: database.decrypt(self.token).await?,
             created: self.created.and_utc(),
@@ -474,6 +616,11 @@ impl Node {
 
 #[async_trait::async_trait]
 impl ByUuid for Node {
+    const TABLE: &'static str = "nodes";
+    const JOINS: &'static str = r#"
+        JOIN locations ON locations.uuid = nodes.location_uuid
+    "#;
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -496,6 +643,10 @@ impl ByUuid for Node {
     }
 }
 
+fn default_deployment_weight() -> i16 {
+    100
+}
+
 #[derive(ToSchema, Deserialize, Validate)]
 pub struct CreateNodeOptions {
     #[garde(skip)]
@@ -512,6 +663,10 @@ pub struct CreateNodeOptions {
     pub deployment_enabled: bool,
     #[garde(skip)]
     pub maintenance_enabled: bool,
+    #[garde(range(min = 1, max = 1000))]
+    #[schema(minimum = 1, maximum = 1000)]
+    #[serde(default = "default_deployment_weight")]
+    pub deployment_weight: i16,
     #[garde(length(chars, min = 3, max = 255), url)]
     #[schema(min_length = 3, max_length = 255, format = "uri")]
     pub public_url: Option<compact_str::CompactString>,
@@ -581,6 +736,7 @@ impl CreatableModel for Node {
             .set("description", &options.description)
             .set("deployment_enabled", options.deployment_enabled)
             .set("maintenance_enabled", options.maintenance_enabled)
+            .set("deployment_weight", options.deployment_weight)
             .set("public_url", &options.public_url)
             .set("url", &options.url)
             .set("sftp_host", &options.sftp_host)
@@ -598,7 +754,7 @@ impl CreatableModel for Node {
 
         transaction.commit().await?;
 
-        Self::by_uuid(&state.database, uuid).await
+        Self::by_uuid(&state.database.primary(), uuid).await
     }
 }
 
@@ -628,6 +784,9 @@ pub struct UpdateNodeOptions {
     pub deployment_enabled: Option<bool>,
     #[garde(skip)]
     pub maintenance_enabled: Option<bool>,
+    #[garde(range(min = 1, max = 1000))]
+    #[schema(minimum = 1, maximum = 1000)]
+    pub deployment_weight: Option<i16>,
     #[garde(length(chars, min = 3, max = 255), url)]
     #[schema(min_length = 3, max_length = 255, format = "uri")]
     #[serde(
@@ -711,6 +870,27 @@ impl UpdatableModel for Node {
 
         let mut transaction = state.database.write().begin().await?;
 
+        if let Some(location) = &location
+            && location.uuid != self.location.uuid
+        {
+            // move the server counter of this node over to its new location
+            sqlx::query(
+                r#"
+                UPDATE locations
+                SET server_count = locations.server_count + CASE
+                    WHEN locations.uuid = $2 THEN nodes.server_count
+                    ELSE -nodes.server_count
+                END
+                FROM nodes
+                WHERE nodes.uuid = $1 AND locations.uuid IN (nodes.location_uuid, $2)
+                "#,
+            )
+            .bind(self.uuid)
+            .bind(location.uuid)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
         let mut query_builder = UpdateQueryBuilder::new("nodes");
 
         Self::run_update_handlers(
@@ -738,6 +918,7 @@ impl UpdatableModel for Node {
             )
             .set("deployment_enabled", options.deployment_enabled)
             .set("maintenance_enabled", options.maintenance_enabled)
+            .set("deployment_weight", options.deployment_weight)
             .set(
                 "public_url",
                 options.public_url.as_ref().map(|u| u.as_ref()),
@@ -769,6 +950,9 @@ impl UpdatableModel for Node {
         if let Some(maintenance_enabled) = options.maintenance_enabled {
             self.maintenance_enabled = maintenance_enabled;
         }
+        if let Some(deployment_weight) = options.deployment_weight {
+            self.deployment_weight = deployment_weight;
+        }
         if let Some(public_url) = options.public_url {
             self.public_url = public_url
                 .try_map(|url| url.parse())
@@ -833,6 +1017,15 @@ impl DeletableModel for Node {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct NodeAllocatedResources {
+    pub servers: i64,
+    pub memory: i64,
+    pub unlimited_memory_servers: i64,
+    pub disk: i64,
+    pub unlimited_disk_servers: i64,
+}
+
 #[derive(ToSchema, Serialize)]
 #[schema(title = "Node")]
 pub struct AdminApiNode {
@@ -845,6 +1038,7 @@ pub struct AdminApiNode {
 
     pub deployment_enabled: bool,
     pub maintenance_enabled: bool,
+    pub deployment_weight: i16,
 
     #[schema(format = "uri")]
     pub public_url: Option<String>,
@@ -856,6 +1050,8 @@ pub struct AdminApiNode {
     pub memory: i64,
     pub disk: i64,
 
+    pub server_count: i32,
+
     pub token_id: compact_str::CompactString,
     pub token: compact_str::CompactString,
 
@@ -90,6 +90,23 @@ impl NodeAllocation {
         Ok(())
     }
 
+    /// Checks whether an allocation alias resolves to at least one address via DNS.
+    pub async fn ip_alias_resolves(ip_alias: &str) -> bool {
+        if ip_alias.parse::<std::net::IpAddr>().is_ok() {
+            return true;
+        }
+
+        match tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            tokio::net::lookup_host((ip_alias, 0)),
+        )
+        .await
+        {
+            Ok(Ok(mut addresses)) => addresses.next().is_some(),
+            _ => false,
+        }
+    }
+
     pub async fn get_random(
         database: &crate::database::Database,
         node_uuid: uuid::Uuid,
@@ -163,6 +180,34 @@ impl NodeAllocation {
         row.try_map(|row| Self::map(None, &row))
     }
 
+    /// Finds an allocation of the node by its address that is not assigned to any server.
+    pub async fn available_by_node_uuid_ip_port(
+        database: &crate::database::Database,
+        node_uuid: uuid::Uuid,
+        ip: &str,
+        port: i32,
+    ) -> Result<Option<Self>, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM node_allocations
+            LEFT JOIN server_allocations ON server_allocations.allocation_uuid = node_allocations.uuid
+            WHERE node_allocations.node_uuid = $1
+                AND host(node_allocations.ip) = $2
+                AND node_allocations.port = $3
+                AND server_allocations.uuid IS NULL
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(node_uuid)
+        .bind(ip)
+        .bind(port)
+        .fetch_optional(database.read())
+        .await?;
+
+        row.try_map(|row| Self::map(None, &row))
+    }
+
     pub async fn available_by_node_uuid_with_pagination(
         database: &crate::database::Database,
         node_uuid: uuid::Uuid,
@@ -269,7 +314,7 @@ impl NodeAllocation {
     pub async fn into_admin_api_object(
         self,
         database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
+        storage_url_retriever: &StorageUrlRetriever,
     ) -> Result<AdminApiNodeAllocation, crate::database::DatabaseError> {
         let server = match self.server {
             Some(fetchable) => Some(
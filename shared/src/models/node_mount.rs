@@ -234,8 +234,12 @@ impl CreatableModel for NodeMount {
 
         transaction.commit().await?;
 
-        match Self::by_node_uuid_mount_uuid(&state.database, options.node_uuid, options.mount_uuid)
-            .await?
+        match Self::by_node_uuid_mount_uuid(
+            &state.database.primary(),
+            options.node_uuid,
+            options.mount_uuid,
+        )
+        .await?
         {
             Some(node_mount) => Ok(node_mount),
             None => Err(sqlx::Error::RowNotFound.into()),
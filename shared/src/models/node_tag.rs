@@ -0,0 +1,226 @@
+use crate::{models::InsertQueryBuilder, prelude::*};
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, LazyLock},
+};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize)]
+pub struct NodeTag {
+    pub node: Fetchable<super::node::Node>,
+
+    pub name: compact_str::CompactString,
+
+    pub created: chrono::NaiveDateTime,
+}
+
+impl BaseModel for NodeTag {
+    const NAME: &'static str = "node_tag";
+
+    #[inline]
+    fn columns(prefix: Option<&str>) -> BTreeMap<&'static str, compact_str::CompactString> {
+        let prefix = prefix.unwrap_or_default();
+
+        BTreeMap::from([
+            (
+                "node_tags.node_uuid",
+                compact_str::format_compact!("{prefix}node_uuid"),
+            ),
+            (
+                "node_tags.name",
+                compact_str::format_compact!("{prefix}name"),
+            ),
+            (
+                "node_tags.created",
+                compact_str::format_compact!("{prefix}created"),
+            ),
+        ])
+    }
+
+    #[inline]
+    fn map(prefix: Option<&str>, row: &PgRow) -> Result<Self, crate::database::DatabaseError> {
+        let prefix = prefix.unwrap_or_default();
+
+        Ok(Self {
+            node: super::node::Node::get_fetchable(
+                row.try_get(compact_str::format_compact!("{prefix}node_uuid").as_str())?,
+            ),
+            name: row.try_get(compact_str::format_compact!("{prefix}name").as_str())?,
+            created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
+        })
+    }
+}
+
+impl NodeTag {
+    /// Normalizes a tag name the way it is stored, trimmed and lowercased.
+    #[inline]
+    pub fn normalize_name(name: &str) -> compact_str::CompactString {
+        name.trim().to_lowercase().into()
+    }
+
+    /// Normalizes a list of tag names, dropping empty and duplicate ones.
+    pub fn normalize_names(
+        names: &[compact_str::CompactString],
+    ) -> Vec<compact_str::CompactString> {
+        let mut names: Vec<_> = names
+            .iter()
+            .map(|name| Self::normalize_name(name))
+            .filter(|name| !name.is_empty())
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+
+        names
+    }
+
+    pub async fn by_node_uuid_name(
+        database: &crate::database::Database,
+        node_uuid: uuid::Uuid,
+        name: &str,
+    ) -> Result<Option<Self>, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM node_tags
+            WHERE node_tags.node_uuid = $1 AND node_tags.name = $2
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(node_uuid)
+        .bind(name)
+        .fetch_optional(database.read())
+        .await?;
+
+        row.try_map(|row| Self::map(None, &row))
+    }
+
+    pub async fn all_by_node_uuid(
+        database: &crate::database::Database,
+        node_uuid: uuid::Uuid,
+    ) -> Result<Vec<Self>, crate::database::DatabaseError> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM node_tags
+            WHERE node_tags.node_uuid = $1
+            ORDER BY node_tags.name
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(node_uuid)
+        .fetch_all(database.read())
+        .await?;
+
+        rows.into_iter()
+            .map(|row| Self::map(None, &row))
+            .try_collect_vec()
+    }
+
+    #[inline]
+    pub fn into_admin_api_object(self) -> AdminApiNodeTag {
+        AdminApiNodeTag {
+            name: self.name,
+            created: self.created.and_utc(),
+        }
+    }
+}
+
+#[derive(ToSchema, Deserialize, Validate)]
+pub struct CreateNodeTagOptions {
+    #[garde(skip)]
+    pub node_uuid: uuid::Uuid,
+    #[garde(length(chars, min = 1, max = 31), pattern("^[a-z0-9][a-z0-9_-]*$"))]
+    #[schema(min_length = 1, max_length = 31, pattern = "^[a-z0-9][a-z0-9_-]*$")]
+    pub name: compact_str::CompactString,
+}
+
+#[async_trait::async_trait]
+impl CreatableModel for NodeTag {
+    type CreateOptions<'a> = CreateNodeTagOptions;
+    type CreateResult = Self;
+
+    fn get_create_handlers() -> &'static LazyLock<CreateListenerList<Self>> {
+        static CREATE_LISTENERS: LazyLock<CreateListenerList<NodeTag>> =
+            LazyLock::new(|| Arc::new(ModelHandlerList::default()));
+
+        &CREATE_LISTENERS
+    }
+
+    async fn create(
+        state: &crate::State,
+        mut options: Self::CreateOptions<'_>,
+    ) -> Result<Self, crate::database::DatabaseError> {
+        options.validate()?;
+
+        let mut transaction = state.database.write().begin().await?;
+
+        let mut query_builder = InsertQueryBuilder::new("node_tags");
+
+        Self::run_create_handlers(&mut options, &mut query_builder, state, &mut transaction)
+            .await?;
+
+        query_builder
+            .set("node_uuid", options.node_uuid)
+            .set("name", &options.name);
+
+        query_builder.execute(&mut *transaction).await?;
+
+        transaction.commit().await?;
+
+        match Self::by_node_uuid_name(&state.database.primary(), options.node_uuid, &options.name)
+            .await?
+        {
+            Some(node_tag) => Ok(node_tag),
+            None => Err(sqlx::Error::RowNotFound.into()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DeletableModel for NodeTag {
+    type DeleteOptions = ();
+
+    fn get_delete_handlers() -> &'static LazyLock<DeleteListenerList<Self>> {
+        static DELETE_LISTENERS: LazyLock<DeleteListenerList<NodeTag>> =
+            LazyLock::new(|| Arc::new(ModelHandlerList::default()));
+
+        &DELETE_LISTENERS
+    }
+
+    async fn delete(
+        &self,
+        state: &crate::State,
+        options: Self::DeleteOptions,
+    ) -> Result<(), anyhow::Error> {
+        let mut transaction = state.database.write().begin().await?;
+
+        self.run_delete_handlers(&options, state, &mut transaction)
+            .await?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM node_tags
+            WHERE node_tags.node_uuid = $1 AND node_tags.name = $2
+            "#,
+        )
+        .bind(self.node.uuid)
+        .bind(&self.name)
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+#[schema(title = "AdminNodeTag")]
+pub struct AdminApiNodeTag {
+    pub name: compact_str::CompactString,
+
+    pub created: chrono::DateTime<chrono::Utc>,
+}
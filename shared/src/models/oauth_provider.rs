@@ -371,6 +371,8 @@ impl OAuthProvider {
 
 #[async_trait::async_trait]
 impl ByUuid for OAuthProvider {
+    const TABLE: &'static str = "oauth_providers";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -134,6 +134,8 @@ impl Role {
 
 #[async_trait::async_trait]
 impl ByUuid for Role {
+    const TABLE: &'static str = "roles";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -29,6 +29,21 @@ pub enum ServerEvent {
     /// Emitted when a server's state is reset manually (such as via an API call).
     /// If you handle this you might also want to handle `NodeEvent::StateReset` as well. (Called when the node restarts)
     StateReset { server: Box<super::Server> },
+    /// Emitted when a server exceeded one of its resource alert thresholds for the configured window.
+    ResourceAlert {
+        server: Box<super::Server>,
+        resource: crate::models::server_resource_alert::ResourceAlertResource,
+        usage: f64,
+        threshold: i16,
+    },
+    /// Emitted when the power state of a server changes, as reported by its node after a
+    /// power action initiated by the panel or when a different state was observed otherwise.
+    /// `from` is `None` if the previous state is not known.
+    PowerStateChanged {
+        server: Box<super::Server>,
+        from: Option<wings_api::ServerState>,
+        to: wings_api::ServerState,
+    },
 }
 
 #[async_trait::async_trait]
@@ -0,0 +1,260 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::LazyLock};
+use tokio::sync::{RwLock, broadcast};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[schema(rename_all = "snake_case")]
+pub enum InstallProgressStage {
+    PullingImage,
+    RunningScript,
+    Completed,
+    Failed,
+}
+
+impl InstallProgressStage {
+    #[inline]
+    pub fn is_final(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+pub struct InstallProgress {
+    pub stage: InstallProgressStage,
+    /// Progress of the current stage in percent, if known.
+    pub progress: Option<u8>,
+    pub message: Option<compact_str::CompactString>,
+}
+
+impl InstallProgress {
+    #[inline]
+    pub fn new(stage: InstallProgressStage) -> Self {
+        Self {
+            stage,
+            progress: None,
+            message: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_message(mut self, message: impl Into<compact_str::CompactString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+struct InstallProgressChannel {
+    last: Option<InstallProgress>,
+    sender: broadcast::Sender<InstallProgress>,
+    relaying: bool,
+}
+
+impl InstallProgressChannel {
+    fn new() -> Self {
+        Self {
+            last: None,
+            sender: broadcast::channel(16).0,
+            relaying: false,
+        }
+    }
+}
+
+/// Install progress is relayed in memory only, every panel instance relays the wings
+/// websocket events of the installations its own subscribers are watching.
+static INSTALL_PROGRESS: LazyLock<RwLock<HashMap<uuid::Uuid, InstallProgressChannel>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Maps an install related wings websocket event to install progress.
+fn map_websocket_message(message: &wings_api::WebsocketMessage) -> Option<InstallProgress> {
+    use wings_api::WebsocketEvent;
+
+    match message.event {
+        WebsocketEvent::InstallStarted => {
+            Some(InstallProgress::new(InstallProgressStage::PullingImage))
+        }
+        WebsocketEvent::ImagePullProgress => {
+            #[derive(Deserialize)]
+            struct ImagePullProgress {
+                progress: u64,
+                total: u64,
+            }
+
+            let pull: ImagePullProgress = serde_json::from_str(message.args.get(1)?).ok()?;
+
+            Some(InstallProgress {
+                stage: InstallProgressStage::PullingImage,
+                progress: (pull.total > 0)
+                    .then(|| (pull.progress.min(pull.total) * 100 / pull.total) as u8),
+                message: None,
+            })
+        }
+        WebsocketEvent::ImagePullCompleted => {
+            Some(InstallProgress::new(InstallProgressStage::RunningScript))
+        }
+        WebsocketEvent::InstallOutput => Some(
+            InstallProgress::new(InstallProgressStage::RunningScript)
+                .with_message(message.args.first()?.chars().take(255).collect::<String>()),
+        ),
+        WebsocketEvent::InstallCompleted => Some(InstallProgress::new(
+            if message
+                .args
+                .first()
+                .is_some_and(|successful| successful == "true")
+            {
+                InstallProgressStage::Completed
+            } else {
+                InstallProgressStage::Failed
+            },
+        )),
+        _ => None,
+    }
+}
+
+#[inline]
+async fn has_install_progress_subscribers(server_uuid: uuid::Uuid) -> bool {
+    INSTALL_PROGRESS
+        .read()
+        .await
+        .get(&server_uuid)
+        .is_some_and(|channel| channel.sender.receiver_count() > 0)
+}
+
+impl super::Server {
+    /// Publishes an install progress update to all subscribers of the server.
+    /// The last update is kept, so late subscribers immediately receive the current stage.
+    /// The channel is dropped once a final stage was sent, later subscribers rely on the server status.
+    pub async fn publish_install_progress(server_uuid: uuid::Uuid, progress: InstallProgress) {
+        let mut channels = INSTALL_PROGRESS.write().await;
+
+        if progress.stage.is_final() {
+            if let Some(channel) = channels.remove(&server_uuid) {
+                let _ = channel.sender.send(progress);
+            }
+
+            return;
+        }
+
+        let channel = channels
+            .entry(server_uuid)
+            .or_insert_with(InstallProgressChannel::new);
+
+        channel.last = Some(progress.clone());
+        let _ = channel.sender.send(progress);
+    }
+
+    /// Subscribes to install progress updates of a server, returning the last known update alongside the receiver.
+    ///
+    /// The server status should be read after subscribing, a final stage published before that is not replayed.
+    pub async fn subscribe_install_progress(
+        server_uuid: uuid::Uuid,
+    ) -> (
+        Option<InstallProgress>,
+        broadcast::Receiver<InstallProgress>,
+    ) {
+        let mut channels = INSTALL_PROGRESS.write().await;
+
+        // drop channels of installations that never published and lost all their subscribers
+        channels.retain(|_, channel| {
+            channel.relaying || channel.last.is_some() || channel.sender.receiver_count() > 0
+        });
+
+        let channel = channels
+            .entry(server_uuid)
+            .or_insert_with(InstallProgressChannel::new);
+
+        (channel.last.clone(), channel.sender.subscribe())
+    }
+
+    /// Starts relaying the install events of the wings websocket into the progress channel of the server,
+    /// unless this instance is relaying them already.
+    pub async fn relay_install_progress_in_background(&self, state: &crate::State) {
+        let mut channels = INSTALL_PROGRESS.write().await;
+        let channel = channels
+            .entry(self.uuid)
+            .or_insert_with(InstallProgressChannel::new);
+
+        if channel.relaying {
+            return;
+        }
+        channel.relaying = true;
+
+        let state = state.clone();
+        let server = self.clone();
+        tokio::spawn(async move {
+            if let Err(err) = server.relay_install_progress(&state).await {
+                tracing::warn!(server = %server.uuid, "failed to relay install progress: {:?}", err);
+            }
+
+            if let Some(channel) = INSTALL_PROGRESS.write().await.get_mut(&server.uuid) {
+                channel.relaying = false;
+            }
+        });
+    }
+
+    /// Clears the progress of a previous installation, should be called when a new installation starts.
+    pub async fn reset_install_progress(server_uuid: uuid::Uuid) {
+        let mut channels = INSTALL_PROGRESS.write().await;
+
+        if let Some(channel) = channels.get_mut(&server_uuid) {
+            if channel.sender.receiver_count() == 0 && !channel.relaying {
+                channels.remove(&server_uuid);
+            } else {
+                channel.last = None;
+            }
+        }
+    }
+
+    /// Connects to the wings websocket of the server and publishes its install events,
+    /// until the installation finished or nobody on this instance is subscribed anymore.
+    async fn relay_install_progress(&self, state: &crate::State) -> Result<(), anyhow::Error> {
+        let mut websocket = self
+            .connect_wings_websocket(state, &["websocket.connect", "admin.websocket.install"])
+            .await?;
+
+        loop {
+            let message = match tokio::time::timeout(
+                std::time::Duration::from_secs(15),
+                websocket.next_message(),
+            )
+            .await
+            {
+                Ok(Ok(Some(message))) => message,
+                Ok(Ok(None)) => break,
+                Ok(Err(err)) => {
+                    websocket.close().await;
+                    return Err(err);
+                }
+                Err(_) => {
+                    if has_install_progress_subscribers(self.uuid).await {
+                        continue;
+                    }
+
+                    break;
+                }
+            };
+
+            if let Some(power_state) = super::power_state::map_websocket_message(&message) {
+                self.store_power_state(state, power_state).await;
+            }
+
+            if let Some(progress) = map_websocket_message(&message) {
+                let is_final = progress.stage.is_final();
+                Self::publish_install_progress(self.uuid, progress).await;
+
+                if is_final {
+                    break;
+                }
+            }
+
+            if !has_install_progress_subscribers(self.uuid).await {
+                break;
+            }
+        }
+
+        websocket.close().await;
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
 use compact_str::ToCompactString;
 use garde::Validate;
 use indexmap::IndexMap;
+use rustis::commands::{SetExpiration, StringCommands};
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, postgres::PgRow, prelude::Type};
 use std::{
@@ -17,7 +18,11 @@ use std::{
 use utoipa::ToSchema;
 
 mod events;
+mod install_progress;
+mod power_state;
+mod wings_websocket;
 pub use events::ServerEvent;
+pub use install_progress::{InstallProgress, InstallProgressStage};
 
 pub type GetServer = crate::extract::ConsumingExtension<Server>;
 pub type GetServerActivityLogger = crate::extract::ConsumingExtension<ServerActivityLogger>;
@@ -119,6 +124,89 @@ pub struct ServerTransferOptions {
     pub multiplex_channels: u64,
 }
 
+#[derive(ToSchema, Validate, Serialize, Deserialize)]
+pub struct ExportedServerEgg {
+    #[garde(length(chars, min = 1, max = 255))]
+    #[schema(min_length = 1, max_length = 255)]
+    pub nest: compact_str::CompactString,
+    #[garde(length(chars, min = 1, max = 255))]
+    #[schema(min_length = 1, max_length = 255)]
+    pub name: compact_str::CompactString,
+    #[garde(skip)]
+    pub author: compact_str::CompactString,
+}
+
+#[derive(ToSchema, Validate, Serialize, Deserialize)]
+pub struct ExportedServerVariable {
+    #[garde(length(chars, min = 1, max = 255))]
+    #[schema(min_length = 1, max_length = 255)]
+    pub env_variable: compact_str::CompactString,
+    #[garde(length(max = 4096))]
+    #[schema(max_length = 4096)]
+    pub value: String,
+}
+
+#[derive(ToSchema, Validate, Serialize, Deserialize)]
+pub struct ExportedServerAllocation {
+    #[garde(skip)]
+    pub ip: compact_str::CompactString,
+    #[garde(skip)]
+    pub ip_alias: Option<compact_str::CompactString>,
+    #[garde(skip)]
+    pub port: i32,
+    #[garde(skip)]
+    pub notes: Option<compact_str::CompactString>,
+    #[garde(skip)]
+    pub primary: bool,
+}
+
+/// A portable definition of a server's configuration, without any of its data.
+/// Secret variable values are never part of an export.
+#[derive(ToSchema, Validate, Serialize, Deserialize)]
+pub struct ExportedServer {
+    #[garde(length(chars, min = 3, max = 255))]
+    #[schema(min_length = 3, max_length = 255)]
+    pub name: compact_str::CompactString,
+    #[garde(length(chars, min = 1, max = 1024))]
+    #[schema(min_length = 1, max_length = 1024)]
+    pub description: Option<compact_str::CompactString>,
+
+    #[garde(dive)]
+    pub egg: ExportedServerEgg,
+
+    #[garde(dive)]
+    pub limits: AdminApiServerLimits,
+    #[garde(inner(range(min = 0)))]
+    pub pinned_cpus: Vec<i16>,
+    #[garde(dive)]
+    pub feature_limits: ApiServerFeatureLimits,
+
+    #[garde(length(chars, min = 1, max = 8192))]
+    #[schema(min_length = 1, max_length = 8192)]
+    pub startup: compact_str::CompactString,
+    #[garde(length(chars, min = 2, max = 255))]
+    #[schema(min_length = 2, max_length = 255)]
+    pub image: compact_str::CompactString,
+    #[garde(
+        length(chars, min = 3, max = 255),
+        inner(custom(crate::utils::validate_timezone))
+    )]
+    #[schema(min_length = 3, max_length = 255)]
+    pub timezone: Option<compact_str::CompactString>,
+
+    #[garde(skip)]
+    pub hugepages_passthrough_enabled: bool,
+    #[garde(skip)]
+    pub kvm_passthrough_enabled: bool,
+
+    #[garde(dive)]
+    pub variables: Vec<ExportedServerVariable>,
+    #[garde(dive)]
+    pub allocations: Vec<ExportedServerAllocation>,
+    #[garde(skip)]
+    pub mounts: Vec<compact_str::CompactString>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Server {
     pub uuid: uuid::Uuid,
@@ -149,8 +237,10 @@ pub struct Server {
 
     pub startup: compact_str::CompactString,
     pub image: compact_str::CompactString,
+    pub allow_custom_image: bool,
     pub auto_kill: wings_api::ServerConfigurationAutoKill,
     pub auto_start_behavior: ServerAutoStartBehavior,
+    pub auto_start_on_reconnect: bool,
     pub timezone: Option<compact_str::CompactString>,
 
     pub hugepages_passthrough_enabled: bool,
@@ -159,12 +249,15 @@ pub struct Server {
     pub allocation_limit: i32,
     pub database_limit: i32,
     pub backup_limit: i32,
+    pub backup_size_limit: Option<i64>,
     pub schedule_limit: i32,
 
     pub subuser_permissions: Option<Arc<Vec<compact_str::CompactString>>>,
     pub subuser_ignored_files: Option<Vec<compact_str::CompactString>>,
     #[serde(skip_serializing, skip_deserializing)]
     subuser_ignored_files_overrides: Option<Box<ignore::overrides::Override>>,
+    #[serde(skip_serializing, skip_deserializing)]
+    file_denylist_overrides: Option<Box<ignore::overrides::Override>>,
 
     pub created: chrono::NaiveDateTime,
 }
@@ -242,6 +335,10 @@ impl BaseModel for Server {
                 "servers.image",
                 compact_str::format_compact!("{prefix}image"),
             ),
+            (
+                "servers.allow_custom_image",
+                compact_str::format_compact!("{prefix}allow_custom_image"),
+            ),
             (
                 "servers.auto_kill",
                 compact_str::format_compact!("{prefix}auto_kill"),
@@ -250,6 +347,10 @@ impl BaseModel for Server {
                 "servers.auto_start_behavior",
                 compact_str::format_compact!("{prefix}auto_start_behavior"),
             ),
+            (
+                "servers.auto_start_on_reconnect",
+                compact_str::format_compact!("{prefix}auto_start_on_reconnect"),
+            ),
             (
                 "servers.timezone",
                 compact_str::format_compact!("{prefix}timezone"),
@@ -274,6 +375,10 @@ impl BaseModel for Server {
                 "servers.backup_limit",
                 compact_str::format_compact!("{prefix}backup_limit"),
             ),
+            (
+                "servers.backup_size_limit",
+                compact_str::format_compact!("{prefix}backup_size_limit"),
+            ),
             (
                 "servers.schedule_limit",
                 compact_str::format_compact!("{prefix}schedule_limit"),
@@ -352,11 +457,16 @@ impl BaseModel for Server {
                 .try_get(compact_str::format_compact!("{prefix}pinned_cpus").as_str())?,
             startup: row.try_get(compact_str::format_compact!("{prefix}startup").as_str())?,
             image: row.try_get(compact_str::format_compact!("{prefix}image").as_str())?,
+            allow_custom_image: row
+                .try_get(compact_str::format_compact!("{prefix}allow_custom_image").as_str())?,
             auto_kill: serde_json::from_value(row.try_get::<serde_json::Value, _>(
                 compact_str::format_compact!("{prefix}auto_kill").as_str(),
             )?)?,
             auto_start_behavior: row
                 .try_get(compact_str::format_compact!("{prefix}auto_start_behavior").as_str())?,
+            auto_start_on_reconnect: row.try_get(
+                compact_str::format_compact!("{prefix}auto_start_on_reconnect").as_str(),
+            )?,
             timezone: row.try_get(compact_str::format_compact!("{prefix}timezone").as_str())?,
             hugepages_passthrough_enabled: row.try_get(
                 compact_str::format_compact!("{prefix}hugepages_passthrough_enabled").as_str(),
@@ -370,6 +480,8 @@ impl BaseModel for Server {
                 .try_get(compact_str::format_compact!("{prefix}database_limit").as_str())?,
             backup_limit: row
                 .try_get(compact_str::format_compact!("{prefix}backup_limit").as_str())?,
+            backup_size_limit: row
+                .try_get(compact_str::format_compact!("{prefix}backup_size_limit").as_str())?,
             schedule_limit: row
                 .try_get(compact_str::format_compact!("{prefix}schedule_limit").as_str())?,
             subuser_permissions: row
@@ -380,6 +492,7 @@ impl BaseModel for Server {
                 .try_get::<Vec<compact_str::CompactString>, _>("ignored_files")
                 .ok(),
             subuser_ignored_files_overrides: None,
+            file_denylist_overrides: None,
             created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
         })
     }
@@ -784,6 +897,34 @@ impl Server {
         })
     }
 
+    pub async fn all_by_node_uuid(
+        database: &crate::database::Database,
+        node_uuid: uuid::Uuid,
+    ) -> Result<Vec<Self>, crate::database::DatabaseError> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM servers
+            LEFT JOIN server_allocations ON server_allocations.uuid = servers.allocation_uuid
+            LEFT JOIN node_allocations ON node_allocations.uuid = server_allocations.allocation_uuid
+            JOIN users ON users.uuid = servers.owner_uuid
+            LEFT JOIN roles ON roles.uuid = users.role_uuid
+            JOIN nest_eggs ON nest_eggs.uuid = servers.egg_uuid
+            JOIN nests ON nests.uuid = nest_eggs.nest_uuid
+            WHERE servers.node_uuid = $1
+            ORDER BY servers.created
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(node_uuid)
+        .fetch_all(database.read())
+        .await?;
+
+        rows.into_iter()
+            .map(|row| Self::map(None, &row))
+            .try_collect_vec()
+    }
+
     pub async fn by_node_uuid_transferring_with_pagination(
         database: &crate::database::Database,
         node_uuid: uuid::Uuid,
@@ -997,6 +1138,35 @@ impl Server {
         .unwrap_or(0)
     }
 
+    /// Adjusts the denormalized server counters of a node and its location,
+    /// has to run within the transaction that adds or removes the server.
+    pub async fn adjust_server_counters(
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        node_uuid: uuid::Uuid,
+        delta: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            WITH node AS (
+                UPDATE nodes
+                SET server_count = nodes.server_count + $2
+                WHERE nodes.uuid = $1
+                RETURNING nodes.location_uuid
+            )
+            UPDATE locations
+            SET server_count = locations.server_count + $2
+            FROM node
+            WHERE locations.uuid = node.location_uuid
+            "#,
+        )
+        .bind(node_uuid)
+        .bind(delta)
+        .execute(&mut **transaction)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn count_by_egg_uuid(
         database: &crate::database::Database,
         egg_uuid: uuid::Uuid,
@@ -1014,6 +1184,64 @@ impl Server {
         .unwrap_or(0)
     }
 
+    /// Checks a server name against the configured `server.name_uniqueness` scope.
+    /// Returns a `DisplayError` with a conflict status if the name is already taken.
+    ///
+    /// Must be called inside the transaction that writes the name, the name is locked
+    /// (per owner or globally, depending on the scope) until that transaction ends.
+    pub async fn validate_name_uniqueness(
+        state: &crate::State,
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        name: &str,
+        owner_uuid: uuid::Uuid,
+        server_uuid: Option<uuid::Uuid>,
+    ) -> Result<(), anyhow::Error> {
+        use crate::settings::server::ServerNameUniqueness;
+
+        let scope = state.settings.server().await?.name_uniqueness;
+        let lock_key = match scope {
+            ServerNameUniqueness::None => return Ok(()),
+            ServerNameUniqueness::Owner => {
+                format!("servers::name::{owner_uuid}::{name}")
+            }
+            ServerNameUniqueness::Global => format!("servers::name::{name}"),
+        };
+
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind(lock_key)
+            .execute(&mut **transaction)
+            .await?;
+
+        let exists: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM servers
+                WHERE servers.name = $1
+                    AND ($2::uuid IS NULL OR servers.owner_uuid = $2)
+                    AND ($3::uuid IS NULL OR servers.uuid != $3)
+            )
+            "#,
+        )
+        .bind(name)
+        .bind((scope == ServerNameUniqueness::Owner).then_some(owner_uuid))
+        .bind(server_uuid)
+        .fetch_one(&mut **transaction)
+        .await?;
+
+        if exists {
+            return Err(DisplayError::new(match scope {
+                ServerNameUniqueness::Owner => "owner already has a server with this name",
+                ServerNameUniqueness::Global | ServerNameUniqueness::None => {
+                    "server with this name already exists"
+                }
+            })
+            .with_status(axum::http::StatusCode::CONFLICT)
+            .into());
+        }
+
+        Ok(())
+    }
+
     pub async fn sync(self, database: &crate::database::Database) -> Result<(), anyhow::Error> {
         self.node
             .fetch_cached(database)
@@ -1031,12 +1259,169 @@ impl Server {
         Ok(())
     }
 
+    /// Fetches the current power state of the server from its node.
+    /// Results are cached for `server.power_state_cache_seconds`, 0 disables caching.
+    /// Transitional states (starting/stopping) are never kept in the cache.
+    pub async fn fetch_power_state(
+        &self,
+        state: &crate::State,
+    ) -> Result<wings_api::ServerState, anyhow::Error> {
+        let cache_seconds = state.settings.get().await?.server.power_state_cache_seconds;
+        let node = self.node.fetch_cached(&state.database).await?;
+
+        let fetch = async || {
+            let server = node
+                .api_client(&state.database)
+                .await?
+                .get_servers_server(self.uuid)
+                .await?;
+
+            self.record_power_state(state, server.state).await;
+
+            Ok::<_, anyhow::Error>(server.state)
+        };
+
+        if cache_seconds == 0 {
+            return fetch().await;
+        }
+
+        let power_state = state
+            .database
+            .cache
+            .cached(
+                &format!("server::{}::power_state", self.uuid),
+                cache_seconds,
+                fetch,
+            )
+            .await?;
+
+        if matches!(
+            power_state,
+            wings_api::ServerState::Starting | wings_api::ServerState::Stopping
+        ) {
+            Self::invalidate_power_state(&state.database, self.uuid).await;
+        }
+
+        Ok(power_state)
+    }
+
+    /// Remembers the last known power state of the server and emits
+    /// `ServerEvent::PowerStateChanged` if it differs from the previously known one.
+    /// The state is swapped atomically, so only one panel instance emits the event for a change.
+    pub async fn record_power_state(
+        &self,
+        state: &crate::State,
+        power_state: wings_api::ServerState,
+    ) {
+        let cache = &state.database.cache;
+
+        let previous: Result<Option<String>, _> = cache
+            .client
+            .set_get_with_options(
+                cache.key(format!("server::{}::last_power_state", self.uuid)),
+                serde_json::to_string(&power_state).unwrap_or_default(),
+                None,
+                SetExpiration::Ex(86400),
+            )
+            .await;
+
+        let previous = match previous {
+            Ok(previous) => previous.and_then(|previous| {
+                serde_json::from_str::<wings_api::ServerState>(&previous).ok()
+            }),
+            Err(err) => {
+                tracing::warn!(server = %self.uuid, "failed to swap last power state: {:?}", err);
+                return;
+            }
+        };
+
+        if previous == Some(power_state) {
+            return;
+        }
+
+        Self::get_event_emitter().emit(
+            state.clone(),
+            ServerEvent::PowerStateChanged {
+                server: Box::new(self.clone()),
+                from: previous,
+                to: power_state,
+            },
+        );
+    }
+
+    /// Like [`Self::fetch_power_state`], but returns `None` instead of failing
+    /// or waiting on a node that does not respond in time.
+    pub async fn fetch_power_state_optional(
+        &self,
+        state: &crate::State,
+    ) -> Option<wings_api::ServerState> {
+        match tokio::time::timeout(
+            std::time::Duration::from_secs(2),
+            self.fetch_power_state(state),
+        )
+        .await
+        {
+            Ok(Ok(power_state)) => Some(power_state),
+            Ok(Err(err)) => {
+                tracing::debug!(server = %self.uuid, "failed to fetch power state: {:?}", err);
+                None
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Drops the cached power state of a server, should be called after any power action.
+    pub async fn invalidate_power_state(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+    ) {
+        if let Err(err) = database
+            .cache
+            .invalidate(&format!("server::{server_uuid}::power_state"))
+            .await
+        {
+            tracing::warn!(server = %server_uuid, "failed to invalidate cached power state: {:?}", err);
+        }
+    }
+
+    /// Fetches the sha256 fingerprint of a file from the node, used to detect
+    /// concurrent modifications in the file editor. Returns `None` if the file does not exist.
+    pub async fn file_fingerprint(
+        &self,
+        database: &crate::database::Database,
+        file: impl Into<compact_str::CompactString>,
+    ) -> Result<Option<compact_str::CompactString>, anyhow::Error> {
+        let fingerprints = match self
+            .node
+            .fetch_cached(database)
+            .await?
+            .api_client(database)
+            .await?
+            .get_servers_server_files_fingerprints(
+                self.uuid,
+                wings_api::Algorithm::Sha256,
+                vec![file.into()],
+            )
+            .await
+        {
+            Ok(data) => data.fingerprints,
+            Err(wings_api::client::ApiHttpError::Http(axum::http::StatusCode::NOT_FOUND, _)) => {
+                return Ok(None);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(fingerprints.into_values().next())
+    }
+
     /// Triggers a re-installation of the server on the node.
     /// This will only work if the server is in a state that allows re-installation. (None status)
     /// If this is not the case, a `DisplayError` will be returned.
+    /// The reinstall is recorded in the server's reinstall history, attributed to `user_uuid`.
     pub async fn install(
         &self,
         state: &crate::State,
+        user_uuid: Option<uuid::Uuid>,
         truncate_directory: bool,
         installation_script: Option<wings_api::InstallationScript>,
     ) -> Result<(), anyhow::Error> {
@@ -1061,6 +1446,17 @@ impl Server {
             .into());
         }
 
+        super::server_reinstall_history::ServerReinstallHistory::create(
+            &mut transaction,
+            self.uuid,
+            self.egg.uuid,
+            user_uuid,
+            truncate_directory,
+        )
+        .await?;
+
+        Self::reset_install_progress(self.uuid).await;
+
         match self
             .node
             .fetch_cached(&state.database)
@@ -1184,6 +1580,8 @@ impl Server {
             .await?;
         }
 
+        let transfer_ttl = state.settings.jwt().await?.transfer_ttl;
+
         let token = options.destination_node.create_jwt(
             &state.database,
             &state.jwt,
@@ -1191,7 +1589,7 @@ impl Server {
                 issuer: "panel".into(),
                 subject: Some(self.uuid.to_string()),
                 audience: Vec::new(),
-                expiration_time: Some(chrono::Utc::now().timestamp() + 600),
+                expiration_time: Some(chrono::Utc::now().timestamp() + transfer_ttl as i64),
                 not_before: None,
                 issued_at: Some(chrono::Utc::now().timestamp()),
                 jwt_id: self.node.uuid.to_string(),
@@ -1235,6 +1633,9 @@ impl Server {
         Ok(())
     }
 
+    /// Permissions are forwarded to wings verbatim, wings gates console output on
+    /// `control.read-console` and sending commands on `control.console`, so the two
+    /// can't be renamed without breaking existing nodes.
     pub fn wings_permissions(
         &self,
         settings: &crate::settings::AppSettings,
@@ -1350,6 +1751,112 @@ impl Server {
         None
     }
 
+    /// Returns whether `image` may replace `current_image`, images outside of the egg's
+    /// docker images are only allowed when the server allows custom images.
+    fn is_image_allowed(
+        docker_images: &IndexMap<compact_str::CompactString, compact_str::CompactString>,
+        current_image: &str,
+        image: &str,
+        allow_custom_image: bool,
+    ) -> bool {
+        image == current_image || allow_custom_image || docker_images.values().any(|i| i == image)
+    }
+
+    /// Notifies the owner that the server has been suspended.
+    async fn send_suspended_notification(&self, state: &crate::State) {
+        let (app_name, app_url) = match state.settings.app().await {
+            Ok(app_settings) => (app_settings.name.clone(), app_settings.url.clone()),
+            Err(err) => {
+                tracing::error!(server = %self.uuid, "failed to get app settings: {:?}", err);
+                return;
+            }
+        };
+
+        state
+            .mail
+            .send_notification(
+                &state.database,
+                self.owner.uuid,
+                super::user_notification_preferences::NotificationCategory::ServerSuspended,
+                self.owner.email.clone(),
+                format!("{app_name} - Server {} Suspended", self.name).into(),
+                crate::mail::MAIL_SERVER_SUSPENDED,
+                minijinja::context! {
+                    user => &self.owner,
+                    server_name => &self.name,
+                    server_link => format!(
+                        "{}/server/{:08x}",
+                        app_url.trim_end_matches('/'),
+                        self.uuid_short
+                    ),
+                },
+            )
+            .await;
+    }
+
+    /// Rejects images pulled from registries that are not in the configured allowlist.
+    async fn validate_image_registry(
+        state: &crate::State,
+        image: &str,
+    ) -> Result<(), crate::database::DatabaseError> {
+        if let Err(registry) = state
+            .settings
+            .server()
+            .await?
+            .validate_image_registry(image)
+        {
+            let mut report = garde::Report::new();
+            report.append(
+                garde::Path::new("image"),
+                garde::Error::new(compact_str::format_compact!(
+                    "images from registry {registry} are not allowed"
+                )),
+            );
+
+            return Err(report.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether a backup of the given size in bytes exceeds the server's backup size limit.
+    /// Wings' backup endpoint takes no size cap, so the limit is checked once the node reports the
+    /// archive size, either when requesting the S3 upload or when reporting the finished backup.
+    #[inline]
+    pub fn exceeds_backup_size_limit(&self, bytes: u64) -> bool {
+        self.backup_size_limit
+            .is_some_and(|limit| bytes > (limit.max(0) as u64).saturating_mul(1024 * 1024))
+    }
+
+    /// Returns whether the path matches the egg's file denylist, such files cannot be written by users.
+    /// `..` components are resolved before matching, so they cannot be used to escape a pattern.
+    pub fn is_denylisted(&mut self, path: impl AsRef<std::path::Path>) -> bool {
+        if self.egg.file_denylist.is_empty() {
+            return false;
+        }
+
+        let path = crate::cap::CapFilesystem::resolve_path(path.as_ref());
+
+        if let Some(overrides) = &self.file_denylist_overrides {
+            return overrides.matched(path, false).is_whitelist();
+        }
+
+        let mut override_builder = ignore::overrides::OverrideBuilder::new("/");
+
+        for file in &self.egg.file_denylist {
+            override_builder.add(file).ok();
+        }
+
+        if let Ok(override_builder) = override_builder.build() {
+            let denylisted = override_builder.matched(path, false).is_whitelist();
+            self.file_denylist_overrides = Some(Box::new(override_builder));
+
+            return denylisted;
+        }
+
+        false
+    }
+
     pub fn is_ignored(&mut self, path: impl AsRef<std::path::Path>, is_dir: bool) -> bool {
         if let Some(ignored_files) = &self.subuser_ignored_files {
             if let Some(overrides) = &self.subuser_ignored_files_overrides {
@@ -1380,7 +1887,7 @@ impl Server {
     ) -> Result<RemoteApiServer, anyhow::Error> {
         let (variables, backups, schedules, mounts, allocations) = tokio::try_join!(
             sqlx::query!(
-                "SELECT nest_egg_variables.env_variable, COALESCE(server_variables.value, nest_egg_variables.default_value) AS value
+                "SELECT nest_egg_variables.env_variable, COALESCE(server_variables.value, nest_egg_variables.default_value) AS value, nest_egg_variables.resolve_references
                 FROM nest_egg_variables
                 LEFT JOIN server_variables ON server_variables.variable_uuid = nest_egg_variables.uuid AND server_variables.server_uuid = $1
                 WHERE nest_egg_variables.egg_uuid = $2",
@@ -1436,6 +1943,41 @@ impl Server {
             );
         }
 
+        let resolved_variables =
+            match super::server_variable::resolve_variable_references(variables.iter().map(|v| {
+                (
+                    v.env_variable.as_str(),
+                    v.value.as_deref().unwrap_or_default(),
+                    v.resolve_references,
+                )
+            })) {
+                Ok(resolved_variables) => Some(resolved_variables),
+                Err(err) => {
+                    tracing::warn!(
+                        server = %self.uuid,
+                        "failed to resolve server variable references, using raw values: {:?}",
+                        err
+                    );
+
+                    None
+                }
+            };
+
+        let environment = variables
+            .iter()
+            .map(|v| {
+                let value = resolved_variables
+                    .as_ref()
+                    .and_then(|resolved| resolved.get(v.env_variable.as_str()).cloned())
+                    .unwrap_or_else(|| v.value.clone().unwrap_or_default());
+
+                (
+                    v.env_variable.as_str().into(),
+                    serde_json::Value::String(value),
+                )
+            })
+            .collect();
+
         let results = futures_util::future::try_join_all(futures).await?;
         let mut schedule_steps = HashMap::new();
         schedule_steps.reserve(schedules.len());
@@ -1456,15 +1998,7 @@ impl Server {
                 invocation: self.startup,
                 entrypoint: None,
                 skip_egg_scripts: false,
-                environment: variables
-                    .into_iter()
-                    .map(|v| {
-                        (
-                            v.env_variable.into(),
-                            serde_json::Value::String(v.value.unwrap_or_default()),
-                        )
-                    })
-                    .collect(),
+                environment,
                 labels: IndexMap::new(),
                 backups: backups.into_iter().map(|b| b.uuid).collect(),
                 schedules: schedules
@@ -1564,11 +2098,112 @@ impl Server {
         })
     }
 
+    pub async fn into_exported(
+        self,
+        database: &crate::database::Database,
+    ) -> Result<ExportedServer, anyhow::Error> {
+        let variables = super::server_variable::ServerVariable::all_by_server_uuid_egg_uuid(
+            database,
+            self.uuid,
+            self.egg.uuid,
+        )
+        .await?;
+
+        let allocations = sqlx::query(
+            r#"
+            SELECT host(node_allocations.ip) AS ip, node_allocations.ip_alias, node_allocations.port,
+                server_allocations.notes, server_allocations.uuid = servers.allocation_uuid AS "primary"
+            FROM server_allocations
+            JOIN node_allocations ON node_allocations.uuid = server_allocations.allocation_uuid
+            JOIN servers ON servers.uuid = server_allocations.server_uuid
+            WHERE server_allocations.server_uuid = $1
+            ORDER BY server_allocations.created
+            "#,
+        )
+        .bind(self.uuid)
+        .fetch_all(database.read())
+        .await?;
+
+        let mounts = sqlx::query_scalar(
+            r#"
+            SELECT mounts.name
+            FROM server_mounts
+            JOIN mounts ON mounts.uuid = server_mounts.mount_uuid
+            WHERE server_mounts.server_uuid = $1
+            ORDER BY mounts.name
+            "#,
+        )
+        .bind(self.uuid)
+        .fetch_all(database.read())
+        .await?;
+
+        let feature_limits = ApiServerFeatureLimits::init_hooks(&self, database).await?;
+        let feature_limits = finish_extendible!(
+            ApiServerFeatureLimits {
+                allocations: self.allocation_limit,
+                databases: self.database_limit,
+                backups: self.backup_limit,
+                backup_size: self.backup_size_limit,
+                schedules: self.schedule_limit,
+            },
+            feature_limits,
+            database
+        )?;
+
+        Ok(ExportedServer {
+            name: self.name,
+            description: self.description,
+            egg: ExportedServerEgg {
+                nest: self.nest.name,
+                name: self.egg.name,
+                author: self.egg.author,
+            },
+            limits: AdminApiServerLimits {
+                cpu: self.cpu,
+                memory: self.memory,
+                memory_overhead: self.memory_overhead,
+                swap: self.swap,
+                disk: self.disk,
+                io_weight: self.io_weight,
+            },
+            pinned_cpus: self.pinned_cpus,
+            feature_limits,
+            startup: self.startup,
+            image: self.image,
+            timezone: self.timezone,
+            hugepages_passthrough_enabled: self.hugepages_passthrough_enabled,
+            kvm_passthrough_enabled: self.kvm_passthrough_enabled,
+            variables: variables
+                .into_iter()
+                .filter(|variable| !variable.variable.secret)
+                .map(|variable| ExportedServerVariable {
+                    env_variable: variable.variable.env_variable,
+                    value: variable.value,
+                })
+                .collect(),
+            allocations: allocations
+                .into_iter()
+                .map(|row| {
+                    Ok::<_, sqlx::Error>(ExportedServerAllocation {
+                        ip: row.try_get("ip")?,
+                        ip_alias: row.try_get("ip_alias")?,
+                        port: row.try_get("port")?,
+                        notes: row.try_get("notes")?,
+                        primary: row
+                            .try_get::<Option<bool>, _>("primary")?
+                            .unwrap_or_default(),
+                    })
+                })
+                .try_collect_vec()?,
+            mounts,
+        })
+    }
+
     #[inline]
     pub async fn into_admin_api_object(
         self,
         database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
+        storage_url_retriever: &StorageUrlRetriever,
     ) -> Result<AdminApiServer, anyhow::Error> {
         let allocation_uuid = self.allocation.as_ref().map(|a| a.uuid);
 
@@ -1578,6 +2213,7 @@ impl Server {
                 allocations: self.allocation_limit,
                 databases: self.database_limit,
                 backups: self.backup_limit,
+                backup_size: self.backup_size_limit,
                 schedules: self.schedule_limit,
             },
             feature_limits,
@@ -1637,8 +2273,10 @@ impl Server {
             feature_limits,
             startup: self.startup,
             image: self.image,
+            allow_custom_image: self.allow_custom_image,
             auto_kill: self.auto_kill,
             auto_start_behavior: self.auto_start_behavior,
+            auto_start_on_reconnect: self.auto_start_on_reconnect,
             timezone: self.timezone,
             hugepages_passthrough_enabled: self.hugepages_passthrough_enabled,
             kvm_passthrough_enabled: self.kvm_passthrough_enabled,
@@ -1649,18 +2287,28 @@ impl Server {
     #[inline]
     pub async fn into_api_object(
         self,
-        database: &crate::database::Database,
+        state: &crate::State,
         user: &super::user::User,
     ) -> Result<ApiServer, anyhow::Error> {
+        let database = &state.database;
         let allocation_uuid = self.allocation.as_ref().map(|a| a.uuid);
         let node = self.node.fetch_cached(database).await?;
 
+        let description_html = if state.settings.server().await?.render_description_markdown {
+            self.description
+                .as_deref()
+                .map(crate::utils::render_description)
+        } else {
+            None
+        };
+
         let feature_limits = ApiServerFeatureLimits::init_hooks(&self, database).await?;
         let feature_limits = finish_extendible!(
             ApiServerFeatureLimits {
                 allocations: self.allocation_limit,
                 databases: self.database_limit,
                 backups: self.backup_limit,
+                backup_size: self.backup_size_limit,
                 schedules: self.schedule_limit,
             },
             feature_limits,
@@ -1692,11 +2340,13 @@ impl Server {
             }),
             sftp_port: node.sftp_port,
             status: self.status,
+            power_state: None,
             is_suspended: self.suspended,
             is_owner: self.owner.uuid == user.uuid,
             is_transferring: self.destination_node.is_some(),
             name: self.name,
             description: self.description,
+            description_html,
             limits: ApiServerLimits {
                 cpu: self.cpu,
                 memory: self.memory,
@@ -1708,6 +2358,7 @@ impl Server {
             image: self.image,
             auto_kill: self.auto_kill,
             auto_start_behavior: self.auto_start_behavior,
+            auto_start_on_reconnect: self.auto_start_on_reconnect,
             timezone: self.timezone,
             created: self.created.and_utc(),
         })
@@ -1716,6 +2367,16 @@ impl Server {
 
 #[async_trait::async_trait]
 impl ByUuid for Server {
+    const TABLE: &'static str = "servers";
+    const JOINS: &'static str = r#"
+        LEFT JOIN server_allocations ON server_allocations.uuid = servers.allocation_uuid
+        LEFT JOIN node_allocations ON node_allocations.uuid = server_allocations.allocation_uuid
+        JOIN users ON users.uuid = servers.owner_uuid
+        LEFT JOIN roles ON roles.uuid = users.role_uuid
+        JOIN nest_eggs ON nest_eggs.uuid = servers.egg_uuid
+        JOIN nests ON nests.uuid = nest_eggs.nest_uuid
+    "#;
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -1784,9 +2445,12 @@ pub struct CreateServerOptions {
     #[garde(length(chars, min = 2, max = 255))]
     #[schema(min_length = 2, max_length = 255)]
     pub image: compact_str::CompactString,
-    #[garde(skip)]
-    #[schema(value_type = Option<String>)]
-    pub timezone: Option<chrono_tz::Tz>,
+    #[garde(
+        length(chars, min = 3, max = 255),
+        inner(custom(crate::utils::validate_timezone))
+    )]
+    #[schema(min_length = 3, max_length = 255)]
+    pub timezone: Option<compact_str::CompactString>,
 
     #[garde(skip)]
     pub hugepages_passthrough_enabled: bool,
@@ -1817,6 +2481,15 @@ impl CreatableModel for Server {
     ) -> Result<Self, crate::database::DatabaseError> {
         options.validate()?;
 
+        if let Some(external_id) = &options.external_id {
+            state
+                .settings
+                .get()
+                .await?
+                .external_id
+                .validate_external_id(external_id)?;
+        }
+
         let node = super::node::Node::by_uuid_optional(&state.database, options.node_uuid)
             .await?
             .ok_or(crate::database::InvalidRelationError("node"))?;
@@ -1828,6 +2501,13 @@ impl CreatableModel for Server {
         super::nest_egg::NestEgg::by_uuid_optional(&state.database, options.egg_uuid)
             .await?
             .ok_or(crate::database::InvalidRelationError("egg"))?;
+        super::nest_egg::NestEgg::validate_startup(
+            &state.database,
+            options.egg_uuid,
+            &options.startup,
+        )
+        .await?;
+        Self::validate_image_registry(state, &options.image).await?;
 
         if let Some(backup_configuration_uuid) = options.backup_configuration_uuid {
             super::backup_configuration::BackupConfiguration::by_uuid_optional(
@@ -1841,6 +2521,15 @@ impl CreatableModel for Server {
         }
 
         let mut transaction = state.database.write().begin().await?;
+        Self::validate_name_uniqueness(
+            state,
+            &mut transaction,
+            &options.name,
+            options.owner_uuid,
+            None,
+        )
+        .await?;
+
         let mut attempts = 0;
 
         loop {
@@ -1882,7 +2571,7 @@ impl CreatableModel for Server {
                 .set("pinned_cpus", &options.pinned_cpus)
                 .set("startup", &options.startup)
                 .set("image", &options.image)
-                .set("timezone", options.timezone.as_ref().map(|t| t.name()))
+                .set("timezone", &options.timezone)
                 .set(
                     "hugepages_passthrough_enabled",
                     options.hugepages_passthrough_enabled,
@@ -1891,6 +2580,7 @@ impl CreatableModel for Server {
                 .set("allocation_limit", options.feature_limits.allocations)
                 .set("database_limit", options.feature_limits.databases)
                 .set("backup_limit", options.feature_limits.backups)
+                .set("backup_size_limit", options.feature_limits.backup_size)
                 .set("schedule_limit", options.feature_limits.schedules);
 
             match query_builder
@@ -1957,6 +2647,16 @@ impl CreatableModel for Server {
                         .await?;
                     }
 
+                    super::server_reinstall_history::ServerReinstallHistory::create_initial(
+                        &mut transaction,
+                        server_uuid,
+                        options.egg_uuid,
+                        !options.skip_installer,
+                    )
+                    .await?;
+
+                    Self::adjust_server_counters(&mut transaction, node.uuid, 1).await?;
+
                     transaction.commit().await?;
 
                     if let Err(err) = node
@@ -1971,14 +2671,19 @@ impl CreatableModel for Server {
                     {
                         tracing::error!(server = %server_uuid, node = %node.uuid, "failed to create server: {:?}", err);
 
+                        let mut transaction = state.database.write().begin().await?;
+
                         sqlx::query!("DELETE FROM servers WHERE servers.uuid = $1", server_uuid)
-                            .execute(state.database.write())
+                            .execute(&mut *transaction)
                             .await?;
+                        Self::adjust_server_counters(&mut transaction, node.uuid, -1).await?;
+
+                        transaction.commit().await?;
 
                         return Err(err.into());
                     }
 
-                    return Self::by_uuid(&state.database, server_uuid).await;
+                    return Self::by_uuid(&state.database.primary(), server_uuid).await;
                 }
                 Err(_) if attempts < 8 => {
                     attempts += 1;
@@ -2041,14 +2746,20 @@ pub struct UpdateServerOptions {
     #[garde(length(chars, min = 2, max = 255))]
     #[schema(min_length = 2, max_length = 255)]
     pub image: Option<compact_str::CompactString>,
+    /// Allows setting an `image` that is not one of the egg's docker images.
     #[garde(skip)]
-    #[schema(value_type = Option<Option<String>>)]
+    pub allow_custom_image: Option<bool>,
+    #[garde(inner(inner(
+        length(chars, min = 3, max = 255),
+        custom(crate::utils::validate_timezone)
+    )))]
+    #[schema(value_type = Option<Option<String>>, min_length = 3, max_length = 255)]
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         with = "::serde_with::rust::double_option"
     )]
-    pub timezone: Option<Option<chrono_tz::Tz>>,
+    pub timezone: Option<Option<compact_str::CompactString>>,
 
     #[garde(skip)]
     pub hugepages_passthrough_enabled: Option<bool>,
@@ -2077,6 +2788,15 @@ impl UpdatableModel for Server {
     ) -> Result<(), crate::database::DatabaseError> {
         options.validate()?;
 
+        if let Some(Some(external_id)) = &options.external_id {
+            state
+                .settings
+                .get()
+                .await?
+                .external_id
+                .validate_external_id(external_id)?;
+        }
+
         let owner = if let Some(owner_uuid) = options.owner_uuid {
             Some(
                 super::user::User::by_uuid_optional(&state.database, owner_uuid)
@@ -2097,6 +2817,52 @@ impl UpdatableModel for Server {
             None
         };
 
+        if let Some(egg) = &egg
+            && egg.uuid != self.egg.uuid
+        {
+            let allocations = super::server_allocation::ServerAllocation::count_by_server_uuid(
+                &state.database,
+                self.uuid,
+            )
+            .await?;
+            egg.config_allocations
+                .ensure_minimum(allocations as usize)?;
+        }
+
+        if let Some(startup) = &options.startup {
+            super::nest_egg::NestEgg::validate_startup(
+                &state.database,
+                egg.as_ref().map_or(self.egg.uuid, |egg| egg.uuid),
+                startup,
+            )
+            .await?;
+        }
+
+        if let Some(image) = &options.image
+            && !Self::is_image_allowed(
+                &egg.as_ref().unwrap_or(&self.egg).docker_images,
+                &self.image,
+                image,
+                options
+                    .allow_custom_image
+                    .unwrap_or(self.allow_custom_image),
+            )
+        {
+            let mut report = garde::Report::new();
+            report.append(
+                garde::Path::new("image"),
+                garde::Error::new("must be one of the egg's docker images"),
+            );
+
+            return Err(report.into());
+        }
+
+        if let Some(image) = &options.image
+            && *image != self.image
+        {
+            Self::validate_image_registry(state, image).await?;
+        }
+
         let backup_configuration =
             if let Some(backup_configuration_uuid) = &options.backup_configuration_uuid {
                 match backup_configuration_uuid {
@@ -2122,6 +2888,21 @@ impl UpdatableModel for Server {
 
         let mut transaction = state.database.write().begin().await?;
 
+        if options.name.as_ref().is_some_and(|name| *name != self.name)
+            || owner
+                .as_ref()
+                .is_some_and(|owner| owner.uuid != self.owner.uuid)
+        {
+            Self::validate_name_uniqueness(
+                state,
+                &mut transaction,
+                options.name.as_deref().unwrap_or(&self.name),
+                owner.as_ref().map_or(self.owner.uuid, |owner| owner.uuid),
+                Some(self.uuid),
+            )
+            .await?;
+        }
+
         let mut query_builder = UpdateQueryBuilder::new("servers");
 
         Self::run_update_handlers(
@@ -2156,13 +2937,8 @@ impl UpdatableModel for Server {
             .set("pinned_cpus", options.pinned_cpus.as_ref())
             .set("startup", options.startup.as_ref())
             .set("image", options.image.as_ref())
-            .set(
-                "timezone",
-                options
-                    .timezone
-                    .as_ref()
-                    .map(|t| t.as_ref().map(|t| t.name())),
-            )
+            .set("allow_custom_image", options.allow_custom_image)
+            .set("timezone", options.timezone.as_ref().map(|t| t.as_deref()))
             .set(
                 "hugepages_passthrough_enabled",
                 options.hugepages_passthrough_enabled,
@@ -2184,6 +2960,7 @@ impl UpdatableModel for Server {
                 .set("allocation_limit", Some(feature_limits.allocations))
                 .set("database_limit", Some(feature_limits.databases))
                 .set("backup_limit", Some(feature_limits.backups))
+                .set("backup_size_limit", Some(feature_limits.backup_size))
                 .set("schedule_limit", Some(feature_limits.schedules));
         }
 
@@ -2200,6 +2977,7 @@ impl UpdatableModel for Server {
         if let Some(backup_configuration) = backup_configuration {
             self.backup_configuration = backup_configuration;
         }
+        let newly_suspended = options.suspended == Some(true) && !self.suspended;
         if let Some(suspended) = options.suspended {
             self.suspended = suspended;
         }
@@ -2229,8 +3007,11 @@ impl UpdatableModel for Server {
         if let Some(image) = options.image {
             self.image = image;
         }
+        if let Some(allow_custom_image) = options.allow_custom_image {
+            self.allow_custom_image = allow_custom_image;
+        }
         if let Some(timezone) = options.timezone {
-            self.timezone = timezone.map(|t| t.name().into());
+            self.timezone = timezone;
         }
         if let Some(hugepages_passthrough_enabled) = options.hugepages_passthrough_enabled {
             self.hugepages_passthrough_enabled = hugepages_passthrough_enabled;
@@ -2242,11 +3023,16 @@ impl UpdatableModel for Server {
             self.allocation_limit = feature_limits.allocations;
             self.database_limit = feature_limits.databases;
             self.backup_limit = feature_limits.backups;
+            self.backup_size_limit = feature_limits.backup_size;
             self.schedule_limit = feature_limits.schedules;
         }
 
         transaction.commit().await?;
 
+        if newly_suspended {
+            self.send_suspended_notification(state).await;
+        }
+
         Ok(())
     }
 }
@@ -2301,6 +3087,7 @@ impl DeletableModel for Server {
             sqlx::query!("DELETE FROM servers WHERE servers.uuid = $1", server_uuid)
                 .execute(&mut *transaction)
                 .await?;
+            Self::adjust_server_counters(&mut transaction, node.uuid, -1).await?;
 
             match node
                 .api_client(&state.database)
@@ -2335,6 +3122,14 @@ pub struct RemoteApiServer {
     process_configuration: super::nest_egg::ProcessConfiguration,
 }
 
+impl RemoteApiServer {
+    /// The environment variables the panel sends to wings for this server.
+    #[inline]
+    pub fn environment(&self) -> &IndexMap<compact_str::CompactString, serde_json::Value> {
+        &self.settings.environment
+    }
+}
+
 #[derive(ToSchema, Validate, Serialize, Deserialize, Clone, Copy)]
 pub struct AdminApiServerLimits {
     #[garde(range(min = 0))]
@@ -2387,6 +3182,12 @@ pub struct ApiServerFeatureLimits {
     #[garde(range(min = 0))]
     #[schema(minimum = 0)]
     pub backups: i32,
+    /// The maximum size of a single backup in MiB, `None` for no limit.
+    /// Checked by the panel once the node created the archive, oversized backups are discarded.
+    #[garde(range(min = 1))]
+    #[schema(minimum = 1)]
+    #[serde(default)]
+    pub backup_size: Option<i64>,
     #[garde(range(min = 0))]
     #[schema(minimum = 0)]
     pub schedules: i32,
@@ -2421,9 +3222,11 @@ pub struct AdminApiServer {
 
     pub startup: compact_str::CompactString,
     pub image: compact_str::CompactString,
+    pub allow_custom_image: bool,
     #[schema(inline)]
     pub auto_kill: wings_api::ServerConfigurationAutoKill,
     pub auto_start_behavior: ServerAutoStartBehavior,
+    pub auto_start_on_reconnect: bool,
     pub timezone: Option<compact_str::CompactString>,
 
     pub hugepages_passthrough_enabled: bool,
@@ -2441,6 +3244,8 @@ pub struct ApiServer {
     pub egg: super::nest_egg::ApiNestEgg,
 
     pub status: Option<ServerStatus>,
+    /// The (cached) power state, only set by the server list and detail endpoints.
+    pub power_state: Option<wings_api::ServerState>,
 
     pub is_owner: bool,
     pub is_suspended: bool,
@@ -2458,6 +3263,8 @@ pub struct ApiServer {
 
     pub name: compact_str::CompactString,
     pub description: Option<compact_str::CompactString>,
+    /// The description rendered as sanitized markdown, only set if markdown rendering is enabled.
+    pub description_html: Option<String>,
 
     #[schema(inline)]
     pub limits: ApiServerLimits,
@@ -2469,7 +3276,61 @@ pub struct ApiServer {
     #[schema(inline)]
     pub auto_kill: wings_api::ServerConfigurationAutoKill,
     pub auto_start_behavior: ServerAutoStartBehavior,
+    pub auto_start_on_reconnect: bool,
     pub timezone: Option<compact_str::CompactString>,
 
     pub created: chrono::DateTime<chrono::Utc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn docker_images() -> IndexMap<compact_str::CompactString, compact_str::CompactString> {
+        IndexMap::from([("Java 21".into(), "ghcr.io/pterodactyl/yolks:java_21".into())])
+    }
+
+    #[test]
+    fn image_from_egg_is_allowed() {
+        assert!(Server::is_image_allowed(
+            &docker_images(),
+            "ghcr.io/pterodactyl/yolks:java_17",
+            "ghcr.io/pterodactyl/yolks:java_21",
+            false,
+        ));
+    }
+
+    #[test]
+    fn custom_image_requires_override() {
+        assert!(!Server::is_image_allowed(
+            &docker_images(),
+            "ghcr.io/pterodactyl/yolks:java_21",
+            "example.com/custom:latest",
+            false,
+        ));
+        assert!(Server::is_image_allowed(
+            &docker_images(),
+            "ghcr.io/pterodactyl/yolks:java_21",
+            "example.com/custom:latest",
+            true,
+        ));
+    }
+
+    #[test]
+    fn unchanged_custom_image_is_allowed() {
+        assert!(Server::is_image_allowed(
+            &docker_images(),
+            "example.com/custom:latest",
+            "example.com/custom:latest",
+            false,
+        ));
+    }
+
+    #[test]
+    fn missing_allow_custom_image_is_not_deserialized_as_false() {
+        let options: UpdateServerOptions =
+            serde_json::from_str(r#"{"image": "example.com/custom:latest"}"#).unwrap();
+
+        assert_eq!(options.allow_custom_image, None);
+    }
+}
@@ -0,0 +1,129 @@
+use std::{collections::HashSet, sync::LazyLock};
+use tokio::sync::Mutex;
+
+/// Servers whose power state this instance is currently relaying from the wings websocket.
+static POWER_STATE_RELAYS: LazyLock<Mutex<HashSet<uuid::Uuid>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Maps a wings websocket status event to the power state it reports.
+pub(super) fn map_websocket_message(
+    message: &wings_api::WebsocketMessage,
+) -> Option<wings_api::ServerState> {
+    if !matches!(message.event, wings_api::WebsocketEvent::Status) {
+        return None;
+    }
+
+    serde_json::from_value(serde_json::Value::String(message.args.first()?.to_string())).ok()
+}
+
+impl super::Server {
+    /// Stores a power state reported by wings in the power state cache.
+    /// Transitional states (starting/stopping) only drop the cached state.
+    pub async fn store_power_state(
+        &self,
+        state: &crate::State,
+        power_state: wings_api::ServerState,
+    ) {
+        let cache_seconds = match state.settings.get().await {
+            Ok(settings) => settings.server.power_state_cache_seconds,
+            Err(err) => {
+                tracing::warn!(server = %self.uuid, "failed to read settings: {:?}", err);
+                0
+            }
+        };
+
+        if cache_seconds == 0
+            || matches!(
+                power_state,
+                wings_api::ServerState::Starting | wings_api::ServerState::Stopping
+            )
+        {
+            Self::invalidate_power_state(&state.database, self.uuid).await;
+        } else if let Err(err) = state
+            .database
+            .cache
+            .set(
+                &format!("server::{}::power_state", self.uuid),
+                cache_seconds,
+                &power_state,
+            )
+            .await
+        {
+            tracing::warn!(server = %self.uuid, "failed to store cached power state: {:?}", err);
+        }
+
+        self.record_power_state(state, power_state).await;
+    }
+
+    /// Follows the status events of the wings websocket in the background after a power action,
+    /// keeping the power state cache current until the server settled as running or offline.
+    /// Every instance relays at most one connection per server, for at most two minutes.
+    pub async fn relay_power_state_in_background(&self, state: &crate::State) {
+        if !POWER_STATE_RELAYS.lock().await.insert(self.uuid) {
+            return;
+        }
+
+        let state = state.clone();
+        let server = self.clone();
+        tokio::spawn(async move {
+            if let Err(err) = tokio::time::timeout(
+                std::time::Duration::from_secs(120),
+                server.relay_power_state(&state),
+            )
+            .await
+            .unwrap_or(Ok(()))
+            {
+                tracing::warn!(server = %server.uuid, "failed to relay power state: {:?}", err);
+            }
+
+            POWER_STATE_RELAYS.lock().await.remove(&server.uuid);
+        });
+    }
+
+    async fn relay_power_state(&self, state: &crate::State) -> Result<(), anyhow::Error> {
+        let mut websocket = self
+            .connect_wings_websocket(state, &["websocket.connect"])
+            .await?;
+
+        // wings reports the current state right after connecting, so a settled state is only
+        // trusted once no further status event followed it for a few seconds
+        let mut settled_until = None;
+
+        loop {
+            let message = match settled_until {
+                Some(settled_until) => {
+                    match tokio::time::timeout_at(settled_until, websocket.next_message()).await {
+                        Ok(message) => message,
+                        Err(_) => break,
+                    }
+                }
+                None => websocket.next_message().await,
+            };
+
+            let message = match message {
+                Ok(Some(message)) => message,
+                Ok(None) => break,
+                Err(err) => {
+                    websocket.close().await;
+                    return Err(err);
+                }
+            };
+
+            let Some(power_state) = map_websocket_message(&message) else {
+                continue;
+            };
+
+            self.store_power_state(state, power_state).await;
+
+            settled_until = matches!(
+                power_state,
+                wings_api::ServerState::Running | wings_api::ServerState::Offline
+            )
+            .then(|| tokio::time::Instant::now() + std::time::Duration::from_secs(10));
+        }
+
+        websocket.close().await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use tokio_tungstenite::{
+    MaybeTlsStream, WebSocketStream,
+    tungstenite::{Message, client::IntoClientRequest},
+};
+
+#[derive(Serialize)]
+struct WebsocketJwt<'a> {
+    #[serde(flatten)]
+    base: crate::jwt::BasePayload,
+
+    user_uuid: uuid::Uuid,
+    server_uuid: uuid::Uuid,
+    permissions: &'a [&'a str],
+    use_console_read_permission: bool,
+}
+
+/// A connection of the panel itself to the wings websocket of a server, used to relay its events.
+pub(super) struct WingsWebsocket {
+    socket: WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>,
+    state: crate::State,
+    node: crate::models::node::Node,
+    user_uuid: uuid::Uuid,
+    server_uuid: uuid::Uuid,
+    permissions: &'static [&'static str],
+}
+
+impl WingsWebsocket {
+    fn create_auth_message(&self, websocket_ttl: u64) -> Result<Message, anyhow::Error> {
+        let token = self.node.create_jwt(
+            &self.state.database,
+            &self.state.jwt,
+            &WebsocketJwt {
+                base: crate::jwt::BasePayload {
+                    issuer: "panel".into(),
+                    subject: None,
+                    audience: Vec::new(),
+                    expiration_time: Some(chrono::Utc::now().timestamp() + websocket_ttl as i64),
+                    not_before: None,
+                    issued_at: Some(chrono::Utc::now().timestamp()),
+                    jwt_id: uuid::Uuid::new_v4().to_string(),
+                },
+                user_uuid: self.user_uuid,
+                server_uuid: self.server_uuid,
+                permissions: self.permissions,
+                use_console_read_permission: false,
+            },
+        )?;
+
+        Ok(Message::text(serde_json::to_string(
+            &wings_api::WebsocketMessage {
+                event: wings_api::WebsocketEvent::Auth,
+                args: vec![token.into()],
+            },
+        )?))
+    }
+
+    async fn authenticate(&mut self) -> Result<(), anyhow::Error> {
+        let websocket_ttl = self.state.settings.jwt().await?.websocket_ttl;
+        let message = self.create_auth_message(websocket_ttl)?;
+
+        self.socket.send(message).await?;
+
+        Ok(())
+    }
+
+    /// Waits for the next event of the server, re-authenticating when the token is about to expire.
+    /// Returns `None` once the connection was closed or the token could not be renewed.
+    pub async fn next_message(
+        &mut self,
+    ) -> Result<Option<wings_api::WebsocketMessage>, anyhow::Error> {
+        while let Some(message) = self.socket.next().await {
+            let Message::Text(message) = message? else {
+                continue;
+            };
+            let Ok(message) = serde_json::from_str::<wings_api::WebsocketMessage>(&message) else {
+                continue;
+            };
+
+            match message.event {
+                wings_api::WebsocketEvent::TokenExpiring => self.authenticate().await?,
+                wings_api::WebsocketEvent::TokenExpired | wings_api::WebsocketEvent::JwtError => {
+                    return Ok(None);
+                }
+                _ => return Ok(Some(message)),
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub async fn close(mut self) {
+        let _ = self.socket.close(None).await;
+    }
+}
+
+impl super::Server {
+    /// Connects to the wings websocket of the server as its owner, with the given permissions.
+    pub(super) async fn connect_wings_websocket(
+        &self,
+        state: &crate::State,
+        permissions: &'static [&'static str],
+    ) -> Result<WingsWebsocket, anyhow::Error> {
+        let node = self.node.fetch_cached(&state.database).await?;
+
+        let mut url = node.url.clone();
+        url.set_path(&format!("/api/servers/{}/ws", self.uuid));
+        if url.scheme() == "http" {
+            url.set_scheme("ws").unwrap();
+        } else if url.scheme() == "https" {
+            url.set_scheme("wss").unwrap();
+        }
+
+        let mut request = url.as_str().into_client_request()?;
+        request.headers_mut().insert(
+            "Origin",
+            state
+                .settings
+                .app()
+                .await?
+                .url
+                .trim_end_matches('/')
+                .parse()?,
+        );
+
+        let (socket, _) = tokio_tungstenite::connect_async(request).await?;
+
+        let mut websocket = WingsWebsocket {
+            socket,
+            state: state.clone(),
+            node,
+            user_uuid: self.owner.uuid,
+            server_uuid: self.uuid,
+            permissions,
+        };
+        websocket.authenticate().await?;
+
+        Ok(websocket)
+    }
+}
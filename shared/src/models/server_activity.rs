@@ -4,11 +4,20 @@ use garde::Validate;
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, postgres::PgRow};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashMap},
     sync::{Arc, LazyLock},
 };
 use utoipa::ToSchema;
 
+/// File paths stored in activity data are capped to this many bytes.
+pub const MAX_LOGGED_PATH_LENGTH: usize = 512;
+
+/// Caps a file path before it is stored in activity data.
+#[inline]
+pub fn cap_logged_path(path: &str) -> &str {
+    crate::utils::slice_up_to(path, MAX_LOGGED_PATH_LENGTH)
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ServerActivity {
     pub user: Option<super::user::User>,
@@ -141,50 +150,77 @@ impl ServerActivity {
         })
     }
 
+    /// Deletes all activity older than `cutoff` in batches of `batch_size` rows,
+    /// returning the total amount of deleted rows.
     pub async fn delete_older_than(
         database: &crate::database::Database,
         cutoff: chrono::DateTime<chrono::Utc>,
+        batch_size: i64,
     ) -> Result<u64, crate::database::DatabaseError> {
-        let result = sqlx::query(
-            r#"
-            DELETE FROM server_activities
-            WHERE created < $1
-            "#,
-        )
-        .bind(cutoff.naive_utc())
-        .execute(database.write())
-        .await?;
+        let mut deleted = 0;
+
+        loop {
+            let result = sqlx::query(
+                r#"
+                DELETE FROM server_activities
+                WHERE ctid IN (
+                    SELECT ctid
+                    FROM server_activities
+                    WHERE created < $1
+                    LIMIT $2
+                )
+                "#,
+            )
+            .bind(cutoff.naive_utc())
+            .bind(batch_size)
+            .execute(database.write())
+            .await?;
+
+            deleted += result.rows_affected();
+
+            if result.rows_affected() < batch_size as u64 {
+                break;
+            }
+        }
 
-        Ok(result.rows_affected())
+        Ok(deleted)
+    }
+
+    /// Loads the impersonators of the activities at once, for [`Self::into_api_object`].
+    pub async fn fetch_impersonators(
+        database: &crate::database::Database,
+        activities: &[Self],
+    ) -> Result<HashMap<uuid::Uuid, super::user::User>, anyhow::Error> {
+        Fetchable::fetch_many_cached(
+            database,
+            activities
+                .iter()
+                .filter_map(|activity| activity.impersonator.as_ref()),
+        )
+        .await
     }
 
     #[inline]
-    pub async fn into_api_object(
+    pub fn into_api_object(
         self,
-        database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
-    ) -> Result<ApiServerActivity, anyhow::Error> {
-        Ok(ApiServerActivity {
+        impersonators: &HashMap<uuid::Uuid, super::user::User>,
+        storage_url_retriever: &StorageUrlRetriever,
+    ) -> ApiServerActivity {
+        ApiServerActivity {
             user: self
                 .user
                 .map(|user| user.into_api_object(storage_url_retriever)),
-            impersonator: if let Some(impersonator) = self.impersonator {
-                Some(
-                    impersonator
-                        .fetch_cached(database)
-                        .await?
-                        .into_api_object(storage_url_retriever),
-                )
-            } else {
-                None
-            },
+            impersonator: self
+                .impersonator
+                .and_then(|impersonator| impersonators.get(&impersonator.uuid))
+                .map(|impersonator| impersonator.clone().into_api_object(storage_url_retriever)),
             event: self.event,
             ip: self.ip.map(|ip| ip.ip().to_compact_string()),
             data: self.data,
             is_api: self.api_key.is_some(),
             is_schedule: self.schedule.is_some(),
             created: self.created.and_utc(),
-        })
+        }
     }
 }
 
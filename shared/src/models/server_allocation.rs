@@ -154,6 +154,29 @@ impl ServerAllocation {
         row.try_map(|row| Self::map(None, &row))
     }
 
+    pub async fn all_by_server_uuid(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+    ) -> Result<Vec<Self>, crate::database::DatabaseError> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM server_allocations
+            JOIN node_allocations ON server_allocations.allocation_uuid = node_allocations.uuid
+            WHERE server_allocations.server_uuid = $1
+            ORDER BY server_allocations.created
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(server_uuid)
+        .fetch_all(database.read())
+        .await?;
+
+        rows.into_iter()
+            .map(|row| Self::map(None, &row))
+            .try_collect_vec()
+    }
+
     pub async fn by_server_uuid_with_pagination(
         database: &crate::database::Database,
         server_uuid: uuid::Uuid,
@@ -195,11 +218,36 @@ impl ServerAllocation {
         })
     }
 
+    /// Makes an allocation the primary allocation of its server, replacing the previous one in a single statement.
+    /// Returns `false` if the allocation does not belong to the server.
+    pub async fn set_primary(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+        allocation_uuid: uuid::Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE servers
+            SET allocation_uuid = server_allocations.uuid
+            FROM server_allocations
+            WHERE servers.uuid = $1
+                AND server_allocations.uuid = $2
+                AND server_allocations.server_uuid = servers.uuid
+            "#,
+        )
+        .bind(server_uuid)
+        .bind(allocation_uuid)
+        .execute(database.write())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     pub async fn count_by_server_uuid(
         database: &crate::database::Database,
         server_uuid: uuid::Uuid,
-    ) -> i64 {
-        sqlx::query_scalar(
+    ) -> Result<i64, crate::database::DatabaseError> {
+        Ok(sqlx::query_scalar(
             r#"
             SELECT COUNT(*)
             FROM server_allocations
@@ -208,8 +256,7 @@ impl ServerAllocation {
         )
         .bind(server_uuid)
         .fetch_one(database.read())
-        .await
-        .unwrap_or(0)
+        .await?)
     }
 
     #[inline]
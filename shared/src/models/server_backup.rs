@@ -196,6 +196,114 @@ impl BaseModel for ServerBackup {
 }
 
 impl ServerBackup {
+    /// Notifies the server owner that the backup has completed or failed.
+    pub async fn send_completed_notification(
+        &self,
+        state: &crate::State,
+        server: &super::server::Server,
+        successful: bool,
+    ) {
+        let (app_name, app_url) = match state.settings.app().await {
+            Ok(app_settings) => (app_settings.name.clone(), app_settings.url.clone()),
+            Err(err) => {
+                tracing::error!(backup = %self.uuid, "failed to get app settings: {:?}", err);
+                return;
+            }
+        };
+
+        state
+            .mail
+            .send_notification(
+                &state.database,
+                server.owner.uuid,
+                super::user_notification_preferences::NotificationCategory::BackupCompleted,
+                server.owner.email.clone(),
+                if successful {
+                    format!("{app_name} - Backup Completed for {}", server.name)
+                } else {
+                    format!("{app_name} - Backup Failed for {}", server.name)
+                }
+                .into(),
+                crate::mail::MAIL_BACKUP_COMPLETED,
+                minijinja::context! {
+                    user => &server.owner,
+                    server_name => &server.name,
+                    backup_name => &self.name,
+                    successful => successful,
+                    server_link => format!(
+                        "{}/server/{:08x}/backups",
+                        app_url.trim_end_matches('/'),
+                        server.uuid_short
+                    ),
+                },
+            )
+            .await;
+    }
+
+    async fn mark_failed(
+        database: &crate::database::Database,
+        uuid: uuid::Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE server_backups
+            SET successful = false, completed = NOW()
+            WHERE server_backups.uuid = $1",
+            uuid
+        )
+        .execute(database.write())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Starts creating this backup on the server's node, marking it as failed if the node rejects it.
+    pub async fn start(
+        &self,
+        database: &crate::database::Database,
+        server: &super::server::Server,
+    ) -> Result<(), anyhow::Error> {
+        tracing::debug!(backup = %self.uuid, "creating server backup");
+
+        let result = async {
+            server
+                .node
+                .fetch_cached(database)
+                .await?
+                .api_client(database)
+                .await?
+                .post_servers_server_backup(
+                    server.uuid,
+                    &wings_api::servers_server_backup::post::RequestBody {
+                        adapter: self.disk.to_wings_adapter(),
+                        uuid: self.uuid,
+                        ignore: self
+                            .ignored_files
+                            .iter()
+                            .map(|s| s.as_str())
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                            .into(),
+                    },
+                )
+                .await?;
+
+            Ok::<_, anyhow::Error>(())
+        }
+        .await;
+
+        if let Err(err) = result {
+            tracing::error!(backup = %self.uuid, "failed to create server backup: {:?}", err);
+
+            if let Err(err) = Self::mark_failed(database, self.uuid).await {
+                tracing::error!(backup = %self.uuid, "failed to update server backup status: {:?}", err);
+            }
+
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
     pub async fn create_raw(
         state: &crate::State,
         options: CreateServerBackupOptions<'_>,
@@ -219,6 +327,10 @@ impl ServerBackup {
             .into());
         }
 
+        let mut transaction = state.database.write().begin().await?;
+
+        Self::ensure_owner_storage_quota(&mut transaction, options.server).await?;
+
         let row = sqlx::query(&format!(
             r#"
             INSERT INTO server_backups (server_uuid, node_uuid, backup_configuration_uuid, name, ignored_files, bytes, disk)
@@ -234,12 +346,38 @@ impl ServerBackup {
         .bind(&options.ignored_files)
         .bind(0i64)
         .bind(backup_configuration.backup_disk)
-        .fetch_one(state.database.write())
+        .fetch_one(&mut *transaction)
         .await?;
 
+        Self::adjust_backup_counter(&mut transaction, options.server.uuid, 1).await?;
+
+        transaction.commit().await?;
+
         Ok(Self::map(None, &row)?)
     }
 
+    /// Adjusts the denormalized backup counter of a server,
+    /// has to run within the transaction that adds or removes the backup.
+    pub async fn adjust_backup_counter(
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        server_uuid: uuid::Uuid,
+        delta: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE servers
+            SET backup_count = servers.backup_count + $2
+            WHERE servers.uuid = $1
+            "#,
+        )
+        .bind(server_uuid)
+        .bind(delta)
+        .execute(&mut **transaction)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn by_server_uuid_uuid(
         database: &crate::database::Database,
         server_uuid: uuid::Uuid,
@@ -493,15 +631,92 @@ impl ServerBackup {
             .try_collect_vec()
     }
 
+    /// Returns the combined size in bytes of all backups of servers owned by a user.
+    pub async fn storage_used_by_owner_uuid(
+        database: &crate::database::Database,
+        owner_uuid: uuid::Uuid,
+    ) -> Result<i64, crate::database::DatabaseError> {
+        Ok(sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(SUM(server_backups.bytes), 0)::bigint
+            FROM server_backups
+            JOIN servers ON servers.uuid = server_backups.server_uuid
+            WHERE servers.owner_uuid = $1 AND server_backups.deleted IS NULL
+            "#,
+        )
+        .bind(owner_uuid)
+        .fetch_one(database.read())
+        .await?)
+    }
+
+    /// Fails with a `DisplayError` if the owner of the server has reached their backup storage limit,
+    /// has to run within the transaction that inserts the backup.
+    ///
+    /// The owner row is locked so concurrent backup creations are checked one after another.
+    /// Backups that are still in progress reserve the size of the last completed backup of their server.
+    pub async fn ensure_owner_storage_quota(
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        server: &super::server::Server,
+    ) -> Result<(), anyhow::Error> {
+        let limit: Option<i64> = sqlx::query_scalar(
+            r#"
+            SELECT users.backup_storage_limit
+            FROM users
+            WHERE users.uuid = $1
+            FOR UPDATE
+            "#,
+        )
+        .bind(server.owner.uuid)
+        .fetch_one(&mut **transaction)
+        .await?;
+
+        let Some(limit) = limit else {
+            return Ok(());
+        };
+
+        let used: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(SUM(
+                CASE WHEN server_backups.completed IS NULL THEN COALESCE((
+                    SELECT last_backups.bytes
+                    FROM server_backups last_backups
+                    WHERE last_backups.server_uuid = server_backups.server_uuid
+                        AND last_backups.completed IS NOT NULL AND last_backups.successful
+                    ORDER BY last_backups.completed DESC
+                    LIMIT 1
+                ), 0) ELSE server_backups.bytes END
+            ), 0)::bigint
+            FROM server_backups
+            JOIN servers ON servers.uuid = server_backups.server_uuid
+            WHERE servers.owner_uuid = $1 AND server_backups.deleted IS NULL
+            "#,
+        )
+        .bind(server.owner.uuid)
+        .fetch_one(&mut **transaction)
+        .await?;
+
+        if used >= limit.max(0).saturating_mul(1024 * 1024) {
+            return Err(crate::response::DisplayError::new(format!(
+                "backup storage quota of {} MiB reached ({} MiB used or reserved by running backups)",
+                limit,
+                used / 1024 / 1024
+            ))
+            .with_status(StatusCode::EXPECTATION_FAILED)
+            .into());
+        }
+
+        Ok(())
+    }
+
     pub async fn count_by_server_uuid(
         database: &crate::database::Database,
         server_uuid: uuid::Uuid,
     ) -> i64 {
         sqlx::query_scalar(
             r#"
-            SELECT COUNT(*)
-            FROM server_backups
-            WHERE server_backups.server_uuid = $1 AND server_backups.deleted IS NULL
+            SELECT servers.backup_count::int8
+            FROM servers
+            WHERE servers.uuid = $1
             "#,
         )
         .bind(server_uuid)
@@ -573,6 +788,8 @@ impl ServerBackup {
             unique_id: uuid::Uuid,
         }
 
+        let file_ttl = state.settings.jwt().await?.file_ttl;
+
         let token = node.create_jwt(
             &state.database,
             &state.jwt,
@@ -581,7 +798,7 @@ impl ServerBackup {
                     issuer: "panel".into(),
                     subject: None,
                     audience: Vec::new(),
-                    expiration_time: Some(chrono::Utc::now().timestamp() + 900),
+                    expiration_time: Some(chrono::Utc::now().timestamp() + file_ttl as i64),
                     not_before: None,
                     issued_at: Some(chrono::Utc::now().timestamp()),
                     jwt_id: user.uuid.to_string(),
@@ -602,6 +819,75 @@ impl ServerBackup {
         Ok(url.to_string())
     }
 
+    /// Ensures the backup will fit within the server's disk limit and the node's free
+    /// disk space once restored, taking the current disk usage reported by Wings into
+    /// account (existing files are freed first when the directory is truncated).
+    pub async fn ensure_fits_disk_limit(
+        &self,
+        database: &crate::database::Database,
+        server: &super::server::Server,
+        truncate_directory: bool,
+    ) -> Result<(), anyhow::Error> {
+        let node = server.node.fetch_cached(database).await?;
+        let backup_bytes = self.bytes.max(0) as u64;
+
+        let disk_usage = match node.fetch_server_resources(database).await {
+            Ok(resources) => resources
+                .get(&server.uuid)
+                .map(|resources| resources.disk_bytes)
+                .unwrap_or_default(),
+            Err(err) => {
+                tracing::warn!(
+                    server = %server.uuid,
+                    "failed to fetch server disk usage before restore: {:?}",
+                    err
+                );
+
+                0
+            }
+        };
+
+        if server.disk > 0 {
+            let disk_limit = (server.disk as u64).saturating_mul(1024 * 1024);
+            let required_bytes = if truncate_directory {
+                backup_bytes
+            } else {
+                disk_usage + backup_bytes
+            };
+
+            if required_bytes > disk_limit {
+                return Err(crate::response::DisplayError::new(format!(
+                    "backup does not fit within the server disk limit ({} MiB required, {} MiB available)",
+                    required_bytes.div_ceil(1024 * 1024),
+                    server.disk
+                ))
+                .with_status(StatusCode::EXPECTATION_FAILED)
+                .into());
+            }
+        }
+
+        let added_bytes = if truncate_directory {
+            backup_bytes.saturating_sub(disk_usage)
+        } else {
+            backup_bytes
+        };
+
+        let stats = node.fetch_system_stats(database).await?;
+        let free_bytes = stats.disk.total.saturating_sub(stats.disk.used);
+
+        if added_bytes > free_bytes {
+            return Err(crate::response::DisplayError::new(format!(
+                "node does not have enough free disk space to restore the backup ({} MiB required, {} MiB free)",
+                added_bytes.div_ceil(1024 * 1024),
+                free_bytes / 1024 / 1024
+            ))
+            .with_status(StatusCode::EXPECTATION_FAILED)
+            .into());
+        }
+
+        Ok(())
+    }
+
     pub async fn restore(
         self,
         database: &crate::database::Database,
@@ -720,7 +1006,7 @@ impl ServerBackup {
     pub async fn into_admin_node_api_object(
         self,
         database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
+        storage_url_retriever: &StorageUrlRetriever,
     ) -> Result<AdminApiNodeServerBackup, anyhow::Error> {
         Ok(AdminApiNodeServerBackup {
             uuid: self.uuid,
@@ -757,7 +1043,7 @@ impl ServerBackup {
     pub async fn into_admin_api_object(
         self,
         database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
+        storage_url_retriever: &StorageUrlRetriever,
     ) -> Result<AdminApiServerBackup, anyhow::Error> {
         Ok(AdminApiServerBackup {
             uuid: self.uuid,
@@ -857,6 +1143,8 @@ impl CreatableModel for ServerBackup {
 
         let mut transaction = state.database.write().begin().await?;
 
+        Self::ensure_owner_storage_quota(&mut transaction, options.server).await?;
+
         let mut query_builder = InsertQueryBuilder::new("server_backups");
 
         Self::run_create_handlers(&mut options, &mut query_builder, state, &mut transaction)
@@ -877,90 +1165,24 @@ impl CreatableModel for ServerBackup {
             .await?;
         let backup = Self::map(None, &row)?;
 
-        transaction.commit().await?;
-
-        let server = options.server.clone();
-        let database = Arc::clone(&state.database);
-        let backup_uuid = backup.uuid;
-        let backup_disk = backup_configuration.backup_disk;
-        let ignored_files_str = options
-            .ignored_files
-            .iter()
-            .map(|s| s.as_str())
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        tokio::spawn(async move {
-            tracing::debug!(backup = %backup_uuid, "creating server backup");
-
-            let node = match server.node.fetch_cached(&database).await {
-                Ok(node) => node,
-                Err(err) => {
-                    tracing::error!(backup = %backup_uuid, "failed to create server backup: {:?}", err);
-
-                    if let Err(err) = sqlx::query!(
-                        "UPDATE server_backups
-                        SET successful = false, completed = NOW()
-                        WHERE server_backups.uuid = $1",
-                        backup_uuid
-                    )
-                    .execute(database.write())
-                    .await
-                    {
-                        tracing::error!(backup = %backup_uuid, "failed to update server backup status: {:?}", err);
-                    }
-
-                    return;
-                }
-            };
-
-            let api_client = match node.api_client(&database).await {
-                Ok(api_client) => api_client,
-                Err(err) => {
-                    tracing::error!(backup = %backup_uuid, "failed to create server backup: {:?}", err);
+        Self::adjust_backup_counter(&mut transaction, options.server.uuid, 1).await?;
 
-                    if let Err(err) = sqlx::query!(
-                        "UPDATE server_backups
-                        SET successful = false, completed = NOW()
-                        WHERE server_backups.uuid = $1",
-                        backup_uuid
-                    )
-                    .execute(database.write())
-                    .await
-                    {
-                        tracing::error!(backup = %backup_uuid, "failed to update server backup status: {:?}", err);
-                    }
+        transaction.commit().await?;
 
-                    return;
-                }
-            };
+        if let Err(err) = crate::models::job::Job::create(
+            &state.database,
+            None,
+            &crate::models::job::JobPayload::ServerBackup(crate::models::job::ServerBackupJob {
+                server_uuid: options.server.uuid,
+                backup_uuid: backup.uuid,
+            }),
+        )
+        .await
+        {
+            Self::mark_failed(&state.database, backup.uuid).await?;
 
-            if let Err(err) = api_client
-                .post_servers_server_backup(
-                    server.uuid,
-                    &wings_api::servers_server_backup::post::RequestBody {
-                        adapter: backup_disk.to_wings_adapter(),
-                        uuid: backup_uuid,
-                        ignore: ignored_files_str.into(),
-                    },
-                )
-                .await
-            {
-                tracing::error!(backup = %backup_uuid, "failed to create server backup: {:?}", err);
-
-                if let Err(err) = sqlx::query!(
-                    "UPDATE server_backups
-                    SET successful = false, completed = NOW()
-                    WHERE server_backups.uuid = $1",
-                    backup_uuid
-                )
-                .execute(database.write())
-                .await
-                {
-                    tracing::error!(backup = %backup_uuid, "failed to update server backup status: {:?}", err);
-                }
-            }
-        });
+            return Err(err);
+        }
 
         Ok(backup)
     }
@@ -1028,6 +1250,8 @@ impl UpdatableModel for ServerBackup {
 
 #[async_trait::async_trait]
 impl ByUuid for ServerBackup {
+    const TABLE: &'static str = "server_backups";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -1107,9 +1331,16 @@ impl DeletableModel for ServerBackup {
 
                     sqlx::query(
                         r#"
-                        UPDATE server_backups
-                        SET deleted = NOW()
-                        WHERE server_backups.uuid = $1
+                        WITH backup AS (
+                            UPDATE server_backups
+                            SET deleted = NOW()
+                            WHERE server_backups.uuid = $1 AND server_backups.deleted IS NULL
+                            RETURNING server_backups.server_uuid
+                        )
+                        UPDATE servers
+                        SET backup_count = servers.backup_count - 1
+                        FROM backup
+                        WHERE servers.uuid = backup.server_uuid
                         "#,
                     )
                     .bind(backup_uuid)
@@ -1196,9 +1427,16 @@ impl DeletableModel for ServerBackup {
 
             sqlx::query(
                 r#"
-                UPDATE server_backups
-                SET deleted = NOW()
-                WHERE server_backups.uuid = $1
+                WITH backup AS (
+                    UPDATE server_backups
+                    SET deleted = NOW()
+                    WHERE server_backups.uuid = $1 AND server_backups.deleted IS NULL
+                    RETURNING server_backups.server_uuid
+                )
+                UPDATE servers
+                SET backup_count = servers.backup_count - 1
+                FROM backup
+                WHERE servers.uuid = backup.server_uuid
                 "#,
             )
             .bind(backup_uuid)
@@ -343,7 +343,7 @@ impl ServerDatabase {
     pub async fn into_admin_api_object(
         self,
         database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
+        storage_url_retriever: &StorageUrlRetriever,
     ) -> Result<AdminApiServerDatabase, anyhow::Error> {
         Ok(AdminApiServerDatabase {
             uuid: self.uuid,
@@ -559,7 +559,7 @@ impl CreatableModel for ServerDatabase {
 
         panel_transaction.commit().await?;
 
-        Self::by_uuid(&state.database, uuid)
+        Self::by_uuid(&state.database.primary(), uuid)
             .await?
             .ok_or(sqlx::Error::RowNotFound.into())
     }
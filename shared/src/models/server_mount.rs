@@ -270,7 +270,7 @@ impl ServerMount {
     pub async fn into_admin_server_api_object(
         self,
         database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
+        storage_url_retriever: &StorageUrlRetriever,
     ) -> Result<AdminApiServerServerMount, anyhow::Error> {
         let created = match self.created {
             Some(created) => created,
@@ -356,9 +356,13 @@ impl CreatableModel for ServerMount {
 
         transaction.commit().await?;
 
-        Self::by_server_uuid_mount_uuid(&state.database, options.server_uuid, options.mount_uuid)
-            .await?
-            .ok_or(sqlx::Error::RowNotFound.into())
+        Self::by_server_uuid_mount_uuid(
+            &state.database.primary(),
+            options.server_uuid,
+            options.mount_uuid,
+        )
+        .await?
+        .ok_or(sqlx::Error::RowNotFound.into())
     }
 }
 
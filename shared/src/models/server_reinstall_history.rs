@@ -0,0 +1,262 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// The amount of reinstall entries kept per server, older entries are removed on insert.
+pub const MAX_ENTRIES_PER_SERVER: i64 = 50;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServerReinstallHistory {
+    pub uuid: uuid::Uuid,
+    pub server_uuid: uuid::Uuid,
+
+    pub old_egg_uuid: Option<uuid::Uuid>,
+    pub new_egg_uuid: Option<uuid::Uuid>,
+    pub user_uuid: Option<uuid::Uuid>,
+
+    pub truncate_directory: bool,
+    pub successful: Option<bool>,
+
+    pub completed: Option<chrono::NaiveDateTime>,
+    pub created: chrono::NaiveDateTime,
+}
+
+impl BaseModel for ServerReinstallHistory {
+    const NAME: &'static str = "server_reinstall_history";
+
+    #[inline]
+    fn columns(prefix: Option<&str>) -> BTreeMap<&'static str, compact_str::CompactString> {
+        let prefix = prefix.unwrap_or_default();
+
+        BTreeMap::from([
+            (
+                "server_reinstall_history.uuid",
+                compact_str::format_compact!("{prefix}uuid"),
+            ),
+            (
+                "server_reinstall_history.server_uuid",
+                compact_str::format_compact!("{prefix}server_uuid"),
+            ),
+            (
+                "server_reinstall_history.old_egg_uuid",
+                compact_str::format_compact!("{prefix}old_egg_uuid"),
+            ),
+            (
+                "server_reinstall_history.new_egg_uuid",
+                compact_str::format_compact!("{prefix}new_egg_uuid"),
+            ),
+            (
+                "server_reinstall_history.user_uuid",
+                compact_str::format_compact!("{prefix}user_uuid"),
+            ),
+            (
+                "server_reinstall_history.truncate_directory",
+                compact_str::format_compact!("{prefix}truncate_directory"),
+            ),
+            (
+                "server_reinstall_history.successful",
+                compact_str::format_compact!("{prefix}successful"),
+            ),
+            (
+                "server_reinstall_history.completed",
+                compact_str::format_compact!("{prefix}completed"),
+            ),
+            (
+                "server_reinstall_history.created",
+                compact_str::format_compact!("{prefix}created"),
+            ),
+        ])
+    }
+
+    #[inline]
+    fn map(prefix: Option<&str>, row: &PgRow) -> Result<Self, crate::database::DatabaseError> {
+        let prefix = prefix.unwrap_or_default();
+
+        Ok(Self {
+            uuid: row.try_get(compact_str::format_compact!("{prefix}uuid").as_str())?,
+            server_uuid: row
+                .try_get(compact_str::format_compact!("{prefix}server_uuid").as_str())?,
+            old_egg_uuid: row
+                .try_get(compact_str::format_compact!("{prefix}old_egg_uuid").as_str())?,
+            new_egg_uuid: row
+                .try_get(compact_str::format_compact!("{prefix}new_egg_uuid").as_str())?,
+            user_uuid: row.try_get(compact_str::format_compact!("{prefix}user_uuid").as_str())?,
+            truncate_directory: row
+                .try_get(compact_str::format_compact!("{prefix}truncate_directory").as_str())?,
+            successful: row.try_get(compact_str::format_compact!("{prefix}successful").as_str())?,
+            completed: row.try_get(compact_str::format_compact!("{prefix}completed").as_str())?,
+            created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
+        })
+    }
+}
+
+impl ServerReinstallHistory {
+    /// Records the initial installation of a newly created server, so the egg it was created with
+    /// is known to its first reinstall. Servers created without running the installer get a
+    /// completed entry without a result.
+    pub async fn create_initial(
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        server_uuid: uuid::Uuid,
+        egg_uuid: uuid::Uuid,
+        installing: bool,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO server_reinstall_history (server_uuid, new_egg_uuid, truncate_directory, completed)
+            VALUES ($1, $2, false, CASE WHEN $3 THEN NULL ELSE NOW() END)
+            "#,
+        )
+        .bind(server_uuid)
+        .bind(egg_uuid)
+        .bind(installing)
+        .execute(&mut **transaction)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records a reinstall, has to run within the transaction that starts the installation.
+    /// The old egg is the egg used by the previous entry of the server, which starts out with the
+    /// entry of its initial installation.
+    pub async fn create(
+        transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        server_uuid: uuid::Uuid,
+        egg_uuid: uuid::Uuid,
+        user_uuid: Option<uuid::Uuid>,
+        truncate_directory: bool,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO server_reinstall_history (server_uuid, old_egg_uuid, new_egg_uuid, user_uuid, truncate_directory)
+            VALUES ($1, (
+                SELECT previous.new_egg_uuid
+                FROM server_reinstall_history previous
+                WHERE previous.server_uuid = $1
+                ORDER BY previous.created DESC
+                LIMIT 1
+            ), $2, $3, $4)
+            "#,
+        )
+        .bind(server_uuid)
+        .bind(egg_uuid)
+        .bind(user_uuid)
+        .bind(truncate_directory)
+        .execute(&mut **transaction)
+        .await?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM server_reinstall_history
+            WHERE server_reinstall_history.server_uuid = $1 AND server_reinstall_history.uuid NOT IN (
+                SELECT kept.uuid
+                FROM server_reinstall_history kept
+                WHERE kept.server_uuid = $1
+                ORDER BY kept.created DESC
+                LIMIT $2
+            )
+            "#,
+        )
+        .bind(server_uuid)
+        .bind(MAX_ENTRIES_PER_SERVER)
+        .execute(&mut **transaction)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Stores the result of the latest unfinished reinstall of a server.
+    pub async fn complete_latest(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+        successful: bool,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE server_reinstall_history
+            SET successful = $2, completed = NOW()
+            WHERE server_reinstall_history.uuid = (
+                SELECT latest.uuid
+                FROM server_reinstall_history latest
+                WHERE latest.server_uuid = $1 AND latest.completed IS NULL
+                ORDER BY latest.created DESC
+                LIMIT 1
+            )
+            "#,
+        )
+        .bind(server_uuid)
+        .bind(successful)
+        .execute(database.write())
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn by_server_uuid_with_pagination(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+        page: i64,
+        per_page: i64,
+    ) -> Result<super::Pagination<Self>, crate::database::DatabaseError> {
+        let offset = (page - 1) * per_page;
+
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}, COUNT(*) OVER() AS total_count
+            FROM server_reinstall_history
+            WHERE server_reinstall_history.server_uuid = $1
+            ORDER BY server_reinstall_history.created DESC
+            LIMIT $2 OFFSET $3
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(server_uuid)
+        .bind(per_page)
+        .bind(offset)
+        .fetch_all(database.read())
+        .await?;
+
+        Ok(super::Pagination {
+            total: rows
+                .first()
+                .map_or(Ok(0), |row| row.try_get("total_count"))?,
+            per_page,
+            page,
+            data: rows
+                .into_iter()
+                .map(|row| Self::map(None, &row))
+                .try_collect_vec()?,
+        })
+    }
+
+    #[inline]
+    pub fn into_admin_api_object(self) -> AdminApiServerReinstallHistory {
+        AdminApiServerReinstallHistory {
+            uuid: self.uuid,
+            old_egg_uuid: self.old_egg_uuid,
+            new_egg_uuid: self.new_egg_uuid,
+            user_uuid: self.user_uuid,
+            truncate_directory: self.truncate_directory,
+            successful: self.successful,
+            completed: self.completed.map(|dt| dt.and_utc()),
+            created: self.created.and_utc(),
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+#[schema(title = "ServerReinstallHistory")]
+pub struct AdminApiServerReinstallHistory {
+    pub uuid: uuid::Uuid,
+
+    pub old_egg_uuid: Option<uuid::Uuid>,
+    pub new_egg_uuid: Option<uuid::Uuid>,
+    pub user_uuid: Option<uuid::Uuid>,
+
+    pub truncate_directory: bool,
+    pub successful: Option<bool>,
+
+    pub completed: Option<chrono::DateTime<chrono::Utc>>,
+    pub created: chrono::DateTime<chrono::Utc>,
+}
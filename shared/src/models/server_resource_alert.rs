@@ -0,0 +1,363 @@
+use crate::{models::EventEmittingModel, prelude::*};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::LazyLock,
+};
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceAlertResource {
+    Cpu,
+    Memory,
+}
+
+impl ResourceAlertResource {
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cpu => "cpu",
+            Self::Memory => "memory",
+        }
+    }
+}
+
+/// The thresholds that apply to a server, either its own or the ones of its egg.
+struct EffectiveResourceAlert {
+    server_uuid: uuid::Uuid,
+    cpu: i32,
+
+    cpu_threshold: Option<i16>,
+    memory_threshold: Option<i16>,
+    window_seconds: i32,
+}
+
+/// Tracks since when a resource has been above its threshold and whether an alert was already sent.
+static BREACHES: LazyLock<
+    Mutex<HashMap<(uuid::Uuid, ResourceAlertResource), (std::time::Instant, bool)>>,
+> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServerResourceAlert {
+    pub server_uuid: uuid::Uuid,
+
+    pub cpu_threshold: Option<i16>,
+    pub memory_threshold: Option<i16>,
+    pub window_seconds: i32,
+
+    pub created: chrono::NaiveDateTime,
+}
+
+impl BaseModel for ServerResourceAlert {
+    const NAME: &'static str = "server_resource_alert";
+
+    #[inline]
+    fn columns(prefix: Option<&str>) -> BTreeMap<&'static str, compact_str::CompactString> {
+        let prefix = prefix.unwrap_or_default();
+
+        BTreeMap::from([
+            (
+                "server_resource_alerts.server_uuid",
+                compact_str::format_compact!("{prefix}server_uuid"),
+            ),
+            (
+                "server_resource_alerts.cpu_threshold",
+                compact_str::format_compact!("{prefix}cpu_threshold"),
+            ),
+            (
+                "server_resource_alerts.memory_threshold",
+                compact_str::format_compact!("{prefix}memory_threshold"),
+            ),
+            (
+                "server_resource_alerts.window_seconds",
+                compact_str::format_compact!("{prefix}window_seconds"),
+            ),
+            (
+                "server_resource_alerts.created",
+                compact_str::format_compact!("{prefix}created"),
+            ),
+        ])
+    }
+
+    #[inline]
+    fn map(prefix: Option<&str>, row: &PgRow) -> Result<Self, crate::database::DatabaseError> {
+        let prefix = prefix.unwrap_or_default();
+
+        Ok(Self {
+            server_uuid: row
+                .try_get(compact_str::format_compact!("{prefix}server_uuid").as_str())?,
+            cpu_threshold: row
+                .try_get(compact_str::format_compact!("{prefix}cpu_threshold").as_str())?,
+            memory_threshold: row
+                .try_get(compact_str::format_compact!("{prefix}memory_threshold").as_str())?,
+            window_seconds: row
+                .try_get(compact_str::format_compact!("{prefix}window_seconds").as_str())?,
+            created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
+        })
+    }
+}
+
+impl ServerResourceAlert {
+    pub async fn by_server_uuid(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+    ) -> Result<Option<Self>, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM server_resource_alerts
+            WHERE server_resource_alerts.server_uuid = $1
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(server_uuid)
+        .fetch_optional(database.read())
+        .await?;
+
+        row.try_map(|row| Self::map(None, &row))
+    }
+
+    pub async fn upsert(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+        cpu_threshold: Option<i16>,
+        memory_threshold: Option<i16>,
+        window_seconds: i32,
+    ) -> Result<Self, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO server_resource_alerts (server_uuid, cpu_threshold, memory_threshold, window_seconds)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (server_uuid) DO UPDATE SET
+                cpu_threshold = EXCLUDED.cpu_threshold,
+                memory_threshold = EXCLUDED.memory_threshold,
+                window_seconds = EXCLUDED.window_seconds
+            RETURNING {}
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(server_uuid)
+        .bind(cpu_threshold)
+        .bind(memory_threshold)
+        .bind(window_seconds)
+        .fetch_one(database.write())
+        .await?;
+
+        Self::map(None, &row)
+    }
+
+    pub async fn delete_by_server_uuid(
+        database: &crate::database::Database,
+        server_uuid: uuid::Uuid,
+    ) -> Result<bool, crate::database::DatabaseError> {
+        let deleted = sqlx::query(
+            r#"
+            DELETE FROM server_resource_alerts
+            WHERE server_resource_alerts.server_uuid = $1
+            "#,
+        )
+        .bind(server_uuid)
+        .execute(database.write())
+        .await?
+        .rows_affected();
+
+        Ok(deleted > 0)
+    }
+
+    /// Evaluates all configured alerts against the current node utilization.
+    /// Servers without an alert of their own use the alert of their egg, if any.
+    /// An alert fires once a threshold has been exceeded for the whole window,
+    /// and can only fire again after usage dropped back below the threshold.
+    ///
+    /// CPU usage is relative to the server's cpu limit, so cpu thresholds are
+    /// skipped for servers without one.
+    pub async fn evaluate_all(state: &crate::State) -> Result<(), anyhow::Error> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                servers.uuid AS server_uuid,
+                servers.node_uuid,
+                servers.cpu,
+                CASE WHEN server_resource_alerts.server_uuid IS NULL
+                    THEN nest_egg_resource_alerts.cpu_threshold
+                    ELSE server_resource_alerts.cpu_threshold
+                END AS cpu_threshold,
+                CASE WHEN server_resource_alerts.server_uuid IS NULL
+                    THEN nest_egg_resource_alerts.memory_threshold
+                    ELSE server_resource_alerts.memory_threshold
+                END AS memory_threshold,
+                COALESCE(
+                    server_resource_alerts.window_seconds,
+                    nest_egg_resource_alerts.window_seconds
+                ) AS window_seconds
+            FROM servers
+            LEFT JOIN server_resource_alerts ON server_resource_alerts.server_uuid = servers.uuid
+            LEFT JOIN nest_egg_resource_alerts ON nest_egg_resource_alerts.egg_uuid = servers.egg_uuid
+            WHERE servers.suspended = false
+                AND (server_resource_alerts.server_uuid IS NOT NULL OR nest_egg_resource_alerts.egg_uuid IS NOT NULL)
+            "#,
+        )
+        .fetch_all(state.database.read())
+        .await?;
+
+        let mut alerts_by_node: HashMap<uuid::Uuid, Vec<EffectiveResourceAlert>> = HashMap::new();
+        for row in rows {
+            alerts_by_node
+                .entry(row.try_get("node_uuid")?)
+                .or_default()
+                .push(EffectiveResourceAlert {
+                    server_uuid: row.try_get("server_uuid")?,
+                    cpu: row.try_get("cpu")?,
+                    cpu_threshold: row.try_get("cpu_threshold")?,
+                    memory_threshold: row.try_get("memory_threshold")?,
+                    window_seconds: row.try_get("window_seconds")?,
+                });
+        }
+
+        // forget breaches of servers whose alert was removed or that got suspended
+        let alerted_servers = alerts_by_node
+            .values()
+            .flatten()
+            .map(|alert| alert.server_uuid)
+            .collect::<HashSet<_>>();
+        BREACHES
+            .lock()
+            .await
+            .retain(|(server_uuid, _), _| alerted_servers.contains(server_uuid));
+
+        let now = std::time::Instant::now();
+        let mut fired = Vec::new();
+
+        for (node_uuid, alerts) in alerts_by_node {
+            let node = match super::node::Node::by_uuid_optional_cached(&state.database, node_uuid)
+                .await?
+            {
+                Some(node) => node,
+                None => continue,
+            };
+
+            let resources = match node.fetch_server_resources(&state.database).await {
+                Ok(resources) => resources,
+                Err(err) => {
+                    tracing::warn!(node = %node.uuid, "failed to fetch server resources for alerts: {:?}", err);
+                    continue;
+                }
+            };
+
+            let mut breaches = BREACHES.lock().await;
+            for alert in alerts {
+                let Some(usage) = resources.get(&alert.server_uuid) else {
+                    breaches.retain(|(server_uuid, _), _| *server_uuid != alert.server_uuid);
+                    continue;
+                };
+
+                let cpu_usage =
+                    (alert.cpu > 0).then(|| usage.cpu_absolute / alert.cpu as f64 * 100.0);
+                let memory_usage = (usage.memory_limit_bytes > 0)
+                    .then(|| usage.memory_bytes as f64 / usage.memory_limit_bytes as f64 * 100.0);
+
+                for (resource, threshold, usage) in [
+                    (ResourceAlertResource::Cpu, alert.cpu_threshold, cpu_usage),
+                    (
+                        ResourceAlertResource::Memory,
+                        alert.memory_threshold,
+                        memory_usage,
+                    ),
+                ] {
+                    let key = (alert.server_uuid, resource);
+
+                    let (Some(threshold), Some(usage)) = (threshold, usage) else {
+                        breaches.remove(&key);
+                        continue;
+                    };
+
+                    if usage < threshold as f64 {
+                        breaches.remove(&key);
+                        continue;
+                    }
+
+                    let (since, alerted) = breaches.entry(key).or_insert((now, false));
+                    if !*alerted
+                        && now.duration_since(*since).as_secs() >= alert.window_seconds as u64
+                    {
+                        *alerted = true;
+                        fired.push((alert.server_uuid, resource, usage, threshold));
+                    }
+                }
+            }
+        }
+
+        for (server_uuid, resource, usage, threshold) in fired {
+            let server = match super::server::Server::by_uuid_optional(&state.database, server_uuid)
+                .await?
+            {
+                Some(server) => server,
+                None => continue,
+            };
+
+            let settings = state.settings.get().await?;
+            let app_name = settings.app.name.clone();
+            let app_url = settings.app.url.clone();
+            drop(settings);
+
+            state
+                .mail
+                .send_notification(
+                    &state.database,
+                    server.owner.uuid,
+                    super::user_notification_preferences::NotificationCategory::ResourceAlerts,
+                    server.owner.email.clone(),
+                    format!("{app_name} - Resource Alert for {}", server.name).into(),
+                    crate::mail::MAIL_RESOURCE_ALERT,
+                    minijinja::context! {
+                        user => &server.owner,
+                        server_name => &server.name,
+                        server_link => format!(
+                            "{}/server/{:08x}",
+                            app_url.trim_end_matches('/'),
+                            server.uuid_short
+                        ),
+                        resource => resource.as_str(),
+                        usage => format!("{usage:.1}"),
+                        threshold => threshold,
+                    },
+                )
+                .await;
+
+            super::server::Server::get_event_emitter().emit(
+                state.clone(),
+                super::server::ServerEvent::ResourceAlert {
+                    server: Box::new(server),
+                    resource,
+                    usage,
+                    threshold,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn into_admin_api_object(self) -> AdminApiServerResourceAlert {
+        AdminApiServerResourceAlert {
+            cpu_threshold: self.cpu_threshold,
+            memory_threshold: self.memory_threshold,
+            window_seconds: self.window_seconds,
+            created: self.created.and_utc(),
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+#[schema(title = "ServerResourceAlert")]
+pub struct AdminApiServerResourceAlert {
+    pub cpu_threshold: Option<i16>,
+    pub memory_threshold: Option<i16>,
+    pub window_seconds: i32,
+
+    pub created: chrono::DateTime<chrono::Utc>,
+}
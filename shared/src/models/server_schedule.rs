@@ -4,13 +4,61 @@ use crate::{
 };
 use garde::Validate;
 use serde::{Deserialize, Serialize};
-use sqlx::{Row, postgres::PgRow};
+use sqlx::{Row, Type, postgres::PgRow};
 use std::{
     collections::BTreeMap,
     sync::{Arc, LazyLock},
 };
 use utoipa::ToSchema;
 
+/// How long a trigger counts as executing, covers the window until the node reports the run as running.
+pub const TRIGGER_LEASE_TTL: u64 = 30;
+/// How long a queued run waits for the current run to finish before it is dropped.
+pub const QUEUED_RUN_TTL: u64 = 60 * 60;
+
+/// How the panel handles a trigger while a run of the schedule is still executing.
+/// Only triggers going through the panel are guarded, the node runs its own cron and event
+/// triggers without knowing about the overlap behavior.
+#[derive(ToSchema, Serialize, Deserialize, Type, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+#[schema(rename_all = "snake_case")]
+#[sqlx(
+    type_name = "server_schedule_overlap_behavior",
+    rename_all = "SCREAMING_SNAKE_CASE"
+)]
+pub enum ServerScheduleOverlapBehavior {
+    #[default]
+    Skip,
+    Queue,
+}
+
+impl ServerScheduleOverlapBehavior {
+    /// The outcome of a trigger, depending on whether a run of the schedule is already executing.
+    #[inline]
+    pub fn trigger_result(self, executing: bool) -> ServerScheduleTriggerResult {
+        match (executing, self) {
+            (false, _) => ServerScheduleTriggerResult::Triggered,
+            (true, Self::Skip) => ServerScheduleTriggerResult::Skipped,
+            (true, Self::Queue) => ServerScheduleTriggerResult::Queued,
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+#[schema(rename_all = "snake_case")]
+pub enum ServerScheduleTriggerResult {
+    Triggered,
+    Skipped,
+    Queued,
+}
+
+#[derive(Serialize, Deserialize)]
+struct QueuedServerScheduleRun {
+    server_uuid: uuid::Uuid,
+    skip_condition: bool,
+}
+
 #[derive(ToSchema, Validate, Serialize, Deserialize)]
 pub struct ExportedServerSchedule {
     #[garde(length(chars, min = 1, max = 255))]
@@ -23,6 +71,9 @@ pub struct ExportedServerSchedule {
     pub triggers: Vec<wings_api::ScheduleTrigger>,
     #[garde(dive)]
     pub condition: wings_api::SchedulePreCondition,
+    #[garde(skip)]
+    #[serde(default)]
+    pub overlap_behavior: ServerScheduleOverlapBehavior,
 
     #[garde(dive)]
     pub steps: Vec<super::server_schedule_step::ExportedServerScheduleStep>,
@@ -37,9 +88,12 @@ pub struct ServerSchedule {
 
     pub triggers: Vec<wings_api::ScheduleTrigger>,
     pub condition: wings_api::SchedulePreCondition,
+    pub overlap_behavior: ServerScheduleOverlapBehavior,
 
     pub last_run: Option<chrono::NaiveDateTime>,
     pub last_failure: Option<chrono::NaiveDateTime>,
+    pub skipped_runs: i32,
+    pub last_skipped: Option<chrono::NaiveDateTime>,
     pub created: chrono::NaiveDateTime,
 }
 
@@ -71,6 +125,10 @@ impl BaseModel for ServerSchedule {
                 "server_schedules.condition",
                 compact_str::format_compact!("{prefix}condition"),
             ),
+            (
+                "server_schedules.overlap_behavior",
+                compact_str::format_compact!("{prefix}overlap_behavior"),
+            ),
             (
                 "server_schedules.last_run",
                 compact_str::format_compact!("{prefix}last_run"),
@@ -79,6 +137,14 @@ impl BaseModel for ServerSchedule {
                 "server_schedules.last_failure",
                 compact_str::format_compact!("{prefix}last_failure"),
             ),
+            (
+                "server_schedules.skipped_runs",
+                compact_str::format_compact!("{prefix}skipped_runs"),
+            ),
+            (
+                "server_schedules.last_skipped",
+                compact_str::format_compact!("{prefix}last_skipped"),
+            ),
             (
                 "server_schedules.created",
                 compact_str::format_compact!("{prefix}created"),
@@ -100,9 +166,15 @@ impl BaseModel for ServerSchedule {
             condition: serde_json::from_value(
                 row.try_get(compact_str::format_compact!("{prefix}condition").as_str())?,
             )?,
+            overlap_behavior: row
+                .try_get(compact_str::format_compact!("{prefix}overlap_behavior").as_str())?,
             last_run: row.try_get(compact_str::format_compact!("{prefix}last_run").as_str())?,
             last_failure: row
                 .try_get(compact_str::format_compact!("{prefix}last_failure").as_str())?,
+            skipped_runs: row
+                .try_get(compact_str::format_compact!("{prefix}skipped_runs").as_str())?,
+            last_skipped: row
+                .try_get(compact_str::format_compact!("{prefix}last_skipped").as_str())?,
             created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
         })
     }
@@ -186,6 +258,198 @@ impl ServerSchedule {
         .unwrap_or(0)
     }
 
+    #[inline]
+    fn trigger_lease_key(&self) -> compact_str::CompactString {
+        compact_str::format_compact!("server_schedule::{}::trigger_lease", self.uuid)
+    }
+
+    #[inline]
+    fn queued_run_key(&self) -> compact_str::CompactString {
+        compact_str::format_compact!("server_schedule::{}::queued_run", self.uuid)
+    }
+
+    #[inline]
+    fn trigger_lock_id(&self) -> compact_str::CompactString {
+        compact_str::format_compact!("server_schedule::{}::trigger", self.uuid)
+    }
+
+    /// Whether a run of this schedule is currently executing. The node is the source of truth,
+    /// so runs started by its own triggers count as well, not only runs triggered through the panel.
+    pub async fn is_executing(
+        &self,
+        state: &crate::State,
+        server: &super::server::Server,
+    ) -> Result<bool, anyhow::Error> {
+        if state
+            .cache
+            .get::<bool>(&self.trigger_lease_key())
+            .await?
+            .is_some()
+        {
+            return Ok(true);
+        }
+
+        let status = server
+            .node
+            .fetch_cached(&state.database)
+            .await?
+            .api_client(&state.database)
+            .await?
+            .get_servers_server_schedules_schedule(server.uuid, self.uuid)
+            .await?;
+
+        Ok(status.status.running)
+    }
+
+    /// Whether a run of this schedule is waiting for the current run to finish.
+    pub async fn has_queued_run(&self, cache: &crate::cache::Cache) -> Result<bool, anyhow::Error> {
+        Ok(cache
+            .get::<QueuedServerScheduleRun>(&self.queued_run_key())
+            .await?
+            .is_some())
+    }
+
+    pub async fn record_skipped_run(
+        &mut self,
+        database: &crate::database::Database,
+    ) -> Result<(), crate::database::DatabaseError> {
+        let row = sqlx::query(
+            r#"
+            UPDATE server_schedules
+            SET skipped_runs = skipped_runs + 1, last_skipped = NOW()
+            WHERE server_schedules.uuid = $1
+            RETURNING server_schedules.skipped_runs, server_schedules.last_skipped
+            "#,
+        )
+        .bind(self.uuid)
+        .fetch_one(database.write())
+        .await?;
+
+        self.skipped_runs = row.try_get("skipped_runs")?;
+        self.last_skipped = row.try_get("last_skipped")?;
+
+        Ok(())
+    }
+
+    /// Triggers the schedule on the node. If a run is still executing on the node, whether it was
+    /// triggered through the panel or by the node itself, the trigger is skipped or queued based on
+    /// the overlap behavior. This only guards triggers going through the panel, runs the node
+    /// starts on its own are not checked against the overlap behavior.
+    pub async fn trigger(
+        &mut self,
+        state: &crate::State,
+        server: &super::server::Server,
+        skip_condition: bool,
+    ) -> Result<ServerScheduleTriggerResult, anyhow::Error> {
+        let _trigger_lock = state
+            .cache
+            .lock(self.trigger_lock_id(), Some(30), Some(5))
+            .await?;
+
+        let executing = self.is_executing(state, server).await?;
+
+        match self.overlap_behavior.trigger_result(executing) {
+            ServerScheduleTriggerResult::Triggered => {}
+            ServerScheduleTriggerResult::Skipped => {
+                self.record_skipped_run(&state.database).await?;
+
+                return Ok(ServerScheduleTriggerResult::Skipped);
+            }
+            ServerScheduleTriggerResult::Queued => {
+                state
+                    .cache
+                    .set(
+                        &self.queued_run_key(),
+                        QUEUED_RUN_TTL,
+                        &QueuedServerScheduleRun {
+                            server_uuid: server.uuid,
+                            skip_condition,
+                        },
+                    )
+                    .await?;
+
+                return Ok(ServerScheduleTriggerResult::Queued);
+            }
+        }
+
+        state
+            .cache
+            .set(&self.trigger_lease_key(), TRIGGER_LEASE_TTL, &true)
+            .await?;
+
+        let result = async {
+            server
+                .node
+                .fetch_cached(&state.database)
+                .await?
+                .api_client(&state.database)
+                .await?
+                .post_servers_server_schedules_schedule_trigger(
+                    server.uuid,
+                    self.uuid,
+                    &wings_api::servers_server_schedules_schedule_trigger::post::RequestBody {
+                        skip_condition,
+                    },
+                )
+                .await?;
+
+            Ok::<_, anyhow::Error>(())
+        }
+        .await;
+
+        if let Err(err) = result {
+            state.cache.invalidate(&self.trigger_lease_key()).await?;
+
+            return Err(err);
+        }
+
+        Ok(ServerScheduleTriggerResult::Triggered)
+    }
+
+    /// Called when the node reported a finished run, starts the queued run if there is one.
+    /// Reports do not identify the run they belong to, so the queued run is only started once
+    /// the node no longer reports any run of the schedule as executing.
+    pub async fn finish_execution(&mut self, state: &crate::State) -> Result<(), anyhow::Error> {
+        let trigger_lock = state
+            .cache
+            .lock(self.trigger_lock_id(), Some(30), Some(5))
+            .await?;
+
+        state.cache.invalidate(&self.trigger_lease_key()).await?;
+
+        let Some(queued_run) = state
+            .cache
+            .get::<QueuedServerScheduleRun>(&self.queued_run_key())
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let server =
+            match super::server::Server::by_uuid_optional(&state.database, queued_run.server_uuid)
+                .await?
+            {
+                Some(server) => server,
+                None => {
+                    state.cache.invalidate(&self.queued_run_key()).await?;
+
+                    return Ok(());
+                }
+            };
+
+        if self.is_executing(state, &server).await? {
+            return Ok(());
+        }
+
+        state.cache.invalidate(&self.queued_run_key()).await?;
+        drop(trigger_lock);
+
+        self.trigger(state, &server, queued_run.skip_condition)
+            .await?;
+
+        Ok(())
+    }
+
     #[inline]
     pub async fn into_exported(
         self,
@@ -196,6 +460,7 @@ impl ServerSchedule {
             enabled: self.enabled,
             triggers: self.triggers,
             condition: self.condition,
+            overlap_behavior: self.overlap_behavior,
             steps: super::server_schedule_step::ServerScheduleStep::all_by_schedule_uuid(
                 database, self.uuid,
             )
@@ -214,8 +479,11 @@ impl ServerSchedule {
             enabled: self.enabled,
             triggers: self.triggers,
             condition: self.condition,
+            overlap_behavior: self.overlap_behavior,
             last_run: self.last_run.map(|dt| dt.and_utc()),
             last_failure: self.last_failure.map(|dt| dt.and_utc()),
+            skipped_runs: self.skipped_runs,
+            last_skipped: self.last_skipped.map(|dt| dt.and_utc()),
             created: self.created.and_utc(),
         }
     }
@@ -234,6 +502,8 @@ pub struct CreateServerScheduleOptions {
     pub triggers: Vec<wings_api::ScheduleTrigger>,
     #[garde(dive)]
     pub condition: wings_api::SchedulePreCondition,
+    #[garde(skip)]
+    pub overlap_behavior: ServerScheduleOverlapBehavior,
 }
 
 #[async_trait::async_trait]
@@ -266,7 +536,8 @@ impl CreatableModel for ServerSchedule {
             .set("name", &options.name)
             .set("enabled", options.enabled)
             .set("triggers", serde_json::to_value(&options.triggers)?)
-            .set("condition", serde_json::to_value(&options.condition)?);
+            .set("condition", serde_json::to_value(&options.condition)?)
+            .set("overlap_behavior", options.overlap_behavior);
 
         let row = query_builder
             .returning(&Self::columns_sql(None))
@@ -291,6 +562,8 @@ pub struct UpdateServerScheduleOptions {
     pub triggers: Option<Vec<wings_api::ScheduleTrigger>>,
     #[garde(dive)]
     pub condition: Option<wings_api::SchedulePreCondition>,
+    #[garde(skip)]
+    pub overlap_behavior: Option<ServerScheduleOverlapBehavior>,
 }
 
 #[async_trait::async_trait]
@@ -343,6 +616,7 @@ impl UpdatableModel for ServerSchedule {
                     None
                 },
             )
+            .set("overlap_behavior", options.overlap_behavior)
             .where_eq("uuid", self.uuid);
 
         query_builder.execute(&mut *transaction).await?;
@@ -359,6 +633,9 @@ impl UpdatableModel for ServerSchedule {
         if let Some(condition) = options.condition {
             self.condition = condition;
         }
+        if let Some(overlap_behavior) = options.overlap_behavior {
+            self.overlap_behavior = overlap_behavior;
+        }
 
         transaction.commit().await?;
 
@@ -368,6 +645,8 @@ impl UpdatableModel for ServerSchedule {
 
 #[async_trait::async_trait]
 impl ByUuid for ServerSchedule {
+    const TABLE: &'static str = "server_schedules";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -435,8 +714,62 @@ pub struct ApiServerSchedule {
 
     pub triggers: Vec<wings_api::ScheduleTrigger>,
     pub condition: wings_api::SchedulePreCondition,
+    pub overlap_behavior: ServerScheduleOverlapBehavior,
 
     pub last_run: Option<chrono::DateTime<chrono::Utc>>,
     pub last_failure: Option<chrono::DateTime<chrono::Utc>>,
+    pub skipped_runs: i32,
+    pub last_skipped: Option<chrono::DateTime<chrono::Utc>>,
     pub created: chrono::DateTime<chrono::Utc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triggers_when_not_executing() {
+        for behavior in [
+            ServerScheduleOverlapBehavior::Skip,
+            ServerScheduleOverlapBehavior::Queue,
+        ] {
+            assert_eq!(
+                behavior.trigger_result(false),
+                ServerScheduleTriggerResult::Triggered
+            );
+        }
+    }
+
+    #[test]
+    fn applies_overlap_behavior_when_executing() {
+        assert_eq!(
+            ServerScheduleOverlapBehavior::Skip.trigger_result(true),
+            ServerScheduleTriggerResult::Skipped
+        );
+        assert_eq!(
+            ServerScheduleOverlapBehavior::Queue.trigger_result(true),
+            ServerScheduleTriggerResult::Queued
+        );
+    }
+
+    #[test]
+    fn defaults_to_skipping_overlapping_runs() {
+        assert!(matches!(
+            ServerScheduleOverlapBehavior::default(),
+            ServerScheduleOverlapBehavior::Skip
+        ));
+
+        let exported: ExportedServerSchedule = serde_json::from_value(serde_json::json!({
+            "name": "backup",
+            "enabled": true,
+            "triggers": [],
+            "condition": { "type": "none" },
+            "steps": [],
+        }))
+        .unwrap();
+        assert!(matches!(
+            exported.overlap_behavior,
+            ServerScheduleOverlapBehavior::Skip
+        ));
+    }
+}
@@ -158,10 +158,7 @@ impl ServerSubuser {
     }
 
     #[inline]
-    pub fn into_api_object(
-        self,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
-    ) -> ApiServerSubuser {
+    pub fn into_api_object(self, storage_url_retriever: &StorageUrlRetriever) -> ApiServerSubuser {
         ApiServerSubuser {
             user: self.user.into_api_object(storage_url_retriever),
             permissions: self.permissions,
@@ -231,6 +228,7 @@ impl CreatableModel for ServerSubuser {
                     password: None,
                     admin: false,
                     language: app_settings.app.language.clone(),
+                    backup_storage_limit: None,
                 };
                 drop(app_settings);
                 let user = match super::user::User::create(state, create_options).await {
@@ -241,15 +239,16 @@ impl CreatableModel for ServerSubuser {
                     }
                 };
 
+                let settings = state.settings.get().await?;
+
                 match super::user_password_reset::UserPasswordReset::create(
                     &state.database,
                     user.uuid,
+                    settings.app.password_reset_expiry_minutes,
                 )
                 .await
                 {
                     Ok(token) => {
-                        let settings = state.settings.get().await?;
-
                         super::user_activity::UserActivity::create(
                             state,
                             super::user_activity::CreateUserActivityOptions {
@@ -318,15 +317,18 @@ impl CreatableModel for ServerSubuser {
 
         transaction.commit().await?;
 
-        let subuser =
-            Self::by_server_uuid_username(&state.database, options.server.uuid, &user.username)
-                .await?
-                .ok_or_else(|| {
-                    anyhow::anyhow!(
-                        "subuser with username {} not found after creation",
-                        user.username
-                    )
-                })?;
+        let subuser = Self::by_server_uuid_username(
+            &state.database.primary(),
+            options.server.uuid,
+            &user.username,
+        )
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "subuser with username {} not found after creation",
+                user.username
+            )
+        })?;
 
         Ok(subuser)
     }
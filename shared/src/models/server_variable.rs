@@ -1,9 +1,102 @@
 use crate::prelude::*;
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, postgres::PgRow};
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::LazyLock,
+};
 use utoipa::ToSchema;
 
+static VARIABLE_REFERENCE_REGEX: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r"\{\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}\}").expect("invalid regex")
+});
+
+/// Resolves `{{OTHER_VAR}}` references inside variable values.
+///
+/// Takes `(env_variable, value, resolve_references)` tuples, only variables that opted into
+/// reference resolution get their references expanded. References to unknown variables are
+/// left untouched, reference cycles result in an error.
+pub fn resolve_variable_references<'a>(
+    variables: impl IntoIterator<Item = (&'a str, &'a str, bool)>,
+) -> Result<HashMap<&'a str, String>, anyhow::Error> {
+    fn resolve<'a>(
+        env_variable: &'a str,
+        variables: &HashMap<&'a str, (&'a str, bool)>,
+        resolved: &mut HashMap<&'a str, String>,
+        stack: &mut Vec<&'a str>,
+    ) -> Result<(), anyhow::Error> {
+        if resolved.contains_key(env_variable) {
+            return Ok(());
+        }
+
+        let Some(&(value, resolve_references)) = variables.get(env_variable) else {
+            return Ok(());
+        };
+
+        if !resolve_references {
+            resolved.insert(env_variable, value.to_string());
+            return Ok(());
+        }
+
+        if stack.contains(&env_variable) {
+            stack.push(env_variable);
+
+            return Err(crate::response::DisplayError::new(format!(
+                "variable reference cycle detected: {}",
+                stack.join(" -> ")
+            ))
+            .into());
+        }
+
+        stack.push(env_variable);
+
+        let mut result = String::with_capacity(value.len());
+        let mut last_end = 0;
+
+        for captures in VARIABLE_REFERENCE_REGEX.captures_iter(value) {
+            let (Some(full), Some(name)) = (captures.get(0), captures.get(1)) else {
+                continue;
+            };
+
+            result.push_str(&value[last_end..full.start()]);
+
+            match variables.get_key_value(name.as_str()) {
+                Some((&reference, _)) => {
+                    resolve(reference, variables, resolved, stack)?;
+                    result.push_str(&resolved[reference]);
+                }
+                None => result.push_str(full.as_str()),
+            }
+
+            last_end = full.end();
+        }
+
+        result.push_str(&value[last_end..]);
+
+        stack.pop();
+        resolved.insert(env_variable, result);
+
+        Ok(())
+    }
+
+    let variables = variables
+        .into_iter()
+        .map(|(env_variable, value, resolve_references)| {
+            (env_variable, (value, resolve_references))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut resolved = HashMap::new();
+    resolved.reserve(variables.len());
+    let mut stack = Vec::new();
+
+    for &env_variable in variables.keys() {
+        resolve(env_variable, &variables, &mut resolved, &mut stack)?;
+    }
+
+    Ok(resolved)
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ServerVariable {
     pub variable: super::nest_egg_variable::NestEggVariable,
@@ -110,6 +203,26 @@ impl ServerVariable {
             .try_collect_vec()
     }
 
+    /// Resolves references between the given variables for display to a server user.
+    /// Only user viewable, non-secret variables can be referenced, references to any
+    /// other variable are left untouched so their values never leak into the result.
+    pub fn resolve_user_viewable_references(
+        variables: &[Self],
+    ) -> Result<HashMap<&str, String>, anyhow::Error> {
+        resolve_variable_references(
+            variables
+                .iter()
+                .filter(|variable| variable.variable.user_viewable && !variable.variable.secret)
+                .map(|variable| {
+                    (
+                        variable.variable.env_variable.as_str(),
+                        variable.value.as_str(),
+                        variable.variable.resolve_references,
+                    )
+                }),
+        )
+    }
+
     #[inline]
     pub fn into_api_object(self) -> ApiServerVariable {
         ApiServerVariable {
@@ -141,3 +254,94 @@ pub struct ApiServerVariable {
 
     pub created: chrono::DateTime<chrono::Utc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variable(
+        env_variable: &str,
+        value: &str,
+        resolve_references: bool,
+        user_viewable: bool,
+        secret: bool,
+    ) -> ServerVariable {
+        ServerVariable {
+            variable: crate::models::nest_egg_variable::NestEggVariable {
+                uuid: uuid::Uuid::new_v4(),
+                name: env_variable.into(),
+                description: None,
+                order: 0,
+                env_variable: env_variable.into(),
+                default_value: None,
+                user_viewable,
+                user_editable: false,
+                secret,
+                resolve_references,
+                rules: Vec::new(),
+                created: chrono::Utc::now().naive_utc(),
+            },
+            value: value.to_string(),
+            created: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    #[test]
+    fn resolves_nested_references() {
+        let resolved = resolve_variable_references([
+            ("A", "{{B}}-{{ C }}", true),
+            ("B", "b{{C}}", true),
+            ("C", "c", false),
+        ])
+        .unwrap();
+
+        assert_eq!(resolved["A"], "bc-c");
+        assert_eq!(resolved["B"], "bc");
+        assert_eq!(resolved["C"], "c");
+    }
+
+    #[test]
+    fn only_resolves_opted_in_variables() {
+        let resolved =
+            resolve_variable_references([("A", "{{B}}", false), ("B", "b", false)]).unwrap();
+
+        assert_eq!(resolved["A"], "{{B}}");
+    }
+
+    #[test]
+    fn leaves_missing_references_untouched() {
+        let resolved =
+            resolve_variable_references([("A", "{{MISSING}}:{{B}}", true), ("B", "b", false)])
+                .unwrap();
+
+        assert_eq!(resolved["A"], "{{MISSING}}:b");
+    }
+
+    #[test]
+    fn rejects_reference_cycles() {
+        assert!(resolve_variable_references([("A", "{{B}}", true), ("B", "{{A}}", true)]).is_err());
+        assert!(resolve_variable_references([("A", "x{{A}}", true)]).is_err());
+    }
+
+    #[test]
+    fn does_not_resolve_hidden_references() {
+        let variables = [
+            variable(
+                "VISIBLE",
+                "{{HIDDEN}}|{{SECRET}}|{{PUBLIC}}",
+                true,
+                true,
+                false,
+            ),
+            variable("HIDDEN", "hidden-value", false, false, false),
+            variable("SECRET", "secret-value", false, true, true),
+            variable("PUBLIC", "public-value", false, true, false),
+        ];
+
+        let resolved = ServerVariable::resolve_user_viewable_references(&variables).unwrap();
+
+        assert_eq!(resolved["VISIBLE"], "{{HIDDEN}}|{{SECRET}}|public-value");
+        assert!(!resolved.contains_key("HIDDEN"));
+        assert!(!resolved.contains_key("SECRET"));
+    }
+}
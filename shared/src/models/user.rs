@@ -203,6 +203,9 @@ pub struct User {
     pub toast_position: UserToastPosition,
     pub start_on_grouped_servers: bool,
 
+    /// The maximum size of all backups across the user's servers in MiB, `None` is unlimited.
+    pub backup_storage_limit: Option<i64>,
+
     pub has_password: bool,
 
     pub created: chrono::NaiveDateTime,
@@ -263,6 +266,10 @@ impl BaseModel for User {
                 "users.start_on_grouped_servers",
                 compact_str::format_compact!("{prefix}start_on_grouped_servers"),
             ),
+            (
+                "users.backup_storage_limit",
+                compact_str::format_compact!("{prefix}backup_storage_limit"),
+            ),
             (
                 "(users.password IS NOT NULL)",
                 compact_str::format_compact!("{prefix}has_password"),
@@ -314,6 +321,8 @@ impl BaseModel for User {
             start_on_grouped_servers: row.try_get(
                 compact_str::format_compact!("{prefix}start_on_grouped_servers").as_str(),
             )?,
+            backup_storage_limit: row
+                .try_get(compact_str::format_compact!("{prefix}backup_storage_limit").as_str())?,
             has_password: row
                 .try_get(compact_str::format_compact!("{prefix}has_password").as_str())?,
             created: row.try_get(compact_str::format_compact!("{prefix}created").as_str())?,
@@ -329,11 +338,15 @@ impl User {
         name_first: &str,
         name_last: &str,
         password: &str,
+        role_uuid: Option<uuid::Uuid>,
     ) -> Result<uuid::Uuid, crate::database::DatabaseError> {
         let row = sqlx::query(
             r#"
-            INSERT INTO users (username, email, name_first, name_last, password, admin)
-            VALUES ($1, $2, $3, $4, crypt($5, gen_salt('bf', 8)), (SELECT COUNT(*) = 0 FROM users))
+            INSERT INTO users (username, email, name_first, name_last, password, admin, role_uuid)
+            VALUES (
+                $1, $2, $3, $4, crypt($5, gen_salt('bf', 8)), (SELECT COUNT(*) = 0 FROM users),
+                (SELECT roles.uuid FROM roles WHERE roles.uuid = $6)
+            )
             RETURNING users.uuid
             "#,
         )
@@ -342,6 +355,7 @@ impl User {
         .bind(name_first)
         .bind(name_last)
         .bind(password)
+        .bind(role_uuid)
         .fetch_one(database.write())
         .await?;
 
@@ -740,11 +754,45 @@ impl User {
         Ok(())
     }
 
-    pub fn require_two_factor(&self, settings: &crate::settings::AppSettings) -> bool {
+    /// Notifies the user about a security relevant change to their account.
+    pub async fn send_security_alert(
+        &self,
+        state: &crate::State,
+        action: &str,
+        ip: std::net::IpAddr,
+    ) {
+        let (app_name, app_url) = match state.settings.app().await {
+            Ok(app_settings) => (app_settings.name.clone(), app_settings.url.clone()),
+            Err(err) => {
+                tracing::error!(user = %self.uuid, "failed to get app settings: {:?}", err);
+                return;
+            }
+        };
+
+        state
+            .mail
+            .send_notification(
+                &state.database,
+                self.uuid,
+                super::user_notification_preferences::NotificationCategory::SecurityAlerts,
+                self.email.clone(),
+                format!("{app_name} - Security Alert").into(),
+                crate::mail::MAIL_SECURITY_ALERT,
+                minijinja::context! {
+                    user => self,
+                    action => action,
+                    ip => ip.to_string(),
+                    activity_link => format!("{}/account/activity", app_url.trim_end_matches('/')),
+                },
+            )
+            .await;
+    }
+
+    pub fn require_two_factor(&self, app_settings: &crate::settings::app::AppSettingsApp) -> bool {
         if let Some(role) = &self.role {
             role.require_two_factor
         } else {
-            match settings.app.two_factor_requirement {
+            match app_settings.two_factor_requirement {
                 crate::settings::app::TwoFactorRequirement::Admins => self.admin,
                 crate::settings::app::TwoFactorRequirement::AllUsers => true,
                 crate::settings::app::TwoFactorRequirement::None => false,
@@ -753,34 +801,33 @@ impl User {
     }
 
     #[inline]
-    pub fn into_api_object(self, storage_url_retriever: &StorageUrlRetriever<'_>) -> ApiUser {
+    pub fn into_api_object(self, storage_url_retriever: &StorageUrlRetriever) -> ApiUser {
         ApiUser {
             uuid: self.uuid,
             username: self.username,
-            avatar: self
-                .avatar
-                .as_ref()
-                .map(|a| storage_url_retriever.get_url(a)),
+            avatar: storage_url_retriever.get_avatar_url(
+                self.avatar.as_ref(),
+                self.uuid,
+                &self.email,
+            ),
             totp_enabled: self.totp_enabled,
             created: self.created.and_utc(),
         }
     }
 
     #[inline]
-    pub fn into_api_full_object(
-        self,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
-    ) -> ApiFullUser {
-        let require_two_factor = self.require_two_factor(storage_url_retriever.get_settings());
+    pub fn into_api_full_object(self, storage_url_retriever: &StorageUrlRetriever) -> ApiFullUser {
+        let require_two_factor = self.require_two_factor(storage_url_retriever.get_app_settings());
 
         ApiFullUser {
             uuid: self.uuid,
             username: self.username,
             role: self.role.map(|r| r.into_admin_api_object()),
-            avatar: self
-                .avatar
-                .as_ref()
-                .map(|a| storage_url_retriever.get_url(a)),
+            avatar: storage_url_retriever.get_avatar_url(
+                self.avatar.as_ref(),
+                self.uuid,
+                &self.email,
+            ),
             email: self.email,
             name_first: self.name_first,
             name_last: self.name_last,
@@ -791,6 +838,7 @@ impl User {
             language: self.language,
             toast_position: self.toast_position,
             start_on_grouped_servers: self.start_on_grouped_servers,
+            backup_storage_limit: self.backup_storage_limit,
             has_password: self.has_password,
             created: self.created.and_utc(),
         }
@@ -832,6 +880,11 @@ pub struct CreateUserOptions {
     )]
     #[schema(min_length = 2, max_length = 15)]
     pub language: compact_str::CompactString,
+
+    #[garde(inner(range(min = 0)))]
+    #[schema(minimum = 0)]
+    #[serde(default)]
+    pub backup_storage_limit: Option<i64>,
 }
 
 #[async_trait::async_trait]
@@ -852,6 +905,15 @@ impl CreatableModel for User {
     ) -> Result<Self, crate::database::DatabaseError> {
         options.validate()?;
 
+        if let Some(external_id) = &options.external_id {
+            state
+                .settings
+                .get()
+                .await?
+                .external_id
+                .validate_external_id(external_id)?;
+        }
+
         if let Some(role_uuid) = options.role_uuid {
             super::role::Role::by_uuid_optional_cached(&state.database, role_uuid)
                 .await?
@@ -879,7 +941,8 @@ impl CreatableModel for User {
 
         query_builder
             .set("admin", options.admin)
-            .set("language", &options.language);
+            .set("language", &options.language)
+            .set("backup_storage_limit", options.backup_storage_limit);
 
         let row = query_builder
             .returning("uuid")
@@ -889,7 +952,7 @@ impl CreatableModel for User {
 
         transaction.commit().await?;
 
-        Self::by_uuid(&state.database, uuid).await
+        Self::by_uuid(&state.database.primary(), uuid).await
     }
 }
 
@@ -938,6 +1001,15 @@ pub struct UpdateUserOptions {
     )]
     #[schema(min_length = 2, max_length = 15)]
     pub language: Option<compact_str::CompactString>,
+
+    #[garde(inner(inner(range(min = 0))))]
+    #[schema(minimum = 0)]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    pub backup_storage_limit: Option<Option<i64>>,
 }
 
 #[async_trait::async_trait]
@@ -958,6 +1030,15 @@ impl UpdatableModel for User {
     ) -> Result<(), crate::database::DatabaseError> {
         options.validate()?;
 
+        if let Some(Some(external_id)) = &options.external_id {
+            state
+                .settings
+                .get()
+                .await?
+                .external_id
+                .validate_external_id(external_id)?;
+        }
+
         let role = if let Some(role_uuid) = options.role_uuid {
             if let Some(role_uuid) = role_uuid {
                 Some(Some(
@@ -994,6 +1075,7 @@ impl UpdatableModel for User {
             .set("name_last", options.name_last.as_ref())
             .set("admin", options.admin)
             .set("language", options.language.as_ref())
+            .set("backup_storage_limit", options.backup_storage_limit)
             .where_eq("uuid", self.uuid);
 
         query_builder.execute(&mut *transaction).await?;
@@ -1022,6 +1104,9 @@ impl UpdatableModel for User {
         if let Some(language) = options.language {
             self.language = language;
         }
+        if let Some(backup_storage_limit) = options.backup_storage_limit {
+            self.backup_storage_limit = backup_storage_limit;
+        }
 
         transaction.commit().await?;
 
@@ -1075,6 +1160,11 @@ impl DeletableModel for User {
 
 #[async_trait::async_trait]
 impl ByUuid for User {
+    const TABLE: &'static str = "users";
+    const JOINS: &'static str = r#"
+        LEFT JOIN roles ON roles.uuid = users.role_uuid
+    "#;
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -1131,6 +1221,8 @@ pub struct ApiFullUser {
     pub toast_position: UserToastPosition,
     pub start_on_grouped_servers: bool,
 
+    pub backup_storage_limit: Option<i64>,
+
     pub has_password: bool,
 
     pub created: chrono::DateTime<chrono::Utc>,
@@ -4,7 +4,7 @@ use garde::Validate;
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, postgres::PgRow};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashMap},
     sync::{Arc, LazyLock},
 };
 use utoipa::ToSchema;
@@ -149,46 +149,73 @@ impl UserActivity {
         })
     }
 
+    /// Deletes all activity older than `cutoff` in batches of `batch_size` rows,
+    /// returning the total amount of deleted rows.
     pub async fn delete_older_than(
         database: &crate::database::Database,
         cutoff: chrono::DateTime<chrono::Utc>,
+        batch_size: i64,
     ) -> Result<u64, crate::database::DatabaseError> {
-        let result = sqlx::query(
-            r#"
-            DELETE FROM user_activities
-            WHERE created < $1
-            "#,
-        )
-        .bind(cutoff.naive_utc())
-        .execute(database.write())
-        .await?;
+        let mut deleted = 0;
+
+        loop {
+            let result = sqlx::query(
+                r#"
+                DELETE FROM user_activities
+                WHERE ctid IN (
+                    SELECT ctid
+                    FROM user_activities
+                    WHERE created < $1
+                    LIMIT $2
+                )
+                "#,
+            )
+            .bind(cutoff.naive_utc())
+            .bind(batch_size)
+            .execute(database.write())
+            .await?;
+
+            deleted += result.rows_affected();
+
+            if result.rows_affected() < batch_size as u64 {
+                break;
+            }
+        }
 
-        Ok(result.rows_affected())
+        Ok(deleted)
+    }
+
+    /// Loads the impersonators of the activities at once, for [`Self::into_api_object`].
+    pub async fn fetch_impersonators(
+        database: &crate::database::Database,
+        activities: &[Self],
+    ) -> Result<HashMap<uuid::Uuid, super::user::User>, anyhow::Error> {
+        Fetchable::fetch_many_cached(
+            database,
+            activities
+                .iter()
+                .filter_map(|activity| activity.impersonator.as_ref()),
+        )
+        .await
     }
 
     #[inline]
-    pub async fn into_api_object(
+    pub fn into_api_object(
         self,
-        database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
-    ) -> Result<ApiUserActivity, anyhow::Error> {
-        Ok(ApiUserActivity {
-            impersonator: if let Some(impersonator) = self.impersonator {
-                Some(
-                    impersonator
-                        .fetch_cached(database)
-                        .await?
-                        .into_api_object(storage_url_retriever),
-                )
-            } else {
-                None
-            },
+        impersonators: &HashMap<uuid::Uuid, super::user::User>,
+        storage_url_retriever: &StorageUrlRetriever,
+    ) -> ApiUserActivity {
+        ApiUserActivity {
+            impersonator: self
+                .impersonator
+                .and_then(|impersonator| impersonators.get(&impersonator.uuid))
+                .map(|impersonator| impersonator.clone().into_api_object(storage_url_retriever)),
             event: self.event,
             ip: self.ip.map(|ip| ip.ip().to_compact_string()),
             data: self.data,
             is_api: self.api_key.is_some(),
             created: self.created.and_utc(),
-        })
+        }
     }
 }
 
@@ -168,6 +168,22 @@ impl UserApiKey {
         })
     }
 
+    pub async fn delete_by_user_uuid(
+        database: &crate::database::Database,
+        user_uuid: uuid::Uuid,
+    ) -> Result<u64, sqlx::Error> {
+        Ok(sqlx::query(
+            r#"
+            DELETE FROM user_api_keys
+            WHERE user_api_keys.user_uuid = $1
+            "#,
+        )
+        .bind(user_uuid)
+        .execute(database.write())
+        .await?
+        .rows_affected())
+    }
+
     pub async fn delete_expired(database: &crate::database::Database) -> Result<u64, sqlx::Error> {
         Ok(sqlx::query(
             r#"
@@ -230,7 +246,7 @@ pub struct CreateUserApiKeyOptions {
     #[garde(length(chars, min = 3, max = 31))]
     #[schema(min_length = 3, max_length = 31)]
     pub name: compact_str::CompactString,
-    #[garde(skip)]
+    #[garde(custom(crate::utils::validate_ip_networks))]
     #[schema(value_type = Vec<String>)]
     pub allowed_ips: Vec<sqlx::types::ipnetwork::IpNetwork>,
 
@@ -303,7 +319,7 @@ pub struct UpdateUserApiKeyOptions {
     #[garde(length(chars, min = 3, max = 31))]
     #[schema(min_length = 3, max_length = 31)]
     pub name: Option<compact_str::CompactString>,
-    #[garde(skip)]
+    #[garde(inner(custom(crate::utils::validate_ip_networks)))]
     #[schema(value_type = Vec<String>)]
     pub allowed_ips: Option<Vec<sqlx::types::ipnetwork::IpNetwork>>,
 
@@ -398,6 +414,8 @@ impl UpdatableModel for UserApiKey {
 
 #[async_trait::async_trait]
 impl ByUuid for UserApiKey {
+    const TABLE: &'static str = "user_api_keys";
+
     async fn by_uuid(
         database: &crate::database::Database,
         uuid: uuid::Uuid,
@@ -416,6 +434,27 @@ impl ByUuid for UserApiKey {
 
         Self::map(None, &row)
     }
+
+    async fn by_uuids(
+        database: &crate::database::Database,
+        uuids: &[uuid::Uuid],
+    ) -> Result<std::collections::HashMap<uuid::Uuid, Self>, crate::database::DatabaseError> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM user_api_keys
+            WHERE user_api_keys.uuid = ANY($1) AND (user_api_keys.expires IS NULL OR user_api_keys.expires > NOW())
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(uuids)
+        .fetch_all(database.read())
+        .await?;
+
+        rows.into_iter()
+            .map(|row| Self::map(None, &row).map(|api_key| (api_key.uuid, api_key)))
+            .collect()
+    }
 }
 
 #[async_trait::async_trait]
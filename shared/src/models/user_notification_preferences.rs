@@ -0,0 +1,172 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationCategory {
+    BackupCompleted,
+    ServerSuspended,
+    SecurityAlerts,
+    ResourceAlerts,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserNotificationPreferences {
+    pub user_uuid: uuid::Uuid,
+
+    pub backup_completed: bool,
+    pub server_suspended: bool,
+    pub security_alerts: bool,
+    pub resource_alerts: bool,
+
+    pub updated: chrono::NaiveDateTime,
+}
+
+impl BaseModel for UserNotificationPreferences {
+    const NAME: &'static str = "user_notification_preferences";
+
+    #[inline]
+    fn columns(prefix: Option<&str>) -> BTreeMap<&'static str, compact_str::CompactString> {
+        let prefix = prefix.unwrap_or_default();
+
+        BTreeMap::from([
+            (
+                "user_notification_preferences.user_uuid",
+                compact_str::format_compact!("{prefix}user_uuid"),
+            ),
+            (
+                "user_notification_preferences.backup_completed",
+                compact_str::format_compact!("{prefix}backup_completed"),
+            ),
+            (
+                "user_notification_preferences.server_suspended",
+                compact_str::format_compact!("{prefix}server_suspended"),
+            ),
+            (
+                "user_notification_preferences.security_alerts",
+                compact_str::format_compact!("{prefix}security_alerts"),
+            ),
+            (
+                "user_notification_preferences.resource_alerts",
+                compact_str::format_compact!("{prefix}resource_alerts"),
+            ),
+            (
+                "user_notification_preferences.updated",
+                compact_str::format_compact!("{prefix}updated"),
+            ),
+        ])
+    }
+
+    #[inline]
+    fn map(prefix: Option<&str>, row: &PgRow) -> Result<Self, crate::database::DatabaseError> {
+        let prefix = prefix.unwrap_or_default();
+
+        Ok(Self {
+            user_uuid: row.try_get(compact_str::format_compact!("{prefix}user_uuid").as_str())?,
+            backup_completed: row
+                .try_get(compact_str::format_compact!("{prefix}backup_completed").as_str())?,
+            server_suspended: row
+                .try_get(compact_str::format_compact!("{prefix}server_suspended").as_str())?,
+            security_alerts: row
+                .try_get(compact_str::format_compact!("{prefix}security_alerts").as_str())?,
+            resource_alerts: row
+                .try_get(compact_str::format_compact!("{prefix}resource_alerts").as_str())?,
+            updated: row.try_get(compact_str::format_compact!("{prefix}updated").as_str())?,
+        })
+    }
+}
+
+impl UserNotificationPreferences {
+    /// Returns the preferences of a user, users without stored preferences receive everything.
+    pub async fn by_user_uuid(
+        database: &crate::database::Database,
+        user_uuid: uuid::Uuid,
+    ) -> Result<Self, crate::database::DatabaseError> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM user_notification_preferences
+            WHERE user_notification_preferences.user_uuid = $1
+            "#,
+            Self::columns_sql(None)
+        ))
+        .bind(user_uuid)
+        .fetch_optional(database.read())
+        .await?;
+
+        match row {
+            Some(row) => Self::map(None, &row),
+            None => Ok(Self {
+                user_uuid,
+                backup_completed: true,
+                server_suspended: true,
+                security_alerts: true,
+                resource_alerts: true,
+                updated: chrono::Utc::now().naive_utc(),
+            }),
+        }
+    }
+
+    pub async fn save(
+        &mut self,
+        database: &crate::database::Database,
+    ) -> Result<(), crate::database::DatabaseError> {
+        self.updated = sqlx::query_scalar(
+            r#"
+            INSERT INTO user_notification_preferences (user_uuid, backup_completed, server_suspended, security_alerts, resource_alerts)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_uuid) DO UPDATE SET
+                backup_completed = EXCLUDED.backup_completed,
+                server_suspended = EXCLUDED.server_suspended,
+                security_alerts = EXCLUDED.security_alerts,
+                resource_alerts = EXCLUDED.resource_alerts,
+                updated = NOW()
+            RETURNING user_notification_preferences.updated
+            "#,
+        )
+        .bind(self.user_uuid)
+        .bind(self.backup_completed)
+        .bind(self.server_suspended)
+        .bind(self.security_alerts)
+        .bind(self.resource_alerts)
+        .fetch_one(database.write())
+        .await?;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_enabled(&self, category: NotificationCategory) -> bool {
+        match category {
+            NotificationCategory::BackupCompleted => self.backup_completed,
+            NotificationCategory::ServerSuspended => self.server_suspended,
+            NotificationCategory::SecurityAlerts => self.security_alerts,
+            NotificationCategory::ResourceAlerts => self.resource_alerts,
+        }
+    }
+
+    #[inline]
+    pub fn into_api_object(self) -> ApiUserNotificationPreferences {
+        ApiUserNotificationPreferences {
+            backup_completed: self.backup_completed,
+            server_suspended: self.server_suspended,
+            security_alerts: self.security_alerts,
+            resource_alerts: self.resource_alerts,
+            updated: self.updated.and_utc(),
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+#[schema(title = "UserNotificationPreferences")]
+pub struct ApiUserNotificationPreferences {
+    pub backup_completed: bool,
+    pub server_suspended: bool,
+    pub security_alerts: bool,
+    pub resource_alerts: bool,
+
+    pub updated: chrono::DateTime<chrono::Utc>,
+}
@@ -215,6 +215,7 @@ impl UserOAuthLink {
         })
     }
 
+    /// Lists the links of an oauth provider, searching by link identifier and the linked user's username or email.
     pub async fn by_oauth_provider_uuid_with_pagination(
         database: &crate::database::Database,
         oauth_provider_uuid: uuid::Uuid,
@@ -228,7 +229,13 @@ impl UserOAuthLink {
             r#"
             SELECT {}, COUNT(*) OVER() AS total_count
             FROM user_oauth_links
-            WHERE user_oauth_links.oauth_provider_uuid = $1 AND ($2 IS NULL OR user_oauth_links.identifier ILIKE '%' || $2 || '%')
+            JOIN users ON users.uuid = user_oauth_links.user_uuid
+            WHERE user_oauth_links.oauth_provider_uuid = $1 AND (
+                $2 IS NULL
+                OR user_oauth_links.identifier ILIKE '%' || $2 || '%'
+                OR users.username ILIKE '%' || $2 || '%'
+                OR users.email ILIKE '%' || $2 || '%'
+            )
             ORDER BY user_oauth_links.created
             LIMIT $3 OFFSET $4
             "#,
@@ -258,7 +265,7 @@ impl UserOAuthLink {
     pub async fn into_admin_api_object(
         self,
         database: &crate::database::Database,
-        storage_url_retriever: &StorageUrlRetriever<'_>,
+        storage_url_retriever: &StorageUrlRetriever,
     ) -> Result<AdminApiUserOAuthLink, anyhow::Error> {
         Ok(AdminApiUserOAuthLink {
             uuid: self.uuid,
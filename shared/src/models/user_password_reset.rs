@@ -55,18 +55,22 @@ impl BaseModel for UserPasswordReset {
 }
 
 impl UserPasswordReset {
+    /// Creates a new password reset token, at most one can be requested per user
+    /// every 20 minutes (or the expiry, if it is shorter).
     pub async fn create(
         database: &crate::database::Database,
         user_uuid: uuid::Uuid,
+        expiry_minutes: u16,
     ) -> Result<String, anyhow::Error> {
         let existing = sqlx::query(
             r#"
             SELECT COUNT(*)
             FROM user_password_resets
-            WHERE user_password_resets.user_uuid = $1 AND user_password_resets.created > NOW() - INTERVAL '20 minutes'
+            WHERE user_password_resets.user_uuid = $1 AND user_password_resets.created > NOW() - make_interval(mins => $2)
             "#,
         )
         .bind(user_uuid)
+        .bind(expiry_minutes.min(20) as i32)
         .fetch_optional(database.read())
         .await?;
 
@@ -94,41 +98,50 @@ impl UserPasswordReset {
         Ok(token)
     }
 
+    /// Consumes a password reset token, it can only ever be used once.
+    /// Expired tokens are consumed as well, but return `None`.
     pub async fn delete_by_token(
         database: &crate::database::Database,
         token: &str,
+        expiry_minutes: u16,
     ) -> Result<Option<Self>, crate::database::DatabaseError> {
         let row = sqlx::query(&format!(
             r#"
+            WITH user_password_resets AS (
+                DELETE FROM user_password_resets
+                WHERE user_password_resets.token = crypt($1, user_password_resets.token)
+                RETURNING *
+            )
             SELECT {}, {} FROM user_password_resets
             JOIN users ON users.uuid = user_password_resets.user_uuid
             LEFT JOIN roles ON roles.uuid = users.role_uuid
-            WHERE
-                user_password_resets.token = crypt($1, user_password_resets.token)
-                AND user_password_resets.created > NOW() - INTERVAL '20 minutes'
+            WHERE user_password_resets.created > NOW() - make_interval(mins => $2)
             "#,
             Self::columns_sql(None),
             super::user::User::columns_sql(Some("user_"))
         ))
         .bind(token)
-        .fetch_optional(database.read())
+        .bind(expiry_minutes as i32)
+        .fetch_optional(database.write())
         .await?;
 
-        let row = match row {
-            Some(row) => row,
-            None => return Ok(None),
-        };
+        row.try_map(|row| Self::map(None, &row))
+    }
 
+    pub async fn delete_by_user_uuid(
+        database: &crate::database::Database,
+        user_uuid: uuid::Uuid,
+    ) -> Result<(), sqlx::Error> {
         sqlx::query(
             r#"
             DELETE FROM user_password_resets
-            WHERE user_password_resets.uuid = $1
+            WHERE user_password_resets.user_uuid = $1
             "#,
         )
-        .bind(row.get::<uuid::Uuid, _>("uuid"))
+        .bind(user_uuid)
         .execute(database.write())
         .await?;
 
-        Ok(Some(Self::map(None, &row)?))
+        Ok(())
     }
 }
@@ -256,6 +256,7 @@ pub(crate) static BASE_ADMIN_PERMISSIONS: LazyLock<IndexMap<&'static str, Permis
                             "oauth-links",
                             "Allows viewing and managing a user's OAuth links.",
                         ),
+                        ("api-keys", "Allows viewing and revoking a user's API keys."),
                         ("impersonate", "Allows impersonating other users."),
                     ]),
                 },
@@ -320,6 +321,10 @@ pub(crate) static BASE_ADMIN_PERMISSIONS: LazyLock<IndexMap<&'static str, Permis
                         ("update", "Allows modifying nodes."),
                         ("delete", "Allows deleting nodes."),
                         ("reset-token", "Allows resetting a node's token."),
+                        (
+                            "configuration",
+                            "Allows generating a node's configuration file, including its token.",
+                        ),
                         (
                             "allocations",
                             "Allows viewing and managing a node's allocations.",
@@ -470,10 +475,13 @@ pub(crate) static BASE_SERVER_PERMISSIONS: LazyLock<IndexMap<&'static str, Permi
                 PermissionGroup {
                     description: "Permissions that control the ability to control the power state of a server, read the console, or send commands.",
                     permissions: IndexMap::from([
-                        ("read-console", "Allows reading the server console logs."),
+                        (
+                            "read-console",
+                            "Allows reading the server console logs. Without the console permission the console is read-only.",
+                        ),
                         (
                             "console",
-                            "Allows sending commands to the server instance via the console.",
+                            "Allows sending commands to the server instance via the console, independent of reading its output.",
                         ),
                         ("start", "Allows starting the server if it is stopped."),
                         ("stop", "Allows stopping the server if it is running."),
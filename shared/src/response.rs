@@ -179,6 +179,10 @@ where
 
         if let Some(error) = err.downcast_ref::<DisplayError>() {
             return ApiResponse::error(&error.message).with_status(error.status);
+        } else if let Some(DatabaseError::Any(error)) = err.downcast_ref::<DatabaseError>()
+            && let Some(error) = error.downcast_ref::<DisplayError>()
+        {
+            return ApiResponse::error(&error.message).with_status(error.status);
         } else if let Some(DatabaseError::Validation(error)) = err.downcast_ref::<DatabaseError>() {
             let error_messages = crate::utils::flatten_validation_errors(error);
 
@@ -235,6 +239,11 @@ impl<'a> DisplayError<'a> {
 
         self
     }
+
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
 }
 
 impl<'a> Display for DisplayError<'a> {
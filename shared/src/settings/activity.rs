@@ -11,6 +11,7 @@ pub struct AppSettingsActivity {
     pub admin_log_retention_days: u16,
     pub user_log_retention_days: u16,
     pub server_log_retention_days: u16,
+    pub log_prune_batch_size: u32,
 
     pub server_log_admin_activity: bool,
     pub server_log_schedule_activity: bool,
@@ -35,6 +36,10 @@ impl SettingsSerializeExt for AppSettingsActivity {
                 "server_log_retention_days",
                 self.server_log_retention_days.to_compact_string(),
             )
+            .write_raw_setting(
+                "log_prune_batch_size",
+                self.log_prune_batch_size.to_compact_string(),
+            )
             .write_raw_setting(
                 "server_log_admin_activity",
                 self.server_log_admin_activity.to_compact_string(),
@@ -67,6 +72,11 @@ impl SettingsDeserializeExt for AppSettingsActivityDeserializer {
                 .take_raw_setting("server_log_retention_days")
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(90),
+            log_prune_batch_size: deserializer
+                .take_raw_setting("log_prune_batch_size")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(5000)
+                .max(1),
             server_log_admin_activity: deserializer
                 .take_raw_setting("server_log_admin_activity")
                 .map(|s| s == "true")
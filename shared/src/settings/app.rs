@@ -14,6 +14,14 @@ pub enum TwoFactorRequirement {
     None,
 }
 
+#[derive(ToSchema, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AvatarFallback {
+    None,
+    Gravatar,
+    Identicon,
+}
+
 #[derive(Clone, ToSchema, Serialize, Deserialize)]
 pub struct AppSettingsApp {
     pub name: compact_str::CompactString,
@@ -21,9 +29,11 @@ pub struct AppSettingsApp {
     pub url: compact_str::CompactString,
     pub language: compact_str::CompactString,
     pub two_factor_requirement: TwoFactorRequirement,
+    pub avatar_fallback: AvatarFallback,
 
     pub telemetry_enabled: bool,
     pub registration_enabled: bool,
+    pub password_reset_expiry_minutes: u16,
 }
 
 #[async_trait::async_trait]
@@ -45,6 +55,14 @@ impl SettingsSerializeExt for AppSettingsApp {
                     TwoFactorRequirement::None => "none",
                 },
             )
+            .write_raw_setting(
+                "avatar_fallback",
+                match self.avatar_fallback {
+                    AvatarFallback::None => "none",
+                    AvatarFallback::Gravatar => "gravatar",
+                    AvatarFallback::Identicon => "identicon",
+                },
+            )
             .write_raw_setting(
                 "telemetry_enabled",
                 self.telemetry_enabled.to_compact_string(),
@@ -52,6 +70,10 @@ impl SettingsSerializeExt for AppSettingsApp {
             .write_raw_setting(
                 "registration_enabled",
                 self.registration_enabled.to_compact_string(),
+            )
+            .write_raw_setting(
+                "password_reset_expiry_minutes",
+                self.password_reset_expiry_minutes.to_compact_string(),
             ))
     }
 }
@@ -85,6 +107,11 @@ impl SettingsDeserializeExt for AppSettingsAppDeserializer {
                 Some("all_users") => TwoFactorRequirement::AllUsers,
                 _ => TwoFactorRequirement::None,
             },
+            avatar_fallback: match deserializer.take_raw_setting("avatar_fallback").as_deref() {
+                Some("gravatar") => AvatarFallback::Gravatar,
+                Some("identicon") => AvatarFallback::Identicon,
+                _ => AvatarFallback::None,
+            },
             telemetry_enabled: deserializer
                 .take_raw_setting("telemetry_enabled")
                 .map(|s| s == "true")
@@ -93,6 +120,10 @@ impl SettingsDeserializeExt for AppSettingsAppDeserializer {
                 .take_raw_setting("registration_enabled")
                 .map(|s| s == "true")
                 .unwrap_or(true),
+            password_reset_expiry_minutes: deserializer
+                .take_raw_setting("password_reset_expiry_minutes")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(20),
         }))
     }
 }
@@ -0,0 +1,96 @@
+use super::{
+    ExtensionSettings, SettingsDeserializeExt, SettingsDeserializer, SettingsSerializeExt,
+    SettingsSerializer,
+};
+use compact_str::ToCompactString;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalAction {
+    NodeDelete,
+    ServerDelete,
+}
+
+impl ApprovalAction {
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NodeDelete => "node_delete",
+            Self::ServerDelete => "server_delete",
+        }
+    }
+
+    #[inline]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "node_delete" => Some(Self::NodeDelete),
+            "server_delete" => Some(Self::ServerDelete),
+            _ => None,
+        }
+    }
+
+    /// The admin permission required to approve an action of this type.
+    #[inline]
+    pub fn admin_permission(&self) -> &'static str {
+        match self {
+            Self::NodeDelete => "nodes.delete",
+            Self::ServerDelete => "servers.delete",
+        }
+    }
+}
+
+#[derive(Clone, ToSchema, Serialize, Deserialize)]
+pub struct AppSettingsApprovals {
+    /// Actions that need to be approved by a second admin before they are executed.
+    pub actions: Vec<ApprovalAction>,
+    pub expiry_minutes: u16,
+}
+
+impl AppSettingsApprovals {
+    #[inline]
+    pub fn requires_approval(&self, action: ApprovalAction) -> bool {
+        self.actions.contains(&action)
+    }
+}
+
+#[async_trait::async_trait]
+impl SettingsSerializeExt for AppSettingsApprovals {
+    async fn serialize(
+        &self,
+        serializer: SettingsSerializer,
+    ) -> Result<SettingsSerializer, anyhow::Error> {
+        Ok(serializer
+            .write_raw_setting(
+                "actions",
+                self.actions
+                    .iter()
+                    .map(|action| action.as_str())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
+            .write_raw_setting("expiry_minutes", self.expiry_minutes.to_compact_string()))
+    }
+}
+
+pub struct AppSettingsApprovalsDeserializer;
+
+#[async_trait::async_trait]
+impl SettingsDeserializeExt for AppSettingsApprovalsDeserializer {
+    async fn deserialize_boxed(
+        &self,
+        mut deserializer: SettingsDeserializer<'_>,
+    ) -> Result<ExtensionSettings, anyhow::Error> {
+        Ok(Box::new(AppSettingsApprovals {
+            actions: deserializer
+                .take_raw_setting("actions")
+                .map(|s| s.split(',').filter_map(ApprovalAction::parse).collect())
+                .unwrap_or_default(),
+            expiry_minutes: deserializer
+                .take_raw_setting("expiry_minutes")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(60),
+        }))
+    }
+}
@@ -0,0 +1,51 @@
+use super::{
+    ExtensionSettings, SettingsDeserializeExt, SettingsDeserializer, SettingsSerializeExt,
+    SettingsSerializer,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Clone, ToSchema, Serialize, Deserialize)]
+pub struct AppSettingsBranding {
+    pub logo_url: Option<compact_str::CompactString>,
+    pub primary_color: Option<compact_str::CompactString>,
+    pub footer_text: Option<compact_str::CompactString>,
+}
+
+#[async_trait::async_trait]
+impl SettingsSerializeExt for AppSettingsBranding {
+    async fn serialize(
+        &self,
+        serializer: SettingsSerializer,
+    ) -> Result<SettingsSerializer, anyhow::Error> {
+        Ok(serializer
+            .write_raw_setting("logo_url", self.logo_url.clone().unwrap_or_default())
+            .write_raw_setting(
+                "primary_color",
+                self.primary_color.clone().unwrap_or_default(),
+            )
+            .write_raw_setting("footer_text", self.footer_text.clone().unwrap_or_default()))
+    }
+}
+
+pub struct AppSettingsBrandingDeserializer;
+
+#[async_trait::async_trait]
+impl SettingsDeserializeExt for AppSettingsBrandingDeserializer {
+    async fn deserialize_boxed(
+        &self,
+        mut deserializer: SettingsDeserializer<'_>,
+    ) -> Result<ExtensionSettings, anyhow::Error> {
+        Ok(Box::new(AppSettingsBranding {
+            logo_url: deserializer
+                .take_raw_setting("logo_url")
+                .filter(|s| !s.is_empty()),
+            primary_color: deserializer
+                .take_raw_setting("primary_color")
+                .filter(|s| !s.is_empty()),
+            footer_text: deserializer
+                .take_raw_setting("footer_text")
+                .filter(|s| !s.is_empty()),
+        }))
+    }
+}
@@ -0,0 +1,103 @@
+use super::{
+    ExtensionSettings, SettingsDeserializeExt, SettingsDeserializer, SettingsSerializeExt,
+    SettingsSerializer,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalIdFormat {
+    None,
+    Prefix,
+    Regex,
+}
+
+#[derive(Clone, ToSchema, Serialize, Deserialize)]
+pub struct AppSettingsExternalId {
+    pub format: ExternalIdFormat,
+    pub pattern: compact_str::CompactString,
+}
+
+impl AppSettingsExternalId {
+    /// Compiles the configured pattern, the whole external id has to match it.
+    /// Returns `None` if the format is not `regex`.
+    pub fn regex(&self) -> Result<Option<regex::Regex>, regex::Error> {
+        if self.format != ExternalIdFormat::Regex {
+            return Ok(None);
+        }
+
+        regex::Regex::new(&format!("^(?:{})$", self.pattern)).map(Some)
+    }
+
+    /// Validates an external id against the configured format.
+    pub fn validate_external_id(&self, external_id: &str) -> Result<(), garde::Report> {
+        let valid = match self.format {
+            ExternalIdFormat::None => true,
+            ExternalIdFormat::Prefix => external_id.starts_with(self.pattern.as_str()),
+            ExternalIdFormat::Regex => match self.regex() {
+                Ok(Some(regex)) => regex.is_match(external_id),
+                Ok(None) => true,
+                Err(err) => {
+                    tracing::error!("invalid external id format regex: {:?}", err);
+                    false
+                }
+            },
+        };
+
+        if valid {
+            return Ok(());
+        }
+
+        let mut report = garde::Report::new();
+        report.append(
+            garde::Path::new("external_id"),
+            garde::Error::new(match self.format {
+                ExternalIdFormat::Prefix => {
+                    compact_str::format_compact!("must start with `{}`", self.pattern)
+                }
+                _ => compact_str::format_compact!("must match the pattern `{}`", self.pattern),
+            }),
+        );
+
+        Err(report)
+    }
+}
+
+#[async_trait::async_trait]
+impl SettingsSerializeExt for AppSettingsExternalId {
+    async fn serialize(
+        &self,
+        serializer: SettingsSerializer,
+    ) -> Result<SettingsSerializer, anyhow::Error> {
+        Ok(serializer
+            .write_raw_setting(
+                "format",
+                match self.format {
+                    ExternalIdFormat::None => "none",
+                    ExternalIdFormat::Prefix => "prefix",
+                    ExternalIdFormat::Regex => "regex",
+                },
+            )
+            .write_raw_setting("pattern", &*self.pattern))
+    }
+}
+
+pub struct AppSettingsExternalIdDeserializer;
+
+#[async_trait::async_trait]
+impl SettingsDeserializeExt for AppSettingsExternalIdDeserializer {
+    async fn deserialize_boxed(
+        &self,
+        mut deserializer: SettingsDeserializer<'_>,
+    ) -> Result<ExtensionSettings, anyhow::Error> {
+        Ok(Box::new(AppSettingsExternalId {
+            format: match deserializer.take_raw_setting("format").as_deref() {
+                Some("prefix") => ExternalIdFormat::Prefix,
+                Some("regex") => ExternalIdFormat::Regex,
+                _ => ExternalIdFormat::None,
+            },
+            pattern: deserializer.take_raw_setting("pattern").unwrap_or_default(),
+        }))
+    }
+}
@@ -0,0 +1,118 @@
+use super::{
+    ExtensionSettings, SettingsDeserializeExt, SettingsDeserializer, SettingsSerializeExt,
+    SettingsSerializer,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+pub fn validate_feature_flags(
+    flags: &BTreeMap<compact_str::CompactString, FeatureFlag>,
+    _context: &(),
+) -> Result<(), garde::Error> {
+    if flags.len() > 128 {
+        return Err(garde::Error::new(
+            "at most 128 feature flags may be specified",
+        ));
+    }
+
+    for name in flags.keys() {
+        if name.is_empty()
+            || name.len() > 64
+            || !name.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.')
+            })
+        {
+            return Err(garde::Error::new(compact_str::format_compact!(
+                "invalid feature flag name: {name}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, ToSchema, Serialize, Deserialize, Default)]
+pub struct FeatureFlag {
+    /// Whether the flag is enabled for everyone.
+    pub enabled: bool,
+    /// Roles the flag is enabled for when it is not globally enabled.
+    #[serde(default)]
+    pub role_uuids: Vec<uuid::Uuid>,
+    /// Users the flag is enabled for when it is not globally enabled.
+    #[serde(default)]
+    pub user_uuids: Vec<uuid::Uuid>,
+}
+
+impl FeatureFlag {
+    #[inline]
+    pub fn is_enabled_for(&self, user: Option<&crate::models::user::User>) -> bool {
+        if self.enabled {
+            return true;
+        }
+
+        let Some(user) = user else {
+            return false;
+        };
+
+        self.user_uuids.contains(&user.uuid)
+            || user
+                .role
+                .as_ref()
+                .is_some_and(|role| self.role_uuids.contains(&role.uuid))
+    }
+}
+
+#[derive(Clone, ToSchema, Serialize, Deserialize)]
+pub struct AppSettingsFeatureFlags {
+    pub flags: BTreeMap<compact_str::CompactString, FeatureFlag>,
+}
+
+impl AppSettingsFeatureFlags {
+    /// Returns whether a flag is enabled for the user, unknown flags are always disabled.
+    #[inline]
+    pub fn is_enabled(&self, flag: &str, user: Option<&crate::models::user::User>) -> bool {
+        self.flags
+            .get(flag)
+            .is_some_and(|feature_flag| feature_flag.is_enabled_for(user))
+    }
+
+    /// Returns the names of all flags enabled for the user.
+    pub fn enabled_for(
+        &self,
+        user: Option<&crate::models::user::User>,
+    ) -> Vec<compact_str::CompactString> {
+        self.flags
+            .iter()
+            .filter(|(_, feature_flag)| feature_flag.is_enabled_for(user))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl SettingsSerializeExt for AppSettingsFeatureFlags {
+    async fn serialize(
+        &self,
+        serializer: SettingsSerializer,
+    ) -> Result<SettingsSerializer, anyhow::Error> {
+        Ok(serializer.write_raw_setting("flags", serde_json::to_string(&self.flags)?))
+    }
+}
+
+pub struct AppSettingsFeatureFlagsDeserializer;
+
+#[async_trait::async_trait]
+impl SettingsDeserializeExt for AppSettingsFeatureFlagsDeserializer {
+    async fn deserialize_boxed(
+        &self,
+        mut deserializer: SettingsDeserializer<'_>,
+    ) -> Result<ExtensionSettings, anyhow::Error> {
+        Ok(Box::new(AppSettingsFeatureFlags {
+            flags: deserializer
+                .take_raw_setting("flags")
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default(),
+        }))
+    }
+}
@@ -0,0 +1,59 @@
+use super::{
+    ExtensionSettings, SettingsDeserializeExt, SettingsDeserializer, SettingsSerializeExt,
+    SettingsSerializer,
+};
+use compact_str::ToCompactString;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Lifetimes of the JWTs issued by the panel, in seconds.
+#[derive(Clone, ToSchema, Serialize, Deserialize)]
+pub struct AppSettingsJwt {
+    pub two_factor_ttl: u32,
+    pub websocket_ttl: u32,
+    pub file_ttl: u32,
+    pub transfer_ttl: u32,
+}
+
+#[async_trait::async_trait]
+impl SettingsSerializeExt for AppSettingsJwt {
+    async fn serialize(
+        &self,
+        serializer: SettingsSerializer,
+    ) -> Result<SettingsSerializer, anyhow::Error> {
+        Ok(serializer
+            .write_raw_setting("two_factor_ttl", self.two_factor_ttl.to_compact_string())
+            .write_raw_setting("websocket_ttl", self.websocket_ttl.to_compact_string())
+            .write_raw_setting("file_ttl", self.file_ttl.to_compact_string())
+            .write_raw_setting("transfer_ttl", self.transfer_ttl.to_compact_string()))
+    }
+}
+
+pub struct AppSettingsJwtDeserializer;
+
+#[async_trait::async_trait]
+impl SettingsDeserializeExt for AppSettingsJwtDeserializer {
+    async fn deserialize_boxed(
+        &self,
+        mut deserializer: SettingsDeserializer<'_>,
+    ) -> Result<ExtensionSettings, anyhow::Error> {
+        Ok(Box::new(AppSettingsJwt {
+            two_factor_ttl: deserializer
+                .take_raw_setting("two_factor_ttl")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(300),
+            websocket_ttl: deserializer
+                .take_raw_setting("websocket_ttl")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(600),
+            file_ttl: deserializer
+                .take_raw_setting("file_ttl")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(900),
+            transfer_ttl: deserializer
+                .take_raw_setting("transfer_ttl")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(600),
+        }))
+    }
+}
@@ -0,0 +1,63 @@
+use super::{
+    ExtensionSettings, SettingsDeserializeExt, SettingsDeserializer, SettingsSerializeExt,
+    SettingsSerializer,
+};
+use compact_str::ToCompactString;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Clone, ToSchema, Serialize, Deserialize)]
+pub struct AppSettingsMail {
+    pub smtp_pool_size: u32,
+    pub max_retries: u32,
+    pub retry_backoff_ms: u64,
+    pub queue_size: u32,
+}
+
+#[async_trait::async_trait]
+impl SettingsSerializeExt for AppSettingsMail {
+    async fn serialize(
+        &self,
+        serializer: SettingsSerializer,
+    ) -> Result<SettingsSerializer, anyhow::Error> {
+        Ok(serializer
+            .write_raw_setting("smtp_pool_size", self.smtp_pool_size.to_compact_string())
+            .write_raw_setting("max_retries", self.max_retries.to_compact_string())
+            .write_raw_setting(
+                "retry_backoff_ms",
+                self.retry_backoff_ms.to_compact_string(),
+            )
+            .write_raw_setting("queue_size", self.queue_size.to_compact_string()))
+    }
+}
+
+pub struct AppSettingsMailDeserializer;
+
+#[async_trait::async_trait]
+impl SettingsDeserializeExt for AppSettingsMailDeserializer {
+    async fn deserialize_boxed(
+        &self,
+        mut deserializer: SettingsDeserializer<'_>,
+    ) -> Result<ExtensionSettings, anyhow::Error> {
+        Ok(Box::new(AppSettingsMail {
+            smtp_pool_size: deserializer
+                .take_raw_setting("smtp_pool_size")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(4u32)
+                .max(1),
+            max_retries: deserializer
+                .take_raw_setting("max_retries")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(3),
+            retry_backoff_ms: deserializer
+                .take_raw_setting("retry_backoff_ms")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(1000),
+            queue_size: deserializer
+                .take_raw_setting("queue_size")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(256u32)
+                .max(1),
+        }))
+    }
+}
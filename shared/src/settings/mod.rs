@@ -6,6 +6,7 @@ use crate::{
     },
     prelude::{AsyncOptionExt, StringExt},
 };
+use arc_swap::ArcSwap;
 use compact_str::ToCompactString;
 use garde::Validate;
 use serde::{Deserialize, Serialize};
@@ -24,7 +25,16 @@ use utoipa::ToSchema;
 
 pub mod activity;
 pub mod app;
+pub mod approvals;
+pub mod branding;
+pub mod external_id;
+pub mod feature_flags;
+pub mod jwt;
+pub mod mail;
+pub mod pagination;
 pub mod server;
+pub mod support;
+pub mod users;
 pub mod webauthn;
 
 #[derive(ToSchema, Validate, Serialize, Deserialize, Clone)]
@@ -222,12 +232,39 @@ pub struct AppSettings {
     pub server: server::AppSettingsServer,
     #[schema(inline)]
     pub activity: activity::AppSettingsActivity,
+    #[schema(inline)]
+    pub pagination: pagination::AppSettingsPagination,
+    #[schema(inline)]
+    pub support: support::AppSettingsSupport,
+    #[schema(inline)]
+    pub branding: branding::AppSettingsBranding,
+    #[schema(inline)]
+    pub mail: mail::AppSettingsMail,
+    #[schema(inline)]
+    pub external_id: external_id::AppSettingsExternalId,
+    #[schema(inline)]
+    pub jwt: jwt::AppSettingsJwt,
+    #[schema(inline)]
+    pub approvals: approvals::AppSettingsApprovals,
+    #[schema(inline)]
+    pub users: users::AppSettingsUsers,
+    #[schema(inline)]
+    pub feature_flags: feature_flags::AppSettingsFeatureFlags,
 
     #[serde(skip)]
     pub extensions: HashMap<&'static str, ExtensionSettings>,
 }
 
 impl AppSettings {
+    /// Propagates settings that are read outside of a request context
+    /// (e.g. while deserializing query params) to their global counterparts.
+    fn apply_runtime_limits(&self) {
+        crate::models::Pagination::set_per_page_limits(
+            self.pagination.default_per_page as i64,
+            self.pagination.max_per_page as i64,
+        );
+    }
+
     pub fn get_extension_settings<T: 'static>(
         &self,
         ext_identifier: &str,
@@ -462,6 +499,24 @@ impl SettingsSerializeExt for AppSettings {
             .nest("server", &self.server)
             .await?
             .nest("activity", &self.activity)
+            .await?
+            .nest("pagination", &self.pagination)
+            .await?
+            .nest("support", &self.support)
+            .await?
+            .nest("branding", &self.branding)
+            .await?
+            .nest("mail", &self.mail)
+            .await?
+            .nest("external_id", &self.external_id)
+            .await?
+            .nest("jwt", &self.jwt)
+            .await?
+            .nest("approvals", &self.approvals)
+            .await?
+            .nest("users", &self.users)
+            .await?
+            .nest("feature_flags", &self.feature_flags)
             .await?;
 
         for (ext_identifier, ext_settings) in self.extensions.iter() {
@@ -694,6 +749,39 @@ impl SettingsDeserializeExt for AppSettingsDeserializer {
             activity: deserializer
                 .nest("activity", &activity::AppSettingsActivityDeserializer)
                 .await?,
+            pagination: deserializer
+                .nest("pagination", &pagination::AppSettingsPaginationDeserializer)
+                .await?,
+            support: deserializer
+                .nest("support", &support::AppSettingsSupportDeserializer)
+                .await?,
+            branding: deserializer
+                .nest("branding", &branding::AppSettingsBrandingDeserializer)
+                .await?,
+            mail: deserializer
+                .nest("mail", &mail::AppSettingsMailDeserializer)
+                .await?,
+            external_id: deserializer
+                .nest(
+                    "external_id",
+                    &external_id::AppSettingsExternalIdDeserializer,
+                )
+                .await?,
+            jwt: deserializer
+                .nest("jwt", &jwt::AppSettingsJwtDeserializer)
+                .await?,
+            approvals: deserializer
+                .nest("approvals", &approvals::AppSettingsApprovalsDeserializer)
+                .await?,
+            users: deserializer
+                .nest("users", &users::AppSettingsUsersDeserializer)
+                .await?,
+            feature_flags: deserializer
+                .nest(
+                    "feature_flags",
+                    &feature_flags::AppSettingsFeatureFlagsDeserializer,
+                )
+                .await?,
             extensions,
         }))
     }
@@ -742,7 +830,11 @@ impl<'a> SettingsWriteGuard<'a> {
         .execute(self.parent.database.write())
         .await?;
 
+        settings_guard.settings.apply_runtime_limits();
         settings_guard.expires = std::time::Instant::now() + std::time::Duration::from_secs(60);
+        self.parent
+            .sections
+            .store(&settings_guard.settings, settings_guard.expires);
 
         let _ = self
             .parent
@@ -805,9 +897,41 @@ struct SettingsBuffer {
     expires: std::time::Instant,
 }
 
+/// Copies of frequently read sections, readable without taking the buffer locks.
+/// These are replaced whenever the settings are reloaded or saved.
+struct SettingsSections {
+    app: ArcSwap<app::AppSettingsApp>,
+    server: ArcSwap<server::AppSettingsServer>,
+    jwt: ArcSwap<jwt::AppSettingsJwt>,
+    feature_flags: ArcSwap<feature_flags::AppSettingsFeatureFlags>,
+    expires: ArcSwap<std::time::Instant>,
+}
+
+impl SettingsSections {
+    fn new(settings: &AppSettings, expires: std::time::Instant) -> Self {
+        Self {
+            app: ArcSwap::from_pointee(settings.app.clone()),
+            server: ArcSwap::from_pointee(settings.server.clone()),
+            jwt: ArcSwap::from_pointee(settings.jwt.clone()),
+            feature_flags: ArcSwap::from_pointee(settings.feature_flags.clone()),
+            expires: ArcSwap::from_pointee(expires),
+        }
+    }
+
+    fn store(&self, settings: &AppSettings, expires: std::time::Instant) {
+        self.app.store(Arc::new(settings.app.clone()));
+        self.server.store(Arc::new(settings.server.clone()));
+        self.jwt.store(Arc::new(settings.jwt.clone()));
+        self.feature_flags
+            .store(Arc::new(settings.feature_flags.clone()));
+        self.expires.store(Arc::new(expires));
+    }
+}
+
 pub struct Settings {
     cached: [RwLock<SettingsBuffer>; 2],
     cached_index: AtomicUsize,
+    sections: SettingsSections,
     write_serializing: Semaphore,
 
     database: Arc<crate::database::Database>,
@@ -832,18 +956,22 @@ impl Settings {
         )
         .await?;
 
-        Ok(*(boxed as Box<dyn std::any::Any>)
+        let settings = *(boxed as Box<dyn std::any::Any>)
             .downcast::<AppSettings>()
-            .expect("settings has invalid type"))
+            .expect("settings has invalid type");
+        settings.apply_runtime_limits();
+
+        Ok(settings)
     }
 
     pub async fn new(database: Arc<crate::database::Database>) -> Result<Self, anyhow::Error> {
+        let settings = Self::fetch_settings(&database).await?;
+        let expires = std::time::Instant::now() + std::time::Duration::from_secs(60);
+
         Ok(Self {
+            sections: SettingsSections::new(&settings, expires),
             cached: [
-                RwLock::new(SettingsBuffer {
-                    settings: Self::fetch_settings(&database).await?,
-                    expires: std::time::Instant::now() + std::time::Duration::from_secs(60),
-                }),
+                RwLock::new(SettingsBuffer { settings, expires }),
                 RwLock::new(SettingsBuffer {
                     settings: Self::fetch_settings(&database).await?,
                     expires: std::time::Instant::now() + std::time::Duration::from_secs(60),
@@ -884,6 +1012,7 @@ impl Settings {
         let mut guard = current_buffer.write().await;
         guard.settings = settings;
         guard.expires = now + std::time::Duration::from_secs(60);
+        self.sections.store(&guard.settings, guard.expires);
 
         drop(guard);
 
@@ -902,6 +1031,45 @@ impl Settings {
         Ok(f(&settings))
     }
 
+    async fn get_section<T>(
+        &self,
+        section: impl Fn(&SettingsSections) -> &ArcSwap<T>,
+    ) -> Result<Arc<T>, anyhow::Error> {
+        if std::time::Instant::now() < **self.sections.expires.load() {
+            return Ok(section(&self.sections).load_full());
+        }
+
+        drop(self.get().await?);
+
+        Ok(section(&self.sections).load_full())
+    }
+
+    /// Returns the `app` section without locking the whole settings struct.
+    #[inline]
+    pub async fn app(&self) -> Result<Arc<app::AppSettingsApp>, anyhow::Error> {
+        self.get_section(|sections| &sections.app).await
+    }
+
+    /// Returns the `server` section without locking the whole settings struct.
+    #[inline]
+    pub async fn server(&self) -> Result<Arc<server::AppSettingsServer>, anyhow::Error> {
+        self.get_section(|sections| &sections.server).await
+    }
+
+    /// Returns the `jwt` section without locking the whole settings struct.
+    #[inline]
+    pub async fn jwt(&self) -> Result<Arc<jwt::AppSettingsJwt>, anyhow::Error> {
+        self.get_section(|sections| &sections.jwt).await
+    }
+
+    /// Returns the `feature_flags` section without locking the whole settings struct.
+    #[inline]
+    pub async fn feature_flags(
+        &self,
+    ) -> Result<Arc<feature_flags::AppSettingsFeatureFlags>, anyhow::Error> {
+        self.get_section(|sections| &sections.feature_flags).await
+    }
+
     pub async fn get_webauthn(&self) -> Result<webauthn_rs::Webauthn, anyhow::Error> {
         let settings = self.get().await?;
 
@@ -937,5 +1105,8 @@ impl Settings {
         };
         let index = self.cached_index.load(Ordering::Acquire);
         self.cached[index % 2].write().await.expires = std::time::Instant::now();
+        self.sections
+            .expires
+            .store(Arc::new(std::time::Instant::now()));
     }
 }
@@ -0,0 +1,53 @@
+use super::{
+    ExtensionSettings, SettingsDeserializeExt, SettingsDeserializer, SettingsSerializeExt,
+    SettingsSerializer,
+};
+use compact_str::ToCompactString;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Clone, ToSchema, Serialize, Deserialize)]
+pub struct AppSettingsPagination {
+    pub default_per_page: u16,
+    pub max_per_page: u16,
+}
+
+#[async_trait::async_trait]
+impl SettingsSerializeExt for AppSettingsPagination {
+    async fn serialize(
+        &self,
+        serializer: SettingsSerializer,
+    ) -> Result<SettingsSerializer, anyhow::Error> {
+        Ok(serializer
+            .write_raw_setting(
+                "default_per_page",
+                self.default_per_page.to_compact_string(),
+            )
+            .write_raw_setting("max_per_page", self.max_per_page.to_compact_string()))
+    }
+}
+
+pub struct AppSettingsPaginationDeserializer;
+
+#[async_trait::async_trait]
+impl SettingsDeserializeExt for AppSettingsPaginationDeserializer {
+    async fn deserialize_boxed(
+        &self,
+        mut deserializer: SettingsDeserializer<'_>,
+    ) -> Result<ExtensionSettings, anyhow::Error> {
+        let max_per_page = deserializer
+            .take_raw_setting("max_per_page")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(100u16)
+            .max(1);
+
+        Ok(Box::new(AppSettingsPagination {
+            default_per_page: deserializer
+                .take_raw_setting("default_per_page")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(25u16)
+                .clamp(1, max_per_page),
+            max_per_page,
+        }))
+    }
+}
@@ -6,18 +6,68 @@ use compact_str::ToCompactString;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+#[derive(ToSchema, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerNameUniqueness {
+    None,
+    Owner,
+    Global,
+}
+
 #[derive(Clone, ToSchema, Serialize, Deserialize)]
 pub struct AppSettingsServer {
     pub max_file_manager_view_size: u64,
     pub max_file_manager_content_search_size: u64,
     pub max_file_manager_search_results: u64,
     pub max_schedules_step_count: u64,
+    pub power_state_cache_seconds: u64,
+    pub name_uniqueness: ServerNameUniqueness,
+    pub render_description_markdown: bool,
 
     pub allow_overwriting_custom_docker_image: bool,
     pub allow_editing_startup_command: bool,
     pub allow_viewing_installation_logs: bool,
     pub allow_acknowledging_installation_failure: bool,
     pub allow_viewing_transfer_progress: bool,
+
+    /// Whether allocation aliases have to resolve via DNS when they are created or updated.
+    pub resolve_allocation_aliases: bool,
+
+    /// Registry hostnames server images may be pulled from, empty allows every registry.
+    pub allowed_image_registries: Vec<compact_str::CompactString>,
+}
+
+impl AppSettingsServer {
+    /// Returns the registry hostname of a docker image reference,
+    /// images without an explicit registry are pulled from `docker.io`.
+    pub fn image_registry(image: &str) -> &str {
+        match image.split_once('/') {
+            Some((registry, _))
+                if registry.contains('.') || registry.contains(':') || registry == "localhost" =>
+            {
+                registry
+            }
+            _ => "docker.io",
+        }
+    }
+
+    /// Checks the registry of an image against the allowlist, returning the registry if it is not allowed.
+    pub fn validate_image_registry<'a>(&self, image: &'a str) -> Result<(), &'a str> {
+        if self.allowed_image_registries.is_empty() {
+            return Ok(());
+        }
+
+        let registry = Self::image_registry(image);
+        if self
+            .allowed_image_registries
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(registry))
+        {
+            Ok(())
+        } else {
+            Err(registry)
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -44,6 +94,22 @@ impl SettingsSerializeExt for AppSettingsServer {
                 "max_schedules_step_count",
                 self.max_schedules_step_count.to_compact_string(),
             )
+            .write_raw_setting(
+                "power_state_cache_seconds",
+                self.power_state_cache_seconds.to_compact_string(),
+            )
+            .write_raw_setting(
+                "name_uniqueness",
+                match self.name_uniqueness {
+                    ServerNameUniqueness::None => "none",
+                    ServerNameUniqueness::Owner => "owner",
+                    ServerNameUniqueness::Global => "global",
+                },
+            )
+            .write_raw_setting(
+                "render_description_markdown",
+                self.render_description_markdown.to_compact_string(),
+            )
             .write_raw_setting(
                 "allow_overwriting_custom_docker_image",
                 self.allow_overwriting_custom_docker_image
@@ -65,6 +131,14 @@ impl SettingsSerializeExt for AppSettingsServer {
             .write_raw_setting(
                 "allow_viewing_transfer_progress",
                 self.allow_viewing_transfer_progress.to_compact_string(),
+            )
+            .write_raw_setting(
+                "resolve_allocation_aliases",
+                self.resolve_allocation_aliases.to_compact_string(),
+            )
+            .write_raw_setting(
+                "allowed_image_registries",
+                self.allowed_image_registries.join(","),
             ))
     }
 }
@@ -94,6 +168,19 @@ impl SettingsDeserializeExt for AppSettingsServerDeserializer {
                 .take_raw_setting("max_schedules_step_count")
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(100),
+            power_state_cache_seconds: deserializer
+                .take_raw_setting("power_state_cache_seconds")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(5),
+            name_uniqueness: match deserializer.take_raw_setting("name_uniqueness").as_deref() {
+                Some("owner") => ServerNameUniqueness::Owner,
+                Some("global") => ServerNameUniqueness::Global,
+                _ => ServerNameUniqueness::None,
+            },
+            render_description_markdown: deserializer
+                .take_raw_setting("render_description_markdown")
+                .map(|s| s == "true")
+                .unwrap_or(false),
             allow_overwriting_custom_docker_image: deserializer
                 .take_raw_setting("allow_overwriting_custom_docker_image")
                 .map(|s| s == "true")
@@ -114,6 +201,86 @@ impl SettingsDeserializeExt for AppSettingsServerDeserializer {
                 .take_raw_setting("allow_viewing_transfer_progress")
                 .map(|s| s == "true")
                 .unwrap_or(true),
+            resolve_allocation_aliases: deserializer
+                .take_raw_setting("resolve_allocation_aliases")
+                .map(|s| s == "true")
+                .unwrap_or(false),
+            allowed_image_registries: deserializer
+                .take_raw_setting("allowed_image_registries")
+                .map(|s| {
+                    s.split(',')
+                        .filter(|registry| !registry.is_empty())
+                        .map(Into::into)
+                        .collect()
+                })
+                .unwrap_or_default(),
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings_with_registries(registries: &[&str]) -> AppSettingsServer {
+        AppSettingsServer {
+            max_file_manager_view_size: 0,
+            max_file_manager_content_search_size: 0,
+            max_file_manager_search_results: 0,
+            max_schedules_step_count: 0,
+            power_state_cache_seconds: 0,
+            name_uniqueness: ServerNameUniqueness::None,
+            render_description_markdown: false,
+            allow_overwriting_custom_docker_image: true,
+            allow_editing_startup_command: false,
+            allow_viewing_installation_logs: true,
+            allow_acknowledging_installation_failure: true,
+            allow_viewing_transfer_progress: true,
+            resolve_allocation_aliases: false,
+            allowed_image_registries: registries.iter().map(|r| (*r).into()).collect(),
+        }
+    }
+
+    #[test]
+    fn resolves_image_registries() {
+        for (image, registry) in [
+            ("alpine", "docker.io"),
+            ("library/alpine:3", "docker.io"),
+            ("ghcr.io/pterodactyl/yolks:java_21", "ghcr.io"),
+            (
+                "registry.example.com:5000/image",
+                "registry.example.com:5000",
+            ),
+            ("localhost/image", "localhost"),
+        ] {
+            assert_eq!(AppSettingsServer::image_registry(image), registry);
+        }
+    }
+
+    #[test]
+    fn allows_every_registry_without_allowlist() {
+        let settings = settings_with_registries(&[]);
+
+        assert!(
+            settings
+                .validate_image_registry("evil.example.com/image")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn validates_images_against_allowlist() {
+        let settings = settings_with_registries(&["ghcr.io", "docker.io"]);
+
+        assert!(
+            settings
+                .validate_image_registry("GHCR.io/pterodactyl/yolks:java_21")
+                .is_ok()
+        );
+        assert!(settings.validate_image_registry("alpine").is_ok());
+        assert_eq!(
+            settings.validate_image_registry("quay.io/image"),
+            Err("quay.io")
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use super::{
+    ExtensionSettings, SettingsDeserializeExt, SettingsDeserializer, SettingsSerializeExt,
+    SettingsSerializer,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Clone, ToSchema, Serialize, Deserialize)]
+pub struct AppSettingsSupport {
+    pub email: Option<compact_str::CompactString>,
+    pub docs_url: Option<compact_str::CompactString>,
+}
+
+#[async_trait::async_trait]
+impl SettingsSerializeExt for AppSettingsSupport {
+    async fn serialize(
+        &self,
+        serializer: SettingsSerializer,
+    ) -> Result<SettingsSerializer, anyhow::Error> {
+        Ok(serializer
+            .write_raw_setting("email", self.email.clone().unwrap_or_default())
+            .write_raw_setting("docs_url", self.docs_url.clone().unwrap_or_default()))
+    }
+}
+
+pub struct AppSettingsSupportDeserializer;
+
+#[async_trait::async_trait]
+impl SettingsDeserializeExt for AppSettingsSupportDeserializer {
+    async fn deserialize_boxed(
+        &self,
+        mut deserializer: SettingsDeserializer<'_>,
+    ) -> Result<ExtensionSettings, anyhow::Error> {
+        Ok(Box::new(AppSettingsSupport {
+            email: deserializer
+                .take_raw_setting("email")
+                .filter(|s| !s.is_empty()),
+            docs_url: deserializer
+                .take_raw_setting("docs_url")
+                .filter(|s| !s.is_empty()),
+        }))
+    }
+}
@@ -0,0 +1,46 @@
+use super::{
+    ExtensionSettings, SettingsDeserializeExt, SettingsDeserializer, SettingsSerializeExt,
+    SettingsSerializer,
+};
+use compact_str::ToCompactString;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+#[derive(Clone, ToSchema, Serialize, Deserialize)]
+pub struct AppSettingsUsers {
+    /// The role assigned to users that register themselves.
+    pub default_role_uuid: Option<uuid::Uuid>,
+}
+
+#[async_trait::async_trait]
+impl SettingsSerializeExt for AppSettingsUsers {
+    async fn serialize(
+        &self,
+        serializer: SettingsSerializer,
+    ) -> Result<SettingsSerializer, anyhow::Error> {
+        Ok(serializer.write_raw_setting(
+            "default_role_uuid",
+            self.default_role_uuid
+                .as_ref()
+                .map(|u| u.to_compact_string())
+                .unwrap_or_default(),
+        ))
+    }
+}
+
+pub struct AppSettingsUsersDeserializer;
+
+#[async_trait::async_trait]
+impl SettingsDeserializeExt for AppSettingsUsersDeserializer {
+    async fn deserialize_boxed(
+        &self,
+        mut deserializer: SettingsDeserializer<'_>,
+    ) -> Result<ExtensionSettings, anyhow::Error> {
+        Ok(Box::new(AppSettingsUsers {
+            default_role_uuid: deserializer
+                .take_raw_setting("default_role_uuid")
+                .and_then(|s| uuid::Uuid::from_str(&s).ok()),
+        }))
+    }
+}
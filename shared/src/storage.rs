@@ -1,4 +1,3 @@
-use crate::settings::SettingsReadGuard;
 use compact_str::ToCompactString;
 use serde::{Deserialize, Serialize};
 use std::{path::Path, sync::Arc};
@@ -37,33 +36,98 @@ fn get_s3_client(
     Ok(bucket)
 }
 
-pub struct StorageUrlRetriever<'a> {
-    settings: SettingsReadGuard<'a>,
+#[derive(Clone)]
+pub struct StorageUrlRetriever {
+    base_url: Arc<str>,
+    app_settings: Arc<super::settings::app::AppSettingsApp>,
 }
 
-impl<'a> StorageUrlRetriever<'a> {
-    pub fn new(settings: SettingsReadGuard<'a>) -> Self {
-        Self { settings }
+impl StorageUrlRetriever {
+    pub fn new(settings: &super::settings::AppSettings) -> Self {
+        let base_url = match &settings.storage_driver {
+            super::settings::StorageDriver::Filesystem { .. } => &settings.app.url,
+            super::settings::StorageDriver::S3 { public_url, .. } => public_url,
+        };
+
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            app_settings: Arc::new(settings.app.clone()),
+        }
     }
 
-    pub fn get_settings(&self) -> &super::settings::AppSettings {
-        &self.settings
+    pub fn get_app_settings(&self) -> &super::settings::app::AppSettingsApp {
+        &self.app_settings
     }
 
     pub fn get_url(&self, path: impl AsRef<str>) -> String {
-        match &self.settings.storage_driver {
-            super::settings::StorageDriver::Filesystem { .. } => {
-                format!(
-                    "{}/{}",
-                    self.settings.app.url.trim_end_matches('/'),
-                    path.as_ref()
-                )
+        format!("{}/{}", self.base_url, path.as_ref())
+    }
+
+    /// Returns the URL of a stored avatar, or the configured fallback for the user.
+    pub fn get_avatar_url(
+        &self,
+        avatar: Option<impl AsRef<str>>,
+        user_uuid: uuid::Uuid,
+        email: &str,
+    ) -> Option<String> {
+        use sha2::Digest;
+
+        if let Some(avatar) = avatar {
+            return Some(self.get_url(avatar));
+        }
+
+        match self.app_settings.avatar_fallback {
+            super::settings::app::AvatarFallback::None => None,
+            super::settings::app::AvatarFallback::Identicon => Some(format!(
+                "{}/avatars/{user_uuid}/identicon.svg",
+                self.app_settings.url.trim_end_matches('/')
+            )),
+            super::settings::app::AvatarFallback::Gravatar => {
+                let email_hash = sha2::Sha256::digest(email.trim().to_lowercase().as_bytes());
+
+                Some(format!(
+                    "https://www.gravatar.com/avatar/{email_hash:x}?d=identicon"
+                ))
+            }
+        }
+    }
+}
+
+/// Renders a deterministic 5x5 identicon for the user as an SVG image.
+pub fn render_identicon(user_uuid: uuid::Uuid) -> String {
+    use sha2::Digest;
+    use std::fmt::Write;
+
+    let hash = sha2::Sha256::digest(user_uuid.as_bytes());
+
+    let hue = u16::from_be_bytes([hash[0], hash[1]]) % 360;
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 5 5" shape-rendering="crispEdges"><rect width="5" height="5" fill="#f0f0f0"/><g fill="hsl({hue}, 55%, 50%)">"#
+    );
+
+    for row in 0..5 {
+        for column in 0..3 {
+            if hash[2 + row * 3 + column] & 1 == 0 {
+                continue;
             }
-            super::settings::StorageDriver::S3 { public_url, .. } => {
-                format!("{}/{}", public_url.trim_end_matches('/'), path.as_ref())
+
+            let _ = write!(
+                svg,
+                r#"<rect x="{column}" y="{row}" width="1" height="1"/>"#
+            );
+            if column < 2 {
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{}" y="{row}" width="1" height="1"/>"#,
+                    4 - column
+                );
             }
         }
     }
+
+    svg.push_str("</g></svg>");
+
+    svg
 }
 
 pub struct Storage {
@@ -75,10 +139,10 @@ impl Storage {
         Self { settings }
     }
 
-    pub async fn retrieve_urls(&self) -> Result<StorageUrlRetriever<'_>, anyhow::Error> {
+    pub async fn retrieve_urls(&self) -> Result<StorageUrlRetriever, anyhow::Error> {
         let settings = self.settings.get().await?;
 
-        Ok(StorageUrlRetriever::new(settings))
+        Ok(StorageUrlRetriever::new(&settings))
     }
 
     pub async fn remove(&self, path: Option<impl AsRef<str>>) -> Result<(), anyhow::Error> {
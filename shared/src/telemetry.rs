@@ -1,8 +1,64 @@
 use compact_str::ToCompactString;
 use serde::Serialize;
-use std::collections::{BTreeMap, HashMap};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{OnceLock, RwLock},
+};
+use tracing_subscriber::{Registry, filter::Targets, reload};
 use utoipa::ToSchema;
 
+struct LogFilter {
+    handle: reload::Handle<Targets, Registry>,
+    default_directives: compact_str::CompactString,
+    directives: RwLock<compact_str::CompactString>,
+}
+
+static LOG_FILTER: OnceLock<LogFilter> = OnceLock::new();
+
+/// Creates the reloadable log filter layer used by the global subscriber.
+/// The default directives are what the filter falls back to on reset (and on restart).
+pub fn log_filter_layer(
+    default_directives: &str,
+) -> Result<reload::Layer<Targets, Registry>, anyhow::Error> {
+    let (layer, handle) = reload::Layer::new(default_directives.parse::<Targets>()?);
+
+    LOG_FILTER
+        .set(LogFilter {
+            handle,
+            default_directives: default_directives.into(),
+            directives: RwLock::new(default_directives.into()),
+        })
+        .map_err(|_| anyhow::anyhow!("log filter has already been initialized"))?;
+
+    Ok(layer)
+}
+
+/// Returns the currently active log filter directives.
+pub fn log_filter_directives() -> compact_str::CompactString {
+    LOG_FILTER
+        .get()
+        .map(|filter| filter.directives.read().unwrap().clone())
+        .unwrap_or_default()
+}
+
+/// Replaces the active log filter, e.g. `info,shared::models::node=debug`.
+/// Passing `None` restores the default directives.
+pub fn set_log_filter_directives(
+    directives: Option<&str>,
+) -> Result<compact_str::CompactString, anyhow::Error> {
+    let filter = LOG_FILTER
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("log filter has not been initialized"))?;
+
+    let directives = directives.unwrap_or(&filter.default_directives);
+    filter.handle.reload(directives.parse::<Targets>()?)?;
+
+    let directives = compact_str::CompactString::from(directives);
+    *filter.directives.write().unwrap() = directives.clone();
+
+    Ok(directives)
+}
+
 nestify::nest! {
     #[derive(ToSchema, Serialize)] pub struct TelemetryData {
         pub uuid: uuid::Uuid,
@@ -28,6 +28,50 @@ pub fn validate_language(
     Ok(())
 }
 
+pub fn validate_timezone(
+    timezone: &compact_str::CompactString,
+    _context: &(),
+) -> Result<(), garde::Error> {
+    if timezone.parse::<chrono_tz::Tz>().is_err() {
+        return Err(garde::Error::new(compact_str::format_compact!(
+            "invalid timezone: {timezone}, expected an IANA tz database name"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validates that a value is a syntactically valid hostname (RFC 1123) or an IP address.
+pub fn validate_hostname<T: AsRef<str>>(hostname: &T, _context: &()) -> Result<(), garde::Error> {
+    let hostname = hostname.as_ref();
+
+    if hostname.parse::<std::net::IpAddr>().is_ok() {
+        return Ok(());
+    }
+
+    // a single trailing dot marks a fully qualified name
+    let name = hostname.strip_suffix('.').unwrap_or(hostname);
+
+    let valid = name.len() <= 253
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        });
+
+    if !valid {
+        return Err(garde::Error::new(compact_str::format_compact!(
+            "invalid hostname: {hostname}"
+        )));
+    }
+
+    Ok(())
+}
+
 pub fn validate_time_in_future(
     time: &chrono::DateTime<chrono::Utc>,
     _context: &(),
@@ -40,6 +84,38 @@ pub fn validate_time_in_future(
     Ok(())
 }
 
+pub fn validate_http_url(
+    url: &compact_str::CompactString,
+    _context: &(),
+) -> Result<(), garde::Error> {
+    match reqwest::Url::parse(url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(()),
+        Ok(_) => Err(garde::Error::new("url must use http or https")),
+        Err(_) => Err(garde::Error::new("not a valid url")),
+    }
+}
+
+pub fn validate_ip_networks(
+    networks: &[sqlx::types::ipnetwork::IpNetwork],
+    _context: &(),
+) -> Result<(), garde::Error> {
+    if networks.len() > 64 {
+        return Err(garde::Error::new("at most 64 networks may be specified"));
+    }
+
+    for network in networks {
+        if network.ip() != network.network() {
+            return Err(garde::Error::new(compact_str::format_compact!(
+                "invalid network {network}, host bits are set (did you mean {}/{}?)",
+                network.network(),
+                network.prefix()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 #[inline]
 pub fn validate_data<T: Validate>(data: &T) -> Result<(), Vec<String>>
 where
@@ -65,3 +141,105 @@ pub fn flatten_validation_errors(errors: &garde::Report) -> Vec<String> {
 
     messages
 }
+
+/// Renders a server description as markdown, the resulting HTML is sanitized
+/// so scripts, event handlers and other unsafe markup are stripped.
+pub fn render_description(description: &str) -> String {
+    let parser = pulldown_cmark::Parser::new_ext(
+        description,
+        pulldown_cmark::Options::ENABLE_STRIKETHROUGH | pulldown_cmark::Options::ENABLE_TABLES,
+    );
+
+    let mut html = String::with_capacity(description.len() * 2);
+    pulldown_cmark::html::push_html(&mut html, parser);
+
+    ammonia::clean(&html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use garde::Validate;
+
+    #[derive(Validate)]
+    struct TimezonePayload {
+        #[garde(inner(custom(validate_timezone)))]
+        timezone: Option<compact_str::CompactString>,
+    }
+
+    #[test]
+    fn accepts_valid_timezones() {
+        for timezone in ["UTC", "Europe/Berlin", "America/New_York", "Asia/Kolkata"] {
+            assert!(
+                validate_timezone(&timezone.into(), &()).is_ok(),
+                "{timezone} should be valid"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_timezones() {
+        for timezone in ["", "Mars/Olympus_Mons", "Europe/Berlinn", "GMT+25"] {
+            assert!(
+                validate_timezone(&timezone.into(), &()).is_err(),
+                "{timezone} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn accepts_valid_hostnames() {
+        for hostname in [
+            "localhost",
+            "node-1.example.com",
+            "node-1.example.com.",
+            "127.0.0.1",
+            "::1",
+        ] {
+            assert!(
+                validate_hostname(&hostname, &()).is_ok(),
+                "{hostname} should be valid"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_hostnames() {
+        let long_label = "a".repeat(64);
+
+        for hostname in [
+            "",
+            ".",
+            "example..com",
+            "example.com..",
+            "-example.com",
+            "example-.com",
+            "exa_mple.com",
+            long_label.as_str(),
+        ] {
+            assert!(
+                validate_hostname(&hostname, &()).is_err(),
+                "{hostname} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn validates_optional_timezone_payloads() {
+        assert!(TimezonePayload { timezone: None }.validate().is_ok());
+        assert!(
+            TimezonePayload {
+                timezone: Some("Europe/Amsterdam".into()),
+            }
+            .validate()
+            .is_ok()
+        );
+
+        let errors = TimezonePayload {
+            timezone: Some("Not/A_Zone".into()),
+        }
+        .validate()
+        .unwrap_err();
+        assert!(errors.to_string().contains("invalid timezone: Not/A_Zone"));
+    }
+}
@@ -7,7 +7,7 @@ export default function generateSchemaObject(output: fs.WriteStream, _spaces: nu
     const spaces = ' '.repeat(_spaces)
 
     if (object.enum) {
-        output.write(`${spaces}#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]\n`)
+        output.write(`${spaces}#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]\n`)
         output.write(`${spaces}pub enum ${name} {\n`)
         for (const value of object.enum) {
             output.write(`${spaces}    #[serde(rename = "${value}")]\n`)
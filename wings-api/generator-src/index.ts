@@ -36,7 +36,7 @@ use reqwest::{Client, Method, StatusCode};
 use serde::de::DeserializeOwned;
 use std::{
     pin::Pin,
-    sync::LazyLock,
+    sync::{Arc, LazyLock},
     task::{Context, Poll},
 };
 use tokio::io::AsyncRead;
@@ -97,6 +97,12 @@ async fn request_impl<T: DeserializeOwned + 'static>(
     body: Option<&impl Serialize>,
     body_raw: Option<compact_str::CompactString>,
 ) -> Result<T, ApiHttpError> {
+    // held until the response has been read, streamed bodies are not covered
+    let _permit = match &client.semaphore {
+        Some(semaphore) => semaphore.acquire().await.ok(),
+        None => None,
+    };
+
     let url = format!(
         "{}{}",
         client.base_url.trim_end_matches('/'),
@@ -178,13 +184,26 @@ async fn request_impl<T: DeserializeOwned + 'static>(
 pub struct WingsClient {
     base_url: String,
     token: String,
+    semaphore: Option<Arc<tokio::sync::Semaphore>>,
 }
 
 impl WingsClient {
 
     #[inline]
     pub fn new(base_url: String, token: String) -> Self {
-        Self { base_url, token }
+        Self {
+            base_url,
+            token,
+            semaphore: None,
+        }
+    }
+
+    /// Limits the amount of concurrent requests made through this client,
+    /// the semaphore can be shared between multiple clients.
+    #[inline]
+    pub fn with_semaphore(mut self, semaphore: Arc<tokio::sync::Semaphore>) -> Self {
+        self.semaphore = Some(semaphore);
+        self
     }
 
     pub fn request_raw(
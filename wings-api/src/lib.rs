@@ -22,7 +22,7 @@ nestify::nest! {
     }
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum AppContainerType {
     #[serde(rename = "official")]
     Official,
@@ -32,7 +32,7 @@ pub enum AppContainerType {
     None,
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum ArchiveFormat {
     #[serde(rename = "tar")]
     Tar,
@@ -54,7 +54,7 @@ pub enum ArchiveFormat {
     SevenZip,
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum BackupAdapter {
     #[serde(rename = "wings")]
     Wings,
@@ -70,7 +70,7 @@ pub enum BackupAdapter {
     Restic,
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum CompressionLevel {
     #[serde(rename = "best_speed")]
     BestSpeed,
@@ -110,7 +110,7 @@ nestify::nest! {
     }
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum DiskLimiterMode {
     #[serde(rename = "none")]
     None,
@@ -225,7 +225,7 @@ nestify::nest! {
     }
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum ServerAutoStartBehavior {
     #[serde(rename = "always")]
     Always,
@@ -341,7 +341,7 @@ nestify::nest! {
     }
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum ServerPowerAction {
     #[serde(rename = "start")]
     Start,
@@ -353,7 +353,7 @@ pub enum ServerPowerAction {
     Kill,
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum ServerState {
     #[serde(rename = "offline")]
     Offline,
@@ -365,7 +365,7 @@ pub enum ServerState {
     Running,
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum SystemBackupsDdupBakCompressionFormat {
     #[serde(rename = "none")]
     None,
@@ -430,7 +430,7 @@ nestify::nest! {
     }
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum TransferArchiveFormat {
     #[serde(rename = "tar")]
     Tar,
@@ -459,7 +459,7 @@ nestify::nest! {
     }
 }
 
-#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, ToSchema, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum WebsocketEvent {
     #[serde(rename = "auth success")]
     AuthSuccess,